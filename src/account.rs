@@ -1,13 +1,20 @@
 use error_chain::bail;
 
 use crate::util::build_signed_request;
+use crate::pagination::{PageRequest, Pages, DAY};
+use crate::filters::{ensure_filters, SymbolFilters};
+pub use crate::model::{
+    CancelReplaceMode, CancelRestrictions, NewOrderRespType, OrderRateLimitExceededMode, OrderSide,
+    OrderType, SelfTradePreventionMode, TimeInForce,
+};
 use crate::model::{
-    AccountInformation, Balance, Empty, Order, OrderCanceled, TradeHistory, Transaction,
+    AccountInformation, Balance, CancelReplaceResponse, Empty, Order, OrderAmendment,
+    OrderCanceled, OrderList, Symbol, TradeHistory, Transaction, TransactionAck, Number,
 };
 use crate::client::Client;
-use crate::errors::Result;
+use crate::errors::{BinanceErrorCode, Error, ErrorKind, Result};
+use serde::Deserialize;
 use std::collections::BTreeMap;
-use std::fmt::Display;
 use crate::api::API;
 use crate::api::Spot;
 
@@ -17,73 +24,827 @@ pub struct Account {
     pub recv_window: u64,
 }
 
-struct OrderRequest {
+pub(crate) struct OrderRequest {
     pub symbol: String,
-    pub qty: f64,
-    pub price: f64,
-    pub stop_price: Option<f64>,
+    pub qty: Number,
+    pub price: Number,
+    pub stop_price: Option<Number>,
     pub order_side: OrderSide,
     pub order_type: OrderType,
     pub time_in_force: TimeInForce,
     pub new_client_order_id: Option<String>,
 }
 
-struct OrderQuoteQuantityRequest {
+pub(crate) struct OrderQuoteQuantityRequest {
     pub symbol: String,
-    pub quote_order_qty: f64,
-    pub price: f64,
+    pub quote_order_qty: Number,
+    pub price: Number,
     pub order_side: OrderSide,
     pub order_type: OrderType,
     pub time_in_force: TimeInForce,
     pub new_client_order_id: Option<String>,
 }
 
-pub enum OrderType {
-    Limit,
-    Market,
-    StopLossLimit,
+/// A spot order with every parameter of `POST /api/v3/order`.
+///
+///```no_run
+/// use binance::api::Binance;
+/// use binance::account::*;
+///
+/// fn main() {
+///     let account: Account = Binance::new(Some("api_key".into()), Some("secret_key".into()));
+///     let order = SpotOrderRequest::new("LTCBTC", OrderSide::Buy, OrderType::StopLossLimit)
+///         .set_quantity(10)
+///         .set_price("0.1".parse().unwrap())
+///         .set_stop_price("0.09".parse().unwrap())
+///         .set_time_in_force(TimeInForce::GTC)
+///         .set_iceberg_qty(2);
+///     let result = account.place_order(order);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SpotOrderRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub order_type: OrderType,
+    pub time_in_force: Option<TimeInForce>,
+    pub qty: Option<Number>,
+    pub quote_order_qty: Option<Number>,
+    pub price: Option<Number>,
+    pub new_client_order_id: Option<String>,
+    pub strategy_id: Option<u64>,
+    pub strategy_type: Option<u64>,
+    pub stop_price: Option<Number>,
+    pub trailing_delta: Option<u64>,
+    pub iceberg_qty: Option<Number>,
+    pub new_order_resp_type: Option<NewOrderRespType>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+}
+
+impl SpotOrderRequest {
+    pub fn new<S: Into<String>>(symbol: S, side: OrderSide, order_type: OrderType) -> Self {
+        Self {
+            symbol: symbol.into(),
+            side,
+            order_type,
+            time_in_force: None,
+            qty: None,
+            quote_order_qty: None,
+            price: None,
+            new_client_order_id: None,
+            strategy_id: None,
+            strategy_type: None,
+            stop_price: None,
+            trailing_delta: None,
+            iceberg_qty: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
+        }
+    }
+
+    pub fn set_time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    pub fn set_quantity<F: Into<Number>>(mut self, qty: F) -> Self {
+        self.qty = Some(qty.into());
+        self
+    }
+
+    pub fn set_quote_order_qty<F: Into<Number>>(mut self, quote_order_qty: F) -> Self {
+        self.quote_order_qty = Some(quote_order_qty.into());
+        self
+    }
+
+    pub fn set_price(mut self, price: Number) -> Self {
+        self.price = Some(price);
+        self
+    }
+
+    pub fn set_new_client_order_id<S: Into<String>>(mut self, new_client_order_id: S) -> Self {
+        self.new_client_order_id = Some(new_client_order_id.into());
+        self
+    }
+
+    pub fn set_strategy_id(mut self, strategy_id: u64) -> Self {
+        self.strategy_id = Some(strategy_id);
+        self
+    }
+
+    /// Values below 1000000 are reserved by Binance.
+    pub fn set_strategy_type(mut self, strategy_type: u64) -> Self {
+        self.strategy_type = Some(strategy_type);
+        self
+    }
+
+    pub fn set_stop_price(mut self, stop_price: Number) -> Self {
+        self.stop_price = Some(stop_price);
+        self
+    }
+
+    /// Trailing delta in basis points, for trailing stop orders.
+    pub fn set_trailing_delta(mut self, trailing_delta: u64) -> Self {
+        self.trailing_delta = Some(trailing_delta);
+        self
+    }
+
+    pub fn set_iceberg_qty<F: Into<Number>>(mut self, iceberg_qty: F) -> Self {
+        self.iceberg_qty = Some(iceberg_qty.into());
+        self
+    }
+
+    pub fn set_new_order_resp_type(mut self, new_order_resp_type: NewOrderRespType) -> Self {
+        self.new_order_resp_type = Some(new_order_resp_type);
+        self
+    }
+
+    pub fn set_self_trade_prevention_mode(mut self, mode: SelfTradePreventionMode) -> Self {
+        self.self_trade_prevention_mode = Some(mode);
+        self
+    }
+
+    /// Check the parameters required and allowed by the order type,
+    /// without contacting the exchange.
+    pub fn validate(&self) -> Result<()> {
+        let limit = matches!(
+            self.order_type,
+            OrderType::Limit | OrderType::StopLossLimit | OrderType::TakeProfitLimit
+        );
+        let stop = matches!(
+            self.order_type,
+            OrderType::StopLoss |
+                OrderType::StopLossLimit |
+                OrderType::TakeProfit |
+                OrderType::TakeProfitLimit
+        );
+        let priced = limit || self.order_type == OrderType::LimitMaker;
+
+        match self.order_type {
+            OrderType::Limit |
+            OrderType::Market |
+            OrderType::StopLoss |
+            OrderType::StopLossLimit |
+            OrderType::TakeProfit |
+            OrderType::TakeProfitLimit |
+            OrderType::LimitMaker => {}
+            _ => bail!("{} orders are not supported on spot", self.order_type),
+        }
+
+        if self.symbol.is_empty() {
+            bail!("symbol is required");
+        }
+
+        if self.order_type == OrderType::Market {
+            if self.qty.is_some() == self.quote_order_qty.is_some() {
+                bail!("MARKET orders need exactly one of quantity or quoteOrderQty");
+            }
+        } else if self.qty.is_none() {
+            bail!("{} orders need a quantity", self.order_type);
+        } else if self.quote_order_qty.is_some() {
+            bail!("quoteOrderQty is only allowed on MARKET orders");
+        }
+
+        match (priced, self.price.is_some()) {
+            (true, false) => bail!("{} orders need a price", self.order_type),
+            (false, true) => bail!("{} orders do not take a price", self.order_type),
+            _ => {}
+        }
+
+        match (limit, self.time_in_force.is_some()) {
+            (true, false) => bail!("{} orders need a timeInForce", self.order_type),
+            (false, true) => bail!("{} orders do not take a timeInForce", self.order_type),
+            _ => {}
+        }
+
+        let triggered = self.stop_price.is_some() || self.trailing_delta.is_some();
+        match (stop, triggered) {
+            (true, false) => bail!(
+                "{} orders need a stopPrice or trailingDelta",
+                self.order_type
+            ),
+            (false, true) => bail!(
+                "{} orders do not take a stopPrice or trailingDelta",
+                self.order_type
+            ),
+            _ => {}
+        }
+
+        if self.iceberg_qty.is_some() {
+            if !priced {
+                bail!("{} orders cannot be iceberg orders", self.order_type);
+            }
+            if let Some(time_in_force) = &self.time_in_force {
+                if *time_in_force != TimeInForce::GTC {
+                    bail!("Iceberg orders need timeInForce GTC");
+                }
+            }
+        }
+
+        if let Some(strategy_type) = self.strategy_type {
+            if strategy_type < 1_000_000 {
+                bail!("strategyType must be at least 1000000");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check the order against the symbol filters, see `binance::filters`.
+    pub fn check_filters<S: SymbolFilters + ?Sized>(&self, symbol: &S) -> Result<()> {
+        ensure_filters(symbol, self.into())
+    }
+}
+
+/// An OCO order through `POST /api/v3/order/oco`: a LIMIT_MAKER leg at `price`
+/// and a STOP_LOSS leg at `stop_price`, or STOP_LOSS_LIMIT with a stop limit price.
+///
+/// Binance deprecated this form in favour of `OrderListOcoRequest`.
+#[derive(Clone, Debug)]
+pub struct OcoOrderRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub qty: Number,
+    pub price: Number,
+    pub stop_price: Option<Number>,
+    pub trailing_delta: Option<u64>,
+    pub stop_limit_price: Option<Number>,
+    pub stop_limit_time_in_force: Option<TimeInForce>,
+    pub list_client_order_id: Option<String>,
+    pub limit_client_order_id: Option<String>,
+    pub limit_iceberg_qty: Option<Number>,
+    pub limit_strategy_id: Option<u64>,
+    pub limit_strategy_type: Option<u64>,
+    pub stop_client_order_id: Option<String>,
+    pub stop_iceberg_qty: Option<Number>,
+    pub stop_strategy_id: Option<u64>,
+    pub stop_strategy_type: Option<u64>,
+    pub new_order_resp_type: Option<NewOrderRespType>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
 }
 
-impl Display for OrderType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::Limit => write!(f, "LIMIT"),
-            Self::Market => write!(f, "MARKET"),
-            Self::StopLossLimit => write!(f, "STOP_LOSS_LIMIT"),
+impl OcoOrderRequest {
+    pub fn new<S, F>(symbol: S, side: OrderSide, qty: F, price: Number) -> Self
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        Self {
+            symbol: symbol.into(),
+            side,
+            qty: qty.into(),
+            price,
+            stop_price: None,
+            trailing_delta: None,
+            stop_limit_price: None,
+            stop_limit_time_in_force: None,
+            list_client_order_id: None,
+            limit_client_order_id: None,
+            limit_iceberg_qty: None,
+            limit_strategy_id: None,
+            limit_strategy_type: None,
+            stop_client_order_id: None,
+            stop_iceberg_qty: None,
+            stop_strategy_id: None,
+            stop_strategy_type: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
         }
     }
+
+    pub fn set_stop_price(mut self, stop_price: Number) -> Self {
+        self.stop_price = Some(stop_price);
+        self
+    }
+
+    /// Trailing delta in basis points, for a trailing stop leg.
+    pub fn set_trailing_delta(mut self, trailing_delta: u64) -> Self {
+        self.trailing_delta = Some(trailing_delta);
+        self
+    }
+
+    /// Make the stop leg a STOP_LOSS_LIMIT order.
+    pub fn set_stop_limit(mut self, stop_limit_price: Number, time_in_force: TimeInForce) -> Self {
+        self.stop_limit_price = Some(stop_limit_price);
+        self.stop_limit_time_in_force = Some(time_in_force);
+        self
+    }
+
+    pub fn set_list_client_order_id<S: Into<String>>(mut self, list_client_order_id: S) -> Self {
+        self.list_client_order_id = Some(list_client_order_id.into());
+        self
+    }
+
+    pub fn set_limit_client_order_id<S: Into<String>>(mut self, client_order_id: S) -> Self {
+        self.limit_client_order_id = Some(client_order_id.into());
+        self
+    }
+
+    pub fn set_limit_iceberg_qty<F: Into<Number>>(mut self, iceberg_qty: F) -> Self {
+        self.limit_iceberg_qty = Some(iceberg_qty.into());
+        self
+    }
+
+    pub fn set_limit_strategy(mut self, strategy_id: u64, strategy_type: Option<u64>) -> Self {
+        self.limit_strategy_id = Some(strategy_id);
+        self.limit_strategy_type = strategy_type;
+        self
+    }
+
+    pub fn set_stop_client_order_id<S: Into<String>>(mut self, client_order_id: S) -> Self {
+        self.stop_client_order_id = Some(client_order_id.into());
+        self
+    }
+
+    pub fn set_stop_iceberg_qty<F: Into<Number>>(mut self, iceberg_qty: F) -> Self {
+        self.stop_iceberg_qty = Some(iceberg_qty.into());
+        self
+    }
+
+    pub fn set_stop_strategy(mut self, strategy_id: u64, strategy_type: Option<u64>) -> Self {
+        self.stop_strategy_id = Some(strategy_id);
+        self.stop_strategy_type = strategy_type;
+        self
+    }
+
+    pub fn set_new_order_resp_type(mut self, new_order_resp_type: NewOrderRespType) -> Self {
+        self.new_order_resp_type = Some(new_order_resp_type);
+        self
+    }
+
+    pub fn set_self_trade_prevention_mode(mut self, mode: SelfTradePreventionMode) -> Self {
+        self.self_trade_prevention_mode = Some(mode);
+        self
+    }
+
+    /// Check both legs, without contacting the exchange.
+    pub fn validate(&self) -> Result<()> {
+        let limit = SpotOrderRequest {
+            iceberg_qty: self.limit_iceberg_qty,
+            strategy_type: self.limit_strategy_type,
+            ..SpotOrderRequest::new(
+                self.symbol.clone(),
+                self.side.clone(),
+                OrderType::LimitMaker,
+            )
+            .set_quantity(self.qty)
+            .set_price(self.price)
+        };
+        let stop_type = if self.stop_limit_price.is_some() {
+            OrderType::StopLossLimit
+        } else {
+            OrderType::StopLoss
+        };
+        let stop = SpotOrderRequest {
+            price: self.stop_limit_price,
+            time_in_force: self.stop_limit_time_in_force.clone(),
+            stop_price: self.stop_price,
+            trailing_delta: self.trailing_delta,
+            iceberg_qty: self.stop_iceberg_qty,
+            strategy_type: self.stop_strategy_type,
+            ..SpotOrderRequest::new(self.symbol.clone(), self.side.clone(), stop_type)
+                .set_quantity(self.qty)
+        };
+        limit.validate()?;
+        stop.validate()
+    }
 }
 
-#[derive(Clone)]
-pub enum OrderSide {
-    Buy,
-    Sell,
+/// One leg of an `OrderListOcoRequest`.
+#[derive(Clone, Debug)]
+pub struct OcoLeg {
+    pub order_type: OrderType,
+    pub client_order_id: Option<String>,
+    pub iceberg_qty: Option<Number>,
+    pub price: Option<Number>,
+    pub stop_price: Option<Number>,
+    pub trailing_delta: Option<u64>,
+    pub time_in_force: Option<TimeInForce>,
+    pub strategy_id: Option<u64>,
+    pub strategy_type: Option<u64>,
+}
+
+impl OcoLeg {
+    /// STOP_LOSS_LIMIT, STOP_LOSS, LIMIT_MAKER, TAKE_PROFIT or TAKE_PROFIT_LIMIT.
+    pub fn new(order_type: OrderType) -> Self {
+        Self {
+            order_type,
+            client_order_id: None,
+            iceberg_qty: None,
+            price: None,
+            stop_price: None,
+            trailing_delta: None,
+            time_in_force: None,
+            strategy_id: None,
+            strategy_type: None,
+        }
+    }
+
+    pub fn set_client_order_id<S: Into<String>>(mut self, client_order_id: S) -> Self {
+        self.client_order_id = Some(client_order_id.into());
+        self
+    }
+
+    pub fn set_iceberg_qty<F: Into<Number>>(mut self, iceberg_qty: F) -> Self {
+        self.iceberg_qty = Some(iceberg_qty.into());
+        self
+    }
+
+    pub fn set_price(mut self, price: Number) -> Self {
+        self.price = Some(price);
+        self
+    }
+
+    pub fn set_stop_price(mut self, stop_price: Number) -> Self {
+        self.stop_price = Some(stop_price);
+        self
+    }
+
+    pub fn set_trailing_delta(mut self, trailing_delta: u64) -> Self {
+        self.trailing_delta = Some(trailing_delta);
+        self
+    }
+
+    pub fn set_time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    pub fn set_strategy(mut self, strategy_id: u64, strategy_type: Option<u64>) -> Self {
+        self.strategy_id = Some(strategy_id);
+        self.strategy_type = strategy_type;
+        self
+    }
+
+    fn validate(&self, symbol: &str, side: &OrderSide, qty: Number) -> Result<()> {
+        match self.order_type {
+            OrderType::StopLossLimit |
+            OrderType::StopLoss |
+            OrderType::LimitMaker |
+            OrderType::TakeProfit |
+            OrderType::TakeProfitLimit => {}
+            _ => bail!("{} orders cannot be an OCO leg", self.order_type),
+        }
+        SpotOrderRequest {
+            time_in_force: self.time_in_force.clone(),
+            price: self.price,
+            stop_price: self.stop_price,
+            trailing_delta: self.trailing_delta,
+            iceberg_qty: self.iceberg_qty,
+            strategy_type: self.strategy_type,
+            ..SpotOrderRequest::new(symbol, side.clone(), self.order_type.clone()).set_quantity(qty)
+        }
+        .validate()
+    }
+
+    fn insert(self, prefix: &str, parameters: &mut BTreeMap<String, String>) {
+        parameters.insert(format!("{}Type", prefix), self.order_type.to_string());
+        if let Some(client_order_id) = self.client_order_id {
+            parameters.insert(format!("{}ClientOrderId", prefix), client_order_id);
+        }
+        if let Some(iceberg_qty) = self.iceberg_qty {
+            parameters.insert(format!("{}IcebergQty", prefix), iceberg_qty.to_string());
+        }
+        if let Some(price) = self.price {
+            parameters.insert(format!("{}Price", prefix), price.to_string());
+        }
+        if let Some(stop_price) = self.stop_price {
+            parameters.insert(format!("{}StopPrice", prefix), stop_price.to_string());
+        }
+        if let Some(trailing_delta) = self.trailing_delta {
+            parameters.insert(
+                format!("{}TrailingDelta", prefix),
+                trailing_delta.to_string(),
+            );
+        }
+        if let Some(time_in_force) = self.time_in_force {
+            parameters.insert(format!("{}TimeInForce", prefix), time_in_force.to_string());
+        }
+        if let Some(strategy_id) = self.strategy_id {
+            parameters.insert(format!("{}StrategyId", prefix), strategy_id.to_string());
+        }
+        if let Some(strategy_type) = self.strategy_type {
+            parameters.insert(format!("{}StrategyType", prefix), strategy_type.to_string());
+        }
+    }
+}
+
+/// An OCO order through `POST /api/v3/orderList/oco`, with one leg above
+/// and one leg below the last price.
+///
+///```no_run
+/// use binance::api::Binance;
+/// use binance::account::*;
+///
+/// fn main() {
+///     let account: Account = Binance::new(Some("api_key".into()), Some("secret_key".into()));
+///     // Take profit at 0.12, stop loss at 0.09
+///     let order = OrderListOcoRequest::new(
+///         "LTCBTC",
+///         OrderSide::Sell,
+///         1,
+///         OcoLeg::new(OrderType::LimitMaker).set_price("0.12".parse().unwrap()),
+///         OcoLeg::new(OrderType::StopLoss).set_stop_price("0.09".parse().unwrap()),
+///     );
+///     let result = account.place_order_list_oco(order);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct OrderListOcoRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub qty: Number,
+    pub above: OcoLeg,
+    pub below: OcoLeg,
+    pub list_client_order_id: Option<String>,
+    pub new_order_resp_type: Option<NewOrderRespType>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+}
+
+impl OrderListOcoRequest {
+    pub fn new<S, F>(symbol: S, side: OrderSide, qty: F, above: OcoLeg, below: OcoLeg) -> Self
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        Self {
+            symbol: symbol.into(),
+            side,
+            qty: qty.into(),
+            above,
+            below,
+            list_client_order_id: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
+        }
+    }
+
+    pub fn set_list_client_order_id<S: Into<String>>(mut self, list_client_order_id: S) -> Self {
+        self.list_client_order_id = Some(list_client_order_id.into());
+        self
+    }
+
+    pub fn set_new_order_resp_type(mut self, new_order_resp_type: NewOrderRespType) -> Self {
+        self.new_order_resp_type = Some(new_order_resp_type);
+        self
+    }
+
+    pub fn set_self_trade_prevention_mode(mut self, mode: SelfTradePreventionMode) -> Self {
+        self.self_trade_prevention_mode = Some(mode);
+        self
+    }
+
+    /// Check both legs, without contacting the exchange.
+    pub fn validate(&self) -> Result<()> {
+        self.above.validate(&self.symbol, &self.side, self.qty)?;
+        self.below.validate(&self.symbol, &self.side, self.qty)
+    }
+}
+
+/// Cancel an order and place a new one in a single request, through
+/// `POST /api/v3/order/cancelReplace`.
+///
+/// When a leg fails the call returns `ErrorKind::CancelReplaceFailed`, with
+/// the outcome of both legs.
+///
+///```no_run
+/// use binance::api::Binance;
+/// use binance::account::*;
+///
+/// fn main() {
+///     let account: Account = Binance::new(Some("api_key".into()), Some("secret_key".into()));
+///     let order = SpotOrderRequest::new("LTCBTC", OrderSide::Buy, OrderType::Limit)
+///         .set_quantity(1)
+///         .set_price("0.1".parse().unwrap())
+///         .set_time_in_force(TimeInForce::GTC);
+///     let request = CancelReplaceRequest::new(order, CancelReplaceMode::StopOnFailure)
+///         .set_cancel_order_id(28);
+///     let result = account.cancel_replace_order(request);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CancelReplaceRequest {
+    pub order: SpotOrderRequest,
+    pub cancel_replace_mode: CancelReplaceMode,
+    pub cancel_order_id: Option<u64>,
+    pub cancel_orig_client_order_id: Option<String>,
+    pub cancel_new_client_order_id: Option<String>,
+    pub cancel_restrictions: Option<CancelRestrictions>,
+    pub order_rate_limit_exceeded_mode: Option<OrderRateLimitExceededMode>,
+}
+
+impl CancelReplaceRequest {
+    /// Replace an order with `order`, placed on the same symbol.
+    pub fn new(order: SpotOrderRequest, cancel_replace_mode: CancelReplaceMode) -> Self {
+        Self {
+            order,
+            cancel_replace_mode,
+            cancel_order_id: None,
+            cancel_orig_client_order_id: None,
+            cancel_new_client_order_id: None,
+            cancel_restrictions: None,
+            order_rate_limit_exceeded_mode: None,
+        }
+    }
+
+    /// The order to cancel, by ID.
+    pub fn set_cancel_order_id(mut self, order_id: u64) -> Self {
+        self.cancel_order_id = Some(order_id);
+        self
+    }
+
+    /// The order to cancel, by client order ID.
+    pub fn set_cancel_orig_client_order_id<S: Into<String>>(mut self, client_order_id: S) -> Self {
+        self.cancel_orig_client_order_id = Some(client_order_id.into());
+        self
+    }
+
+    /// Client ID of the cancel itself.
+    pub fn set_cancel_new_client_order_id<S: Into<String>>(mut self, client_order_id: S) -> Self {
+        self.cancel_new_client_order_id = Some(client_order_id.into());
+        self
+    }
+
+    pub fn set_cancel_restrictions(mut self, cancel_restrictions: CancelRestrictions) -> Self {
+        self.cancel_restrictions = Some(cancel_restrictions);
+        self
+    }
+
+    pub fn set_order_rate_limit_exceeded_mode(mut self, mode: OrderRateLimitExceededMode) -> Self {
+        self.order_rate_limit_exceeded_mode = Some(mode);
+        self
+    }
+
+    /// Check the new order and that the order to cancel is identified
+    /// exactly once, without contacting the exchange.
+    pub fn validate(&self) -> Result<()> {
+        if self.cancel_order_id.is_some() == self.cancel_orig_client_order_id.is_some() {
+            bail!("Set exactly one of cancel_order_id and cancel_orig_client_order_id");
+        }
+        if self.order.new_order_resp_type == Some(NewOrderRespType::ACK) {
+            bail!("ACK responses have no order details, cancel-replace needs RESULT or FULL");
+        }
+        self.order.validate()
+    }
 }
 
-impl Display for OrderSide {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::Buy => write!(f, "BUY"),
-            Self::Sell => write!(f, "SELL"),
+/// Reduce the quantity of an open order without losing its place in the
+/// book, through `PUT /api/v3/order/amend/keepPriority`.
+#[derive(Clone, Debug)]
+pub struct OrderAmendRequest {
+    pub symbol: String,
+    pub order_id: Option<u64>,
+    pub orig_client_order_id: Option<String>,
+    pub new_client_order_id: Option<String>,
+    pub new_qty: Number,
+}
+
+impl OrderAmendRequest {
+    /// Amend order `order_id` down to `new_qty`.
+    pub fn new<S, F>(symbol: S, order_id: u64, new_qty: F) -> Self
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        Self {
+            symbol: symbol.into(),
+            order_id: Some(order_id),
+            orig_client_order_id: None,
+            new_client_order_id: None,
+            new_qty: new_qty.into(),
+        }
+    }
+
+    /// Amend the order placed with client order ID `orig_client_order_id`
+    /// down to `new_qty`.
+    pub fn with_client_id<S, C, F>(symbol: S, orig_client_order_id: C, new_qty: F) -> Self
+    where
+        S: Into<String>,
+        C: Into<String>,
+        F: Into<Number>,
+    {
+        Self {
+            symbol: symbol.into(),
+            order_id: None,
+            orig_client_order_id: Some(orig_client_order_id.into()),
+            new_client_order_id: None,
+            new_qty: new_qty.into(),
+        }
+    }
+
+    /// New client ID of the amended order.
+    pub fn set_new_client_order_id<S: Into<String>>(mut self, client_order_id: S) -> Self {
+        self.new_client_order_id = Some(client_order_id.into());
+        self
+    }
+
+    /// Check that the order to amend is identified and the new quantity is
+    /// positive, without contacting the exchange.
+    pub fn validate(&self) -> Result<()> {
+        if self.order_id.is_none() && self.orig_client_order_id.is_none() {
+            bail!("Set order_id or orig_client_order_id");
+        }
+        if self.new_qty <= Number::default() {
+            bail!("newQty must be positive");
         }
+        Ok(())
     }
 }
 
-#[allow(clippy::all)]
-pub enum TimeInForce {
-    GTC,
-    IOC,
-    FOK,
+/// Parameters of `GET /api/v3/allOrders`.
+#[derive(Clone, Debug)]
+pub struct OrderHistoryRequest {
+    pub symbol: String,
+    pub order_id: Option<u64>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u16>,
 }
 
-impl Display for TimeInForce {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::GTC => write!(f, "GTC"),
-            Self::IOC => write!(f, "IOC"),
-            Self::FOK => write!(f, "FOK"),
+impl OrderHistoryRequest {
+    pub fn new<S: Into<String>>(symbol: S) -> Self {
+        Self {
+            symbol: symbol.into(),
+            order_id: None,
+            start_time: None,
+            end_time: None,
+            limit: None,
         }
     }
+
+    /// Orders from this ID onwards.
+    pub fn set_order_id(mut self, order_id: u64) -> Self {
+        self.order_id = Some(order_id);
+        self
+    }
+
+    pub fn set_start_time(mut self, start_time: u64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn set_end_time(mut self, end_time: u64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    /// Orders per request, up to 1000.
+    pub fn set_limit(mut self, limit: u16) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// Parameters of `GET /api/v3/myTrades`.
+#[derive(Clone, Debug)]
+pub struct TradeHistoryRequest {
+    pub symbol: String,
+    pub order_id: Option<u64>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub from_id: Option<u64>,
+    pub limit: Option<u16>,
+}
+
+impl TradeHistoryRequest {
+    pub fn new<S: Into<String>>(symbol: S) -> Self {
+        Self {
+            symbol: symbol.into(),
+            order_id: None,
+            start_time: None,
+            end_time: None,
+            from_id: None,
+            limit: None,
+        }
+    }
+
+    /// Only the trades of this order.
+    pub fn set_order_id(mut self, order_id: u64) -> Self {
+        self.order_id = Some(order_id);
+        self
+    }
+
+    pub fn set_start_time(mut self, start_time: u64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn set_end_time(mut self, end_time: u64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    /// Trades from this trade ID onwards.
+    pub fn set_from_id(mut self, from_id: u64) -> Self {
+        self.from_id = Some(from_id);
+        self
+    }
+
+    /// Trades per request, up to 1000.
+    pub fn set_limit(mut self, limit: u16) -> Self {
+        self.limit = Some(limit);
+        self
+    }
 }
 
 impl Account {
@@ -161,9 +922,26 @@ impl Account {
             .get_signed(API::Spot(Spot::Order), Some(request))
     }
 
+    // Check an order's status by the client order ID it was placed with
+    pub fn order_status_with_client_id<S>(
+        &self, symbol: S, orig_client_order_id: String,
+    ) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("origClientOrderId".into(), orig_client_order_id);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::Order), Some(request))
+    }
+
     /// Place a test status order
     ///
     /// This order is sandboxed: it is validated, but not sent to the matching engine.
+    #[deprecated(note = "Binance has no test endpoint for order queries, use order_status")]
     pub fn test_order_status<S>(&self, symbol: S, order_id: u64) -> Result<()>
     where
         S: Into<String>,
@@ -179,10 +957,10 @@ impl Account {
     }
 
     // Place a LIMIT order - BUY
-    pub fn limit_buy<S, F>(&self, symbol: S, qty: F, price: f64) -> Result<Transaction>
+    pub fn limit_buy<S, F>(&self, symbol: S, qty: F, price: Number) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let buy = OrderRequest {
             symbol: symbol.into(),
@@ -194,7 +972,7 @@ impl Account {
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_order(buy);
+        let order = build_order(buy);
         let request = build_signed_request(order, self.recv_window)?;
         self.client.post_signed(API::Spot(Spot::Order), request)
     }
@@ -202,10 +980,10 @@ impl Account {
     /// Place a test limit order - BUY
     ///
     /// This order is sandboxed: it is validated, but not sent to the matching engine.
-    pub fn test_limit_buy<S, F>(&self, symbol: S, qty: F, price: f64) -> Result<()>
+    pub fn test_limit_buy<S, F>(&self, symbol: S, qty: F, price: Number) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let buy = OrderRequest {
             symbol: symbol.into(),
@@ -217,7 +995,7 @@ impl Account {
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_order(buy);
+        let order = build_order(buy);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
@@ -225,10 +1003,10 @@ impl Account {
     }
 
     // Place a LIMIT order - SELL
-    pub fn limit_sell<S, F>(&self, symbol: S, qty: F, price: f64) -> Result<Transaction>
+    pub fn limit_sell<S, F>(&self, symbol: S, qty: F, price: Number) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
@@ -240,7 +1018,7 @@ impl Account {
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client.post_signed(API::Spot(Spot::Order), request)
     }
@@ -248,10 +1026,10 @@ impl Account {
     /// Place a test LIMIT order - SELL
     ///
     /// This order is sandboxed: it is validated, but not sent to the matching engine.
-    pub fn test_limit_sell<S, F>(&self, symbol: S, qty: F, price: f64) -> Result<()>
+    pub fn test_limit_sell<S, F>(&self, symbol: S, qty: F, price: Number) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
@@ -263,7 +1041,7 @@ impl Account {
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
@@ -274,19 +1052,19 @@ impl Account {
     pub fn market_buy<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let buy = OrderRequest {
             symbol: symbol.into(),
             qty: qty.into(),
-            price: 0.0,
+            price: Number::default(),
             stop_price: None,
             order_side: OrderSide::Buy,
             order_type: OrderType::Market,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_order(buy);
+        let order = build_order(buy);
         let request = build_signed_request(order, self.recv_window)?;
         self.client.post_signed(API::Spot(Spot::Order), request)
     }
@@ -297,19 +1075,19 @@ impl Account {
     pub fn test_market_buy<S, F>(&self, symbol: S, qty: F) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let buy = OrderRequest {
             symbol: symbol.into(),
             qty: qty.into(),
-            price: 0.0,
+            price: Number::default(),
             stop_price: None,
             order_side: OrderSide::Buy,
             order_type: OrderType::Market,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_order(buy);
+        let order = build_order(buy);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
@@ -322,18 +1100,18 @@ impl Account {
     ) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let buy = OrderQuoteQuantityRequest {
             symbol: symbol.into(),
             quote_order_qty: quote_order_qty.into(),
-            price: 0.0,
+            price: Number::default(),
             order_side: OrderSide::Buy,
             order_type: OrderType::Market,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_quote_quantity_order(buy);
+        let order = build_quote_quantity_order(buy);
         let request = build_signed_request(order, self.recv_window)?;
         self.client.post_signed(API::Spot(Spot::Order), request)
     }
@@ -346,18 +1124,18 @@ impl Account {
     ) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let buy = OrderQuoteQuantityRequest {
             symbol: symbol.into(),
             quote_order_qty: quote_order_qty.into(),
-            price: 0.0,
+            price: Number::default(),
             order_side: OrderSide::Buy,
             order_type: OrderType::Market,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_quote_quantity_order(buy);
+        let order = build_quote_quantity_order(buy);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
@@ -368,19 +1146,19 @@ impl Account {
     pub fn market_sell<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
             qty: qty.into(),
-            price: 0.0,
+            price: Number::default(),
             stop_price: None,
             order_side: OrderSide::Sell,
             order_type: OrderType::Market,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client.post_signed(API::Spot(Spot::Order), request)
     }
@@ -391,19 +1169,19 @@ impl Account {
     pub fn test_market_sell<S, F>(&self, symbol: S, qty: F) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
             qty: qty.into(),
-            price: 0.0,
+            price: Number::default(),
             stop_price: None,
             order_side: OrderSide::Sell,
             order_type: OrderType::Market,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
@@ -416,18 +1194,18 @@ impl Account {
     ) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let sell = OrderQuoteQuantityRequest {
             symbol: symbol.into(),
             quote_order_qty: quote_order_qty.into(),
-            price: 0.0,
+            price: Number::default(),
             order_side: OrderSide::Sell,
             order_type: OrderType::Market,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_quote_quantity_order(sell);
+        let order = build_quote_quantity_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client.post_signed(API::Spot(Spot::Order), request)
     }
@@ -440,18 +1218,18 @@ impl Account {
     ) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let sell = OrderQuoteQuantityRequest {
             symbol: symbol.into(),
             quote_order_qty: quote_order_qty.into(),
-            price: 0.0,
+            price: Number::default(),
             order_side: OrderSide::Sell,
             order_type: OrderType::Market,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_quote_quantity_order(sell);
+        let order = build_quote_quantity_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
@@ -469,15 +1247,15 @@ impl Account {
     ///     let api_key = Some("api_key".into());
     ///     let secret_key = Some("secret_key".into());
     ///     let account: Account = Binance::new(api_key, secret_key);
-    ///     let result = account.stop_limit_buy_order("LTCBTC", 1, 0.1, 0.09, TimeInForce::GTC);
+    ///     let result = account.stop_limit_buy_order("LTCBTC", 1, "0.1".parse().unwrap(), "0.09".parse().unwrap(), TimeInForce::GTC);
     /// }
     /// ```
     pub fn stop_limit_buy_order<S, F>(
-        &self, symbol: S, qty: F, price: f64, stop_price: f64, time_in_force: TimeInForce,
+        &self, symbol: S, qty: F, price: Number, stop_price: Number, time_in_force: TimeInForce,
     ) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
@@ -489,7 +1267,7 @@ impl Account {
             time_in_force,
             new_client_order_id: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client.post_signed(API::Spot(Spot::Order), request)
     }
@@ -507,15 +1285,15 @@ impl Account {
     ///     let api_key = Some("api_key".into());
     ///     let secret_key = Some("secret_key".into());
     ///     let account: Account = Binance::new(api_key, secret_key);
-    ///     let result = account.test_stop_limit_buy_order("LTCBTC", 1, 0.1, 0.09, TimeInForce::GTC);
+    ///     let result = account.test_stop_limit_buy_order("LTCBTC", 1, "0.1".parse().unwrap(), "0.09".parse().unwrap(), TimeInForce::GTC);
     /// }
     /// ```
     pub fn test_stop_limit_buy_order<S, F>(
-        &self, symbol: S, qty: F, price: f64, stop_price: f64, time_in_force: TimeInForce,
+        &self, symbol: S, qty: F, price: Number, stop_price: Number, time_in_force: TimeInForce,
     ) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
@@ -527,7 +1305,7 @@ impl Account {
             time_in_force,
             new_client_order_id: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
@@ -545,15 +1323,15 @@ impl Account {
     ///     let api_key = Some("api_key".into());
     ///     let secret_key = Some("secret_key".into());
     ///     let account: Account = Binance::new(api_key, secret_key);
-    ///     let result = account.stop_limit_sell_order("LTCBTC", 1, 0.1, 0.09, TimeInForce::GTC);
+    ///     let result = account.stop_limit_sell_order("LTCBTC", 1, "0.1".parse().unwrap(), "0.09".parse().unwrap(), TimeInForce::GTC);
     /// }
     /// ```
     pub fn stop_limit_sell_order<S, F>(
-        &self, symbol: S, qty: F, price: f64, stop_price: f64, time_in_force: TimeInForce,
+        &self, symbol: S, qty: F, price: Number, stop_price: Number, time_in_force: TimeInForce,
     ) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
@@ -565,7 +1343,7 @@ impl Account {
             time_in_force,
             new_client_order_id: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client.post_signed(API::Spot(Spot::Order), request)
     }
@@ -583,15 +1361,15 @@ impl Account {
     ///     let api_key = Some("api_key".into());
     ///     let secret_key = Some("secret_key".into());
     ///     let account: Account = Binance::new(api_key, secret_key);
-    ///     let result = account.test_stop_limit_sell_order("LTCBTC", 1, 0.1, 0.09, TimeInForce::GTC);
+    ///     let result = account.test_stop_limit_sell_order("LTCBTC", 1, "0.1".parse().unwrap(), "0.09".parse().unwrap(), TimeInForce::GTC);
     /// }
     /// ```
     pub fn test_stop_limit_sell_order<S, F>(
-        &self, symbol: S, qty: F, price: f64, stop_price: f64, time_in_force: TimeInForce,
+        &self, symbol: S, qty: F, price: Number, stop_price: Number, time_in_force: TimeInForce,
     ) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
@@ -603,7 +1381,7 @@ impl Account {
             time_in_force,
             new_client_order_id: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
@@ -613,12 +1391,12 @@ impl Account {
     /// Place a custom order
     #[allow(clippy::too_many_arguments)]
     pub fn custom_order<S, F>(
-        &self, symbol: S, qty: F, price: f64, stop_price: Option<f64>, order_side: OrderSide,
+        &self, symbol: S, qty: F, price: Number, stop_price: Option<Number>, order_side: OrderSide,
         order_type: OrderType, time_in_force: TimeInForce, new_client_order_id: Option<String>,
     ) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
@@ -630,7 +1408,7 @@ impl Account {
             time_in_force,
             new_client_order_id,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client.post_signed(API::Spot(Spot::Order), request)
     }
@@ -640,12 +1418,12 @@ impl Account {
     /// This order is sandboxed: it is validated, but not sent to the matching engine.
     #[allow(clippy::too_many_arguments)]
     pub fn test_custom_order<S, F>(
-        &self, symbol: S, qty: F, price: f64, stop_price: Option<f64>, order_side: OrderSide,
+        &self, symbol: S, qty: F, price: Number, stop_price: Option<Number>, order_side: OrderSide,
         order_type: OrderType, time_in_force: TimeInForce, new_client_order_id: Option<String>,
     ) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
@@ -657,13 +1435,176 @@ impl Account {
             time_in_force,
             new_client_order_id,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
             .map(|_| ())
     }
 
+    /// Place an order built with `SpotOrderRequest`.
+    ///
+    /// The request is validated locally before it is sent. Orders asking
+    /// for an `ACK` response must use `place_order_ack` instead.
+    pub fn place_order(&self, order: SpotOrderRequest) -> Result<Transaction> {
+        if order.new_order_resp_type == Some(NewOrderRespType::ACK) {
+            bail!("ACK responses have no order details, use place_order_ack");
+        }
+        order.validate()?;
+        let request = build_signed_request(build_spot_order(order), self.recv_window)?;
+        self.client.post_signed(API::Spot(Spot::Order), request)
+    }
+
+    /// Place an order built with `SpotOrderRequest`, after checking it against
+    /// the symbol filters so it fails with `ErrorKind::FilterViolations` instead
+    /// of a round trip to the exchange.
+    pub fn place_order_checked(
+        &self, symbol: &Symbol, order: SpotOrderRequest,
+    ) -> Result<Transaction> {
+        order.check_filters(symbol)?;
+        self.place_order(order)
+    }
+
+    /// Place an order built with `SpotOrderRequest`, asking only for an acknowledgement.
+    pub fn place_order_ack(&self, order: SpotOrderRequest) -> Result<TransactionAck> {
+        let order = order.set_new_order_resp_type(NewOrderRespType::ACK);
+        order.validate()?;
+        let request = build_signed_request(build_spot_order(order), self.recv_window)?;
+        self.client.post_signed(API::Spot(Spot::Order), request)
+    }
+
+    /// Place a test order built with `SpotOrderRequest`
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching engine.
+    pub fn test_place_order(&self, order: SpotOrderRequest) -> Result<()> {
+        order.validate()?;
+        let request = build_signed_request(build_spot_order(order), self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .map(|_| ())
+    }
+
+    /// Place an OCO order through the legacy `order/oco` endpoint.
+    pub fn place_oco(&self, order: OcoOrderRequest) -> Result<OrderList> {
+        order.validate()?;
+        let request = build_signed_request(build_oco_order(order), self.recv_window)?;
+        self.client.post_signed(API::Spot(Spot::Oco), request)
+    }
+
+    /// Place an OCO order through `orderList/oco`.
+    pub fn place_order_list_oco(&self, order: OrderListOcoRequest) -> Result<OrderList> {
+        order.validate()?;
+        let request = build_signed_request(build_order_list_oco(order), self.recv_window)?;
+        self.client
+            .post_signed(API::Spot(Spot::OrderListOco), request)
+    }
+
+    /// Cancel an order and place a new one in a single request.
+    ///
+    /// Fails with `ErrorKind::CancelReplaceFailed` when either leg failed, the
+    /// cancel may still have gone through, see `CancelReplaceResponse`.
+    pub fn cancel_replace_order(
+        &self, request: CancelReplaceRequest,
+    ) -> Result<CancelReplaceResponse> {
+        request.validate()?;
+        let request = build_signed_request(build_cancel_replace(request), self.recv_window)?;
+        self.client
+            .post_signed(API::Spot(Spot::CancelReplace), request)
+            .map_err(cancel_replace_error)
+    }
+
+    /// Reduce the quantity of an open order, keeping its priority in the book.
+    pub fn amend_order_keep_priority(&self, request: OrderAmendRequest) -> Result<OrderAmendment> {
+        request.validate()?;
+        let request = build_signed_request(build_order_amend(request), self.recv_window)?;
+        self.client
+            .put_signed(API::Spot(Spot::OrderAmendKeepPriority), request)
+    }
+
+    /// Cancel every order of an order list
+    pub fn cancel_order_list<S>(&self, symbol: S, order_list_id: u64) -> Result<OrderList>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderListId".into(), order_list_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Spot(Spot::OrderList), Some(request))
+    }
+
+    pub fn cancel_order_list_with_client_id<S>(
+        &self, symbol: S, list_client_order_id: String,
+    ) -> Result<OrderList>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("listClientOrderId".into(), list_client_order_id);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Spot(Spot::OrderList), Some(request))
+    }
+
+    // Check an order list's status
+    pub fn order_list_status(&self, order_list_id: u64) -> Result<OrderList> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("orderListId".into(), order_list_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::OrderList), Some(request))
+    }
+
+    pub fn order_list_status_with_client_id(
+        &self, orig_client_order_id: String,
+    ) -> Result<OrderList> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("origClientOrderId".into(), orig_client_order_id);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::OrderList), Some(request))
+    }
+
+    /// All order lists, from `from_id` or within a time window
+    pub fn get_all_order_lists<F, N>(
+        &self, from_id: F, start_time: F, end_time: F, limit: N,
+    ) -> Result<Vec<OrderList>>
+    where
+        F: Into<Option<u64>>,
+        N: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(from_id) = from_id.into() {
+            parameters.insert("fromId".into(), from_id.to_string());
+        }
+        if let Some(start_time) = start_time.into() {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = end_time.into() {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
+        if let Some(limit) = limit.into() {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::AllOrderList), Some(request))
+    }
+
+    // All open order lists
+    pub fn get_open_order_lists(&self) -> Result<Vec<OrderList>> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::OpenOrderList), Some(request))
+    }
+
     // Check an order's status
     pub fn cancel_order<S>(&self, symbol: S, order_id: u64) -> Result<OrderCanceled>
     where
@@ -721,49 +1662,350 @@ impl Account {
             .get_signed(API::Spot(Spot::MyTrades), Some(request))
     }
 
-    fn build_order(&self, order: OrderRequest) -> BTreeMap<String, String> {
-        let mut order_parameters: BTreeMap<String, String> = BTreeMap::new();
-
-        order_parameters.insert("symbol".into(), order.symbol);
-        order_parameters.insert("side".into(), order.order_side.to_string());
-        order_parameters.insert("type".into(), order.order_type.to_string());
-        order_parameters.insert("quantity".into(), order.qty.to_string());
+    /// All orders of a symbol: active, canceled or filled.
+    ///
+    /// The time window can be at most 24 hours, see `all_orders_iter` for longer histories.
+    pub fn get_all_orders(&self, request: OrderHistoryRequest) -> Result<Vec<Order>> {
+        let request = build_signed_request(build_order_history(request), self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::AllOrders), Some(request))
+    }
 
-        if let Some(stop_price) = order.stop_price {
-            order_parameters.insert("stopPrice".into(), stop_price.to_string());
+    /// Every order of a symbol from the request's order ID or start time
+    /// onwards, fetched one page at a time.
+    ///
+    /// The start time is ignored when the request also sets an order ID,
+    /// the end time applies to both.
+    pub fn all_orders_iter(&self, request: OrderHistoryRequest) -> Pages<'_, Order> {
+        let (order_id, start_time, end_time) =
+            (request.order_id, request.start_time, request.end_time);
+        let limit = request.limit.unwrap_or(1000);
+        let fetch = move |page: &PageRequest| {
+            self.get_all_orders(OrderHistoryRequest {
+                order_id: page.from_id,
+                start_time: page.start_time,
+                end_time: page.end_time,
+                limit: Some(page.limit),
+                ..request.clone()
+            })
+        };
+        match (order_id, start_time) {
+            (None, Some(start_time)) => Pages::by_time(fetch, start_time, end_time, DAY, limit),
+            (order_id, _) => Pages::by_id(fetch, order_id.unwrap_or(0), end_time, limit),
         }
+    }
 
-        if order.price != 0.0 {
-            order_parameters.insert("price".into(), order.price.to_string());
-            order_parameters.insert("timeInForce".into(), order.time_in_force.to_string());
-        }
+    /// Trades of a symbol, by order, trade ID or time window.
+    ///
+    /// The time window can be at most 24 hours, see `my_trades_iter` for longer histories.
+    pub fn my_trades(&self, request: TradeHistoryRequest) -> Result<Vec<TradeHistory>> {
+        let request = build_signed_request(build_trade_history(request), self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::MyTrades), Some(request))
+    }
 
-        if let Some(client_order_id) = order.new_client_order_id {
-            order_parameters.insert("newClientOrderId".into(), client_order_id);
+    /// Every trade of a symbol from the request's trade ID or start time
+    /// onwards, fetched one page at a time.
+    ///
+    /// The start time is ignored when the request also sets a trade ID,
+    /// the end time applies to both.
+    pub fn my_trades_iter(&self, request: TradeHistoryRequest) -> Pages<'_, TradeHistory> {
+        let (from_id, start_time, end_time) =
+            (request.from_id, request.start_time, request.end_time);
+        let limit = request.limit.unwrap_or(1000);
+        let fetch = move |page: &PageRequest| {
+            self.my_trades(TradeHistoryRequest {
+                from_id: page.from_id,
+                start_time: page.start_time,
+                end_time: page.end_time,
+                limit: Some(page.limit),
+                ..request.clone()
+            })
+        };
+        match (from_id, start_time) {
+            (None, Some(start_time)) => Pages::by_time(fetch, start_time, end_time, DAY, limit),
+            (from_id, _) => Pages::by_id(fetch, from_id.unwrap_or(0), end_time, limit),
         }
+    }
+}
 
-        order_parameters
+pub(crate) fn build_order(order: OrderRequest) -> BTreeMap<String, String> {
+    let mut order_parameters: BTreeMap<String, String> = BTreeMap::new();
+
+    order_parameters.insert("symbol".into(), order.symbol);
+    order_parameters.insert("side".into(), order.order_side.to_string());
+    order_parameters.insert("type".into(), order.order_type.to_string());
+    order_parameters.insert("quantity".into(), order.qty.to_string());
+
+    if let Some(stop_price) = order.stop_price {
+        order_parameters.insert("stopPrice".into(), stop_price.to_string());
     }
 
-    fn build_quote_quantity_order(
-        &self, order: OrderQuoteQuantityRequest,
-    ) -> BTreeMap<String, String> {
-        let mut order_parameters: BTreeMap<String, String> = BTreeMap::new();
+    if order.price != Number::default() {
+        order_parameters.insert("price".into(), order.price.to_string());
+        order_parameters.insert("timeInForce".into(), order.time_in_force.to_string());
+    }
 
-        order_parameters.insert("symbol".into(), order.symbol);
-        order_parameters.insert("side".into(), order.order_side.to_string());
-        order_parameters.insert("type".into(), order.order_type.to_string());
-        order_parameters.insert("quoteOrderQty".into(), order.quote_order_qty.to_string());
+    if let Some(client_order_id) = order.new_client_order_id {
+        order_parameters.insert("newClientOrderId".into(), client_order_id);
+    }
 
-        if order.price != 0.0 {
-            order_parameters.insert("price".into(), order.price.to_string());
-            order_parameters.insert("timeInForce".into(), order.time_in_force.to_string());
-        }
+    order_parameters
+}
 
-        if let Some(client_order_id) = order.new_client_order_id {
-            order_parameters.insert("newClientOrderId".into(), client_order_id);
-        }
+pub(crate) fn build_quote_quantity_order(
+    order: OrderQuoteQuantityRequest,
+) -> BTreeMap<String, String> {
+    let mut order_parameters: BTreeMap<String, String> = BTreeMap::new();
+
+    order_parameters.insert("symbol".into(), order.symbol);
+    order_parameters.insert("side".into(), order.order_side.to_string());
+    order_parameters.insert("type".into(), order.order_type.to_string());
+    order_parameters.insert("quoteOrderQty".into(), order.quote_order_qty.to_string());
+
+    if order.price != Number::default() {
+        order_parameters.insert("price".into(), order.price.to_string());
+        order_parameters.insert("timeInForce".into(), order.time_in_force.to_string());
+    }
+
+    if let Some(client_order_id) = order.new_client_order_id {
+        order_parameters.insert("newClientOrderId".into(), client_order_id);
+    }
+
+    order_parameters
+}
+
+pub(crate) fn build_spot_order(order: SpotOrderRequest) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+    parameters.insert("symbol".into(), order.symbol);
+    parameters.insert("side".into(), order.side.to_string());
+    parameters.insert("type".into(), order.order_type.to_string());
+
+    if let Some(time_in_force) = order.time_in_force {
+        parameters.insert("timeInForce".into(), time_in_force.to_string());
+    }
+    if let Some(qty) = order.qty {
+        parameters.insert("quantity".into(), qty.to_string());
+    }
+    if let Some(quote_order_qty) = order.quote_order_qty {
+        parameters.insert("quoteOrderQty".into(), quote_order_qty.to_string());
+    }
+    if let Some(price) = order.price {
+        parameters.insert("price".into(), price.to_string());
+    }
+    if let Some(new_client_order_id) = order.new_client_order_id {
+        parameters.insert("newClientOrderId".into(), new_client_order_id);
+    }
+    if let Some(strategy_id) = order.strategy_id {
+        parameters.insert("strategyId".into(), strategy_id.to_string());
+    }
+    if let Some(strategy_type) = order.strategy_type {
+        parameters.insert("strategyType".into(), strategy_type.to_string());
+    }
+    if let Some(stop_price) = order.stop_price {
+        parameters.insert("stopPrice".into(), stop_price.to_string());
+    }
+    if let Some(trailing_delta) = order.trailing_delta {
+        parameters.insert("trailingDelta".into(), trailing_delta.to_string());
+    }
+    if let Some(iceberg_qty) = order.iceberg_qty {
+        parameters.insert("icebergQty".into(), iceberg_qty.to_string());
+    }
+    if let Some(new_order_resp_type) = order.new_order_resp_type {
+        parameters.insert("newOrderRespType".into(), new_order_resp_type.to_string());
+    }
+    if let Some(mode) = order.self_trade_prevention_mode {
+        parameters.insert("selfTradePreventionMode".into(), mode.to_string());
+    }
+
+    parameters
+}
 
-        order_parameters
+pub(crate) fn build_oco_order(order: OcoOrderRequest) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+    parameters.insert("symbol".into(), order.symbol);
+    parameters.insert("side".into(), order.side.to_string());
+    parameters.insert("quantity".into(), order.qty.to_string());
+    parameters.insert("price".into(), order.price.to_string());
+
+    if let Some(stop_price) = order.stop_price {
+        parameters.insert("stopPrice".into(), stop_price.to_string());
+    }
+    if let Some(trailing_delta) = order.trailing_delta {
+        parameters.insert("trailingDelta".into(), trailing_delta.to_string());
+    }
+    if let Some(stop_limit_price) = order.stop_limit_price {
+        parameters.insert("stopLimitPrice".into(), stop_limit_price.to_string());
+    }
+    if let Some(time_in_force) = order.stop_limit_time_in_force {
+        parameters.insert("stopLimitTimeInForce".into(), time_in_force.to_string());
+    }
+    if let Some(list_client_order_id) = order.list_client_order_id {
+        parameters.insert("listClientOrderId".into(), list_client_order_id);
+    }
+    if let Some(client_order_id) = order.limit_client_order_id {
+        parameters.insert("limitClientOrderId".into(), client_order_id);
+    }
+    if let Some(iceberg_qty) = order.limit_iceberg_qty {
+        parameters.insert("limitIcebergQty".into(), iceberg_qty.to_string());
+    }
+    if let Some(strategy_id) = order.limit_strategy_id {
+        parameters.insert("limitStrategyId".into(), strategy_id.to_string());
+    }
+    if let Some(strategy_type) = order.limit_strategy_type {
+        parameters.insert("limitStrategyType".into(), strategy_type.to_string());
+    }
+    if let Some(client_order_id) = order.stop_client_order_id {
+        parameters.insert("stopClientOrderId".into(), client_order_id);
+    }
+    if let Some(iceberg_qty) = order.stop_iceberg_qty {
+        parameters.insert("stopIcebergQty".into(), iceberg_qty.to_string());
+    }
+    if let Some(strategy_id) = order.stop_strategy_id {
+        parameters.insert("stopStrategyId".into(), strategy_id.to_string());
+    }
+    if let Some(strategy_type) = order.stop_strategy_type {
+        parameters.insert("stopStrategyType".into(), strategy_type.to_string());
+    }
+    if let Some(new_order_resp_type) = order.new_order_resp_type {
+        parameters.insert("newOrderRespType".into(), new_order_resp_type.to_string());
+    }
+    if let Some(mode) = order.self_trade_prevention_mode {
+        parameters.insert("selfTradePreventionMode".into(), mode.to_string());
+    }
+
+    parameters
+}
+
+pub(crate) fn build_order_list_oco(order: OrderListOcoRequest) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+    parameters.insert("symbol".into(), order.symbol);
+    parameters.insert("side".into(), order.side.to_string());
+    parameters.insert("quantity".into(), order.qty.to_string());
+    order.above.insert("above", &mut parameters);
+    order.below.insert("below", &mut parameters);
+
+    if let Some(list_client_order_id) = order.list_client_order_id {
+        parameters.insert("listClientOrderId".into(), list_client_order_id);
+    }
+    if let Some(new_order_resp_type) = order.new_order_resp_type {
+        parameters.insert("newOrderRespType".into(), new_order_resp_type.to_string());
+    }
+    if let Some(mode) = order.self_trade_prevention_mode {
+        parameters.insert("selfTradePreventionMode".into(), mode.to_string());
+    }
+
+    parameters
+}
+
+pub(crate) fn build_order_history(request: OrderHistoryRequest) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+    parameters.insert("symbol".into(), request.symbol);
+
+    if let Some(order_id) = request.order_id {
+        parameters.insert("orderId".into(), order_id.to_string());
+    }
+    if let Some(start_time) = request.start_time {
+        parameters.insert("startTime".into(), start_time.to_string());
+    }
+    if let Some(end_time) = request.end_time {
+        parameters.insert("endTime".into(), end_time.to_string());
+    }
+    if let Some(limit) = request.limit {
+        parameters.insert("limit".into(), limit.to_string());
+    }
+
+    parameters
+}
+
+pub(crate) fn build_trade_history(request: TradeHistoryRequest) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+    parameters.insert("symbol".into(), request.symbol);
+
+    if let Some(order_id) = request.order_id {
+        parameters.insert("orderId".into(), order_id.to_string());
+    }
+    if let Some(start_time) = request.start_time {
+        parameters.insert("startTime".into(), start_time.to_string());
+    }
+    if let Some(end_time) = request.end_time {
+        parameters.insert("endTime".into(), end_time.to_string());
+    }
+    if let Some(from_id) = request.from_id {
+        parameters.insert("fromId".into(), from_id.to_string());
+    }
+    if let Some(limit) = request.limit {
+        parameters.insert("limit".into(), limit.to_string());
+    }
+
+    parameters
+}
+
+pub(crate) fn build_cancel_replace(request: CancelReplaceRequest) -> BTreeMap<String, String> {
+    let mut parameters = build_spot_order(request.order);
+    parameters.insert(
+        "cancelReplaceMode".into(),
+        request.cancel_replace_mode.to_string(),
+    );
+
+    if let Some(order_id) = request.cancel_order_id {
+        parameters.insert("cancelOrderId".into(), order_id.to_string());
+    }
+    if let Some(client_order_id) = request.cancel_orig_client_order_id {
+        parameters.insert("cancelOrigClientOrderId".into(), client_order_id);
+    }
+    if let Some(client_order_id) = request.cancel_new_client_order_id {
+        parameters.insert("cancelNewClientOrderId".into(), client_order_id);
+    }
+    if let Some(cancel_restrictions) = request.cancel_restrictions {
+        parameters.insert("cancelRestrictions".into(), cancel_restrictions.to_string());
+    }
+    if let Some(mode) = request.order_rate_limit_exceeded_mode {
+        parameters.insert("orderRateLimitExceededMode".into(), mode.to_string());
+    }
+
+    parameters
+}
+
+pub(crate) fn build_order_amend(request: OrderAmendRequest) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+    parameters.insert("symbol".into(), request.symbol);
+    parameters.insert("newQty".into(), request.new_qty.to_string());
+
+    if let Some(order_id) = request.order_id {
+        parameters.insert("orderId".into(), order_id.to_string());
+    }
+    if let Some(client_order_id) = request.orig_client_order_id {
+        parameters.insert("origClientOrderId".into(), client_order_id);
+    }
+    if let Some(client_order_id) = request.new_client_order_id {
+        parameters.insert("newClientOrderId".into(), client_order_id);
+    }
+
+    parameters
+}
+
+// -2021 and -2022 carry the outcome of each leg in `data`
+pub(crate) fn cancel_replace_error(error: Error) -> Error {
+    #[derive(Deserialize)]
+    struct FailureBody {
+        data: CancelReplaceResponse,
+    }
+
+    let Error(kind, state) = error;
+    match kind {
+        ErrorKind::BinanceError(response)
+            if matches!(
+                response.error_code(),
+                BinanceErrorCode::CancelReplaceFailed |
+                    BinanceErrorCode::CancelReplacePartiallyFailed
+            ) =>
+        {
+            match serde_json::from_str::<FailureBody>(&response.body) {
+                Ok(body) => ErrorKind::CancelReplaceFailed(response, body.data).into(),
+                Err(_) => Error(ErrorKind::BinanceError(response), state),
+            }
+        }
+        kind => Error(kind, state),
     }
 }
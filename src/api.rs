@@ -4,22 +4,30 @@ use crate::config::Config;
 use crate::futures::account::FuturesAccount;
 use crate::futures::general::FuturesGeneral;
 use crate::futures::market::FuturesMarket;
+use crate::futures::rest::FuturesRest;
 use crate::futures::userstream::FuturesUserStream;
 use crate::general::General;
 use crate::market::Market;
+use crate::rest::Rest;
 use crate::userstream::UserStream;
+use crate::margin::Margin;
 use crate::savings::Savings;
+use crate::subaccount::SubAccount;
 
 #[allow(clippy::all)]
+#[derive(Clone, Copy)]
 pub enum API {
     Spot(Spot),
     Savings(Sapi),
+    Margin(MarginSapi),
+    SubAccount(SubAccountSapi),
     Futures(Futures),
 }
 
 /// Endpoint for production and test orders.
 ///
 /// Orders issued to test are validated, but not sent into the matching engine.
+#[derive(Clone, Copy)]
 pub enum Spot {
     Ping,
     Time,
@@ -35,9 +43,12 @@ pub enum Spot {
     BookTicker,
     Order,
     OrderTest,
+    CancelReplace,
+    OrderAmendKeepPriority,
     OpenOrders,
     AllOrders,
     Oco,
+    OrderListOco,
     OrderList,
     AllOrderList,
     OpenOrderList,
@@ -46,13 +57,64 @@ pub enum Spot {
     UserDataStream,
 }
 
+#[derive(Clone, Copy)]
 pub enum Sapi {
     AllCoins,
     AssetDetail,
     DepositAddress,
+    DepositHistory,
+    Withdraw,
+    WithdrawHistory,
     SpotFuturesTransfer,
+    UniversalTransfer,
+    FlexibleProducts,
+    LockedProducts,
+    FlexibleSubscribe,
+    LockedSubscribe,
+    FlexibleRedeem,
+    LockedRedeem,
+    FlexiblePosition,
+    LockedPosition,
+    FlexibleSubscriptionRecord,
+    LockedSubscriptionRecord,
+    FlexibleRedemptionRecord,
+    LockedRedemptionRecord,
+    FlexibleRewardsRecord,
+    LockedRewardsRecord,
 }
 
+/// Cross and isolated margin endpoints.
+#[derive(Clone, Copy)]
+pub enum MarginSapi {
+    Account,
+    IsolatedAccount,
+    BorrowRepay,
+    MaxBorrowable,
+    MaxTransferable,
+    Order,
+    Oco,
+    OrderList,
+    OpenOrders,
+    AllOrders,
+    MyTrades,
+    InterestHistory,
+    Transfer,
+    IsolatedTransfer,
+}
+
+/// Sub-account endpoints, called with the master account's key.
+#[derive(Clone, Copy)]
+pub enum SubAccountSapi {
+    List,
+    SpotAssets,
+    FuturesAccount,
+    MarginAccount,
+    UniversalTransfer,
+    EnableFutures,
+    EnableMargin,
+}
+
+#[derive(Clone, Copy)]
 pub enum Futures {
     Ping,
     Time,
@@ -115,6 +177,9 @@ impl From<API> for String {
                 Spot::OpenOrders => "/api/v3/openOrders",
                 Spot::AllOrders => "/api/v3/allOrders",
                 Spot::Oco => "/api/v3/order/oco",
+                Spot::OrderListOco => "/api/v3/orderList/oco",
+                Spot::CancelReplace => "/api/v3/order/cancelReplace",
+                Spot::OrderAmendKeepPriority => "/api/v3/order/amend/keepPriority",
                 Spot::OrderList => "/api/v3/orderList",
                 Spot::AllOrderList => "/api/v3/allOrderList",
                 Spot::OpenOrderList => "/api/v3/openOrderList",
@@ -126,7 +191,60 @@ impl From<API> for String {
                 Sapi::AllCoins => "/sapi/v1/capital/config/getall",
                 Sapi::AssetDetail => "/sapi/v1/asset/assetDetail",
                 Sapi::DepositAddress => "/sapi/v1/capital/deposit/address",
+                Sapi::DepositHistory => "/sapi/v1/capital/deposit/hisrec",
+                Sapi::Withdraw => "/sapi/v1/capital/withdraw/apply",
+                Sapi::WithdrawHistory => "/sapi/v1/capital/withdraw/history",
                 Sapi::SpotFuturesTransfer => "/sapi/v1/futures/transfer",
+                Sapi::UniversalTransfer => "/sapi/v1/asset/transfer",
+                Sapi::FlexibleProducts => "/sapi/v1/simple-earn/flexible/list",
+                Sapi::LockedProducts => "/sapi/v1/simple-earn/locked/list",
+                Sapi::FlexibleSubscribe => "/sapi/v1/simple-earn/flexible/subscribe",
+                Sapi::LockedSubscribe => "/sapi/v1/simple-earn/locked/subscribe",
+                Sapi::FlexibleRedeem => "/sapi/v1/simple-earn/flexible/redeem",
+                Sapi::LockedRedeem => "/sapi/v1/simple-earn/locked/redeem",
+                Sapi::FlexiblePosition => "/sapi/v1/simple-earn/flexible/position",
+                Sapi::LockedPosition => "/sapi/v1/simple-earn/locked/position",
+                Sapi::FlexibleSubscriptionRecord => {
+                    "/sapi/v1/simple-earn/flexible/history/subscriptionRecord"
+                }
+                Sapi::LockedSubscriptionRecord => {
+                    "/sapi/v1/simple-earn/locked/history/subscriptionRecord"
+                }
+                Sapi::FlexibleRedemptionRecord => {
+                    "/sapi/v1/simple-earn/flexible/history/redemptionRecord"
+                }
+                Sapi::LockedRedemptionRecord => {
+                    "/sapi/v1/simple-earn/locked/history/redemptionRecord"
+                }
+                Sapi::FlexibleRewardsRecord => {
+                    "/sapi/v1/simple-earn/flexible/history/rewardsRecord"
+                }
+                Sapi::LockedRewardsRecord => "/sapi/v1/simple-earn/locked/history/rewardsRecord",
+            },
+            API::Margin(route) => match route {
+                MarginSapi::Account => "/sapi/v1/margin/account",
+                MarginSapi::IsolatedAccount => "/sapi/v1/margin/isolated/account",
+                MarginSapi::BorrowRepay => "/sapi/v1/margin/borrow-repay",
+                MarginSapi::MaxBorrowable => "/sapi/v1/margin/maxBorrowable",
+                MarginSapi::MaxTransferable => "/sapi/v1/margin/maxTransferable",
+                MarginSapi::Order => "/sapi/v1/margin/order",
+                MarginSapi::Oco => "/sapi/v1/margin/order/oco",
+                MarginSapi::OrderList => "/sapi/v1/margin/orderList",
+                MarginSapi::OpenOrders => "/sapi/v1/margin/openOrders",
+                MarginSapi::AllOrders => "/sapi/v1/margin/allOrders",
+                MarginSapi::MyTrades => "/sapi/v1/margin/myTrades",
+                MarginSapi::InterestHistory => "/sapi/v1/margin/interestHistory",
+                MarginSapi::Transfer => "/sapi/v1/margin/transfer",
+                MarginSapi::IsolatedTransfer => "/sapi/v1/margin/isolated/transfer",
+            },
+            API::SubAccount(route) => match route {
+                SubAccountSapi::List => "/sapi/v1/sub-account/list",
+                SubAccountSapi::SpotAssets => "/sapi/v3/sub-account/assets",
+                SubAccountSapi::FuturesAccount => "/sapi/v2/sub-account/futures/account",
+                SubAccountSapi::MarginAccount => "/sapi/v1/sub-account/margin/account",
+                SubAccountSapi::UniversalTransfer => "/sapi/v1/sub-account/universalTransfer",
+                SubAccountSapi::EnableFutures => "/sapi/v1/sub-account/futures/enable",
+                SubAccountSapi::EnableMargin => "/sapi/v1/sub-account/margin/enable",
             },
             API::Futures(route) => match route {
                 Futures::Ping => "/fapi/v1/ping",
@@ -172,6 +290,73 @@ impl From<API> for String {
     }
 }
 
+impl API {
+    // Default request weights from the Binance docs, used to reserve budget before a
+    // request is sent. The weight reported in the response headers always wins.
+    pub(crate) fn weight(&self) -> u64 {
+        match self {
+            API::Spot(route) => match route {
+                Spot::Ping |
+                Spot::Time |
+                Spot::Order |
+                Spot::OrderTest |
+                Spot::Oco |
+                Spot::OrderListOco |
+                Spot::CancelReplace => 1,
+                Spot::Klines |
+                Spot::AggTrades |
+                Spot::AvgPrice |
+                Spot::Ticker24hr |
+                Spot::Price |
+                Spot::BookTicker |
+                Spot::OrderList |
+                Spot::UserDataStream => 2,
+                Spot::OrderAmendKeepPriority => 4,
+                Spot::Depth => 5,
+                Spot::OpenOrders | Spot::OpenOrderList => 6,
+                Spot::ExchangeInfo |
+                Spot::AllOrders |
+                Spot::AllOrderList |
+                Spot::Account |
+                Spot::MyTrades => 20,
+                Spot::Trades | Spot::HistoricalTrades => 25,
+            },
+            // SAPI endpoints are counted separately from X-MBX-USED-WEIGHT
+            API::Savings(_) | API::Margin(_) | API::SubAccount(_) => 0,
+            API::Futures(route) => match route {
+                Futures::Depth |
+                Futures::Trades |
+                Futures::Klines |
+                Futures::ContinuousKlines |
+                Futures::IndexPriceKlines |
+                Futures::MarkPriceKlines |
+                Futures::AllOrders |
+                Futures::UserTrades |
+                Futures::BatchOrders |
+                Futures::PositionRisk |
+                Futures::Balance |
+                Futures::Account => 5,
+                Futures::HistoricalTrades | Futures::AggTrades | Futures::AllForceOrders => 20,
+                Futures::Income => 30,
+                _ => 1,
+            },
+        }
+    }
+
+    // Whether a POST to this endpoint counts against the ORDERS limits
+    pub(crate) fn is_order(&self) -> bool {
+        matches!(
+            self,
+            API::Spot(Spot::Order) |
+                API::Spot(Spot::Oco) |
+                API::Spot(Spot::OrderListOco) |
+                API::Spot(Spot::CancelReplace) |
+                API::Futures(Futures::Order) |
+                API::Futures(Futures::BatchOrders)
+        )
+    }
+}
+
 pub trait Binance {
     fn new(api_key: Option<String>, secret_key: Option<String>) -> Self;
     fn new_with_config(
@@ -188,7 +373,7 @@ impl Binance for General {
         api_key: Option<String>, secret_key: Option<String>, config: &Config,
     ) -> General {
         General {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
+            client: Client::from_config(api_key, secret_key, config, false),
         }
     }
 }
@@ -202,7 +387,7 @@ impl Binance for Account {
         api_key: Option<String>, secret_key: Option<String>, config: &Config,
     ) -> Account {
         Account {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
+            client: Client::from_config(api_key, secret_key, config, false),
             recv_window: config.recv_window,
         }
     }
@@ -217,7 +402,52 @@ impl Binance for Savings {
         api_key: Option<String>, secret_key: Option<String>, config: &Config,
     ) -> Self {
         Self {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
+            client: Client::from_config(api_key, secret_key, config, false),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+impl Binance for Margin {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: Client::from_config(api_key, secret_key, config, false),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+impl Binance for SubAccount {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: Client::from_config(api_key, secret_key, config, false),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+impl Binance for Rest {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: Client::from_config(api_key, secret_key, config, false),
             recv_window: config.recv_window,
         }
     }
@@ -232,7 +462,7 @@ impl Binance for Market {
         api_key: Option<String>, secret_key: Option<String>, config: &Config,
     ) -> Market {
         Market {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
+            client: Client::from_config(api_key, secret_key, config, false),
             recv_window: config.recv_window,
         }
     }
@@ -247,7 +477,7 @@ impl Binance for UserStream {
         api_key: Option<String>, secret_key: Option<String>, config: &Config,
     ) -> UserStream {
         UserStream {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
+            client: Client::from_config(api_key, secret_key, config, false),
             recv_window: config.recv_window,
         }
     }
@@ -266,11 +496,7 @@ impl Binance for FuturesGeneral {
         api_key: Option<String>, secret_key: Option<String>, config: &Config,
     ) -> FuturesGeneral {
         FuturesGeneral {
-            client: Client::new(
-                api_key,
-                secret_key,
-                config.futures_rest_api_endpoint.clone(),
-            ),
+            client: Client::from_config(api_key, secret_key, config, true),
         }
     }
 }
@@ -284,11 +510,7 @@ impl Binance for FuturesMarket {
         api_key: Option<String>, secret_key: Option<String>, config: &Config,
     ) -> FuturesMarket {
         FuturesMarket {
-            client: Client::new(
-                api_key,
-                secret_key,
-                config.futures_rest_api_endpoint.clone(),
-            ),
+            client: Client::from_config(api_key, secret_key, config, true),
             recv_window: config.recv_window,
         }
     }
@@ -303,11 +525,7 @@ impl Binance for FuturesAccount {
         api_key: Option<String>, secret_key: Option<String>, config: &Config,
     ) -> Self {
         Self {
-            client: Client::new(
-                api_key,
-                secret_key,
-                config.futures_rest_api_endpoint.clone(),
-            ),
+            client: Client::from_config(api_key, secret_key, config, true),
             recv_window: config.recv_window,
         }
     }
@@ -322,12 +540,240 @@ impl Binance for FuturesUserStream {
         api_key: Option<String>, secret_key: Option<String>, config: &Config,
     ) -> FuturesUserStream {
         FuturesUserStream {
-            client: Client::new(
-                api_key,
-                secret_key,
-                config.futures_rest_api_endpoint.clone(),
-            ),
+            client: Client::from_config(api_key, secret_key, config, true),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+impl Binance for FuturesRest {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> FuturesRest {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> FuturesRest {
+        FuturesRest {
+            client: Client::from_config(api_key, secret_key, config, true),
             recv_window: config.recv_window,
         }
     }
 }
+
+// *****************************************************
+//              Binance Async API
+// *****************************************************
+
+#[cfg(feature = "async")]
+mod nonblocking {
+    use super::Binance;
+    use crate::config::Config;
+    use crate::nonblocking::account::Account;
+    use crate::nonblocking::futures::account::FuturesAccount;
+    use crate::nonblocking::futures::general::FuturesGeneral;
+    use crate::nonblocking::futures::market::FuturesMarket;
+    use crate::nonblocking::futures::rest::FuturesRest;
+    use crate::nonblocking::futures::userstream::FuturesUserStream;
+    use crate::nonblocking::general::General;
+    use crate::nonblocking::market::Market;
+    use crate::nonblocking::rest::Rest;
+    use crate::nonblocking::margin::Margin;
+    use crate::nonblocking::savings::Savings;
+    use crate::nonblocking::subaccount::SubAccount;
+    use crate::nonblocking::userstream::UserStream;
+    use crate::nonblocking::Client;
+
+    impl Binance for General {
+        fn new(api_key: Option<String>, secret_key: Option<String>) -> General {
+            Self::new_with_config(api_key, secret_key, &Config::default())
+        }
+
+        fn new_with_config(
+            api_key: Option<String>, secret_key: Option<String>, config: &Config,
+        ) -> General {
+            General {
+                client: Client::from_config(api_key, secret_key, config, false),
+            }
+        }
+    }
+
+    impl Binance for Account {
+        fn new(api_key: Option<String>, secret_key: Option<String>) -> Account {
+            Self::new_with_config(api_key, secret_key, &Config::default())
+        }
+
+        fn new_with_config(
+            api_key: Option<String>, secret_key: Option<String>, config: &Config,
+        ) -> Account {
+            Account {
+                client: Client::from_config(api_key, secret_key, config, false),
+                recv_window: config.recv_window,
+            }
+        }
+    }
+
+    impl Binance for Savings {
+        fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+            Self::new_with_config(api_key, secret_key, &Config::default())
+        }
+
+        fn new_with_config(
+            api_key: Option<String>, secret_key: Option<String>, config: &Config,
+        ) -> Self {
+            Self {
+                client: Client::from_config(api_key, secret_key, config, false),
+                recv_window: config.recv_window,
+            }
+        }
+    }
+
+    impl Binance for Margin {
+        fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+            Self::new_with_config(api_key, secret_key, &Config::default())
+        }
+
+        fn new_with_config(
+            api_key: Option<String>, secret_key: Option<String>, config: &Config,
+        ) -> Self {
+            Self {
+                client: Client::from_config(api_key, secret_key, config, false),
+                recv_window: config.recv_window,
+            }
+        }
+    }
+
+    impl Binance for SubAccount {
+        fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+            Self::new_with_config(api_key, secret_key, &Config::default())
+        }
+
+        fn new_with_config(
+            api_key: Option<String>, secret_key: Option<String>, config: &Config,
+        ) -> Self {
+            Self {
+                client: Client::from_config(api_key, secret_key, config, false),
+                recv_window: config.recv_window,
+            }
+        }
+    }
+
+    impl Binance for Rest {
+        fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+            Self::new_with_config(api_key, secret_key, &Config::default())
+        }
+
+        fn new_with_config(
+            api_key: Option<String>, secret_key: Option<String>, config: &Config,
+        ) -> Self {
+            Self {
+                client: Client::from_config(api_key, secret_key, config, false),
+                recv_window: config.recv_window,
+            }
+        }
+    }
+
+    impl Binance for Market {
+        fn new(api_key: Option<String>, secret_key: Option<String>) -> Market {
+            Self::new_with_config(api_key, secret_key, &Config::default())
+        }
+
+        fn new_with_config(
+            api_key: Option<String>, secret_key: Option<String>, config: &Config,
+        ) -> Market {
+            Market {
+                client: Client::from_config(api_key, secret_key, config, false),
+                recv_window: config.recv_window,
+            }
+        }
+    }
+
+    impl Binance for UserStream {
+        fn new(api_key: Option<String>, secret_key: Option<String>) -> UserStream {
+            Self::new_with_config(api_key, secret_key, &Config::default())
+        }
+
+        fn new_with_config(
+            api_key: Option<String>, secret_key: Option<String>, config: &Config,
+        ) -> UserStream {
+            UserStream {
+                client: Client::from_config(api_key, secret_key, config, false),
+                recv_window: config.recv_window,
+            }
+        }
+    }
+
+    impl Binance for FuturesGeneral {
+        fn new(api_key: Option<String>, secret_key: Option<String>) -> FuturesGeneral {
+            Self::new_with_config(api_key, secret_key, &Config::default())
+        }
+
+        fn new_with_config(
+            api_key: Option<String>, secret_key: Option<String>, config: &Config,
+        ) -> FuturesGeneral {
+            FuturesGeneral {
+                client: Client::from_config(api_key, secret_key, config, true),
+            }
+        }
+    }
+
+    impl Binance for FuturesMarket {
+        fn new(api_key: Option<String>, secret_key: Option<String>) -> FuturesMarket {
+            Self::new_with_config(api_key, secret_key, &Config::default())
+        }
+
+        fn new_with_config(
+            api_key: Option<String>, secret_key: Option<String>, config: &Config,
+        ) -> FuturesMarket {
+            FuturesMarket {
+                client: Client::from_config(api_key, secret_key, config, true),
+                recv_window: config.recv_window,
+            }
+        }
+    }
+
+    impl Binance for FuturesAccount {
+        fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+            Self::new_with_config(api_key, secret_key, &Config::default())
+        }
+
+        fn new_with_config(
+            api_key: Option<String>, secret_key: Option<String>, config: &Config,
+        ) -> Self {
+            Self {
+                client: Client::from_config(api_key, secret_key, config, true),
+                recv_window: config.recv_window,
+            }
+        }
+    }
+
+    impl Binance for FuturesUserStream {
+        fn new(api_key: Option<String>, secret_key: Option<String>) -> FuturesUserStream {
+            Self::new_with_config(api_key, secret_key, &Config::default())
+        }
+
+        fn new_with_config(
+            api_key: Option<String>, secret_key: Option<String>, config: &Config,
+        ) -> FuturesUserStream {
+            FuturesUserStream {
+                client: Client::from_config(api_key, secret_key, config, true),
+                recv_window: config.recv_window,
+            }
+        }
+    }
+
+    impl Binance for FuturesRest {
+        fn new(api_key: Option<String>, secret_key: Option<String>) -> FuturesRest {
+            Self::new_with_config(api_key, secret_key, &Config::default())
+        }
+
+        fn new_with_config(
+            api_key: Option<String>, secret_key: Option<String>, config: &Config,
+        ) -> FuturesRest {
+            FuturesRest {
+                client: Client::from_config(api_key, secret_key, config, true),
+                recv_window: config.recv_window,
+            }
+        }
+    }
+}
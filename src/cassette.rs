@@ -0,0 +1,310 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use error_chain::bail;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+
+use crate::errors::Result;
+#[cfg(feature = "async")]
+use crate::transport::AsyncTransport;
+use crate::transport::{HttpRequest, HttpResponse, ReqwestTransport, Transport};
+
+const REDACTED: &str = "REDACTED";
+
+/// Recorded REST interactions and websocket frames, saved as JSON.
+///
+/// Signatures are redacted and the `X-MBX-APIKEY` header is never written, so
+/// cassettes recorded with real keys can be committed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+    /// Text frames in the order they were received.
+    pub frames: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interaction {
+    pub method: String,
+    /// Path and query, without the host.
+    pub url: String,
+    pub body: Option<String>,
+    pub status: u16,
+    pub headers: BTreeMap<String, String>,
+    pub response: String,
+}
+
+impl Cassette {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let content = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+/// Transport that sends requests through another transport and records them.
+///
+/// Pass a clone to `Config::set_transport`, and to `WebSockets::set_recorder` to
+/// capture frames in the same cassette:
+///
+/// ```no_run
+/// use binance::api::*;
+/// use binance::cassette::Recorder;
+/// use binance::config::Config;
+/// use binance::market::Market;
+///
+/// let recorder = Recorder::default();
+/// let config = Config::default().set_transport(recorder.clone());
+/// let market: Market = Binance::new_with_config(None, None, &config);
+/// market.get_price("BTCUSDT").unwrap();
+/// recorder.save("tests/cassettes/get_price.json").unwrap();
+/// ```
+///
+/// Async clients record through `Recorder::new_async` and
+/// `Config::set_async_transport` the same way.
+#[derive(Debug, Clone)]
+pub struct Recorder {
+    inner: Inner,
+    cassette: Arc<Mutex<Cassette>>,
+}
+
+#[derive(Debug, Clone)]
+enum Inner {
+    Blocking(Arc<dyn Transport>),
+    #[cfg(feature = "async")]
+    Async(Arc<dyn AsyncTransport>),
+}
+
+impl Default for Recorder {
+    fn default() -> Self {
+        Self::new(ReqwestTransport::default())
+    }
+}
+
+impl Recorder {
+    pub fn new<T: Transport + 'static>(inner: T) -> Self {
+        Self::with_inner(Inner::Blocking(Arc::new(inner)))
+    }
+
+    /// Record the requests of async clients, sent through `inner`.
+    #[cfg(feature = "async")]
+    pub fn new_async<T: AsyncTransport + 'static>(inner: T) -> Self {
+        Self::with_inner(Inner::Async(Arc::new(inner)))
+    }
+
+    fn with_inner(inner: Inner) -> Self {
+        Recorder {
+            inner,
+            cassette: Arc::new(Mutex::new(Cassette::default())),
+        }
+    }
+
+    /// Snapshot of everything recorded so far.
+    pub fn cassette(&self) -> Cassette {
+        self.cassette.lock().unwrap().clone()
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        self.cassette().save(path)
+    }
+
+    pub(crate) fn record_frame(&self, frame: &str) {
+        self.cassette.lock().unwrap().frames.push(frame.to_string());
+    }
+
+    fn record(&self, request: Interaction, response: &HttpResponse) {
+        let headers = response
+            .headers
+            .iter()
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+        self.cassette
+            .lock()
+            .unwrap()
+            .interactions
+            .push(Interaction {
+                status: response.status.as_u16(),
+                headers,
+                response: response.body.clone(),
+                ..request
+            });
+    }
+}
+
+// The request half of an interaction, completed once the response arrives
+fn interaction(request: &HttpRequest) -> Interaction {
+    Interaction {
+        method: request.method.to_string(),
+        url: redact(&path_and_query(&request.url)),
+        body: request.body.as_ref().map(|body| redact(body)),
+        status: 0,
+        headers: BTreeMap::new(),
+        response: String::new(),
+    }
+}
+
+impl Transport for Recorder {
+    // Without the async feature the blocking transport is the only one
+    #[cfg_attr(not(feature = "async"), allow(clippy::infallible_destructuring_match))]
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let inner = match &self.inner {
+            Inner::Blocking(inner) => inner,
+            #[cfg(feature = "async")]
+            Inner::Async(_) => bail!("Recorder wraps an async transport"),
+        };
+        let recorded = interaction(&request);
+        let response = inner.send(request)?;
+        self.record(recorded, &response);
+        Ok(response)
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncTransport for Recorder {
+    fn send<'a>(
+        &'a self, request: HttpRequest,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<HttpResponse>> + Send + 'a>>
+    {
+        Box::pin(async move {
+            let inner = match &self.inner {
+                Inner::Async(inner) => inner,
+                Inner::Blocking(_) => bail!("Recorder wraps a blocking transport"),
+            };
+            let recorded = interaction(&request);
+            let response = inner.send(request).await?;
+            self.record(recorded, &response);
+            Ok(response)
+        })
+    }
+}
+
+/// Transport that answers from a cassette without touching the network.
+///
+/// A request is matched on method, path, query and body, ignoring `timestamp` and
+/// `signature`. Interactions are served once each, in the recorded order.
+#[derive(Debug, Clone)]
+pub struct Replayer {
+    interactions: Arc<Mutex<Vec<(Interaction, bool)>>>,
+}
+
+impl Replayer {
+    pub fn new(cassette: Cassette) -> Self {
+        Replayer {
+            interactions: Arc::new(Mutex::new(
+                cassette
+                    .interactions
+                    .into_iter()
+                    .map(|interaction| (interaction, false))
+                    .collect(),
+            )),
+        }
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Ok(Self::new(Cassette::load(path)?))
+    }
+
+    fn replay(&self, request: &HttpRequest) -> Result<HttpResponse> {
+        let method = request.method.to_string();
+        let url = normalize(&path_and_query(&request.url));
+        let body = request.body.as_ref().map(|body| normalize(body));
+
+        let mut interactions = self.interactions.lock().unwrap();
+        let found = interactions.iter_mut().find(|(interaction, used)| {
+            !*used &&
+                interaction.method == method &&
+                normalize(&interaction.url) == url &&
+                interaction.body.as_ref().map(|body| normalize(body)) == body
+        });
+        let interaction = match found {
+            Some((interaction, used)) => {
+                *used = true;
+                interaction
+            }
+            None => bail!(format!("No recorded response for {} {}", method, url)),
+        };
+
+        let status = match StatusCode::from_u16(interaction.status) {
+            Ok(status) => status,
+            Err(e) => bail!(format!("Invalid recorded status: {}", e)),
+        };
+        let mut headers = HeaderMap::new();
+        for (name, value) in &interaction.headers {
+            if let Ok(name) = HeaderName::from_bytes(name.as_bytes()) {
+                headers.insert(name, HeaderValue::from_str(value)?);
+            }
+        }
+
+        Ok(HttpResponse {
+            status,
+            headers,
+            body: interaction.response.clone(),
+        })
+    }
+}
+
+impl Transport for Replayer {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        self.replay(&request)
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncTransport for Replayer {
+    fn send<'a>(
+        &'a self, request: HttpRequest,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<HttpResponse>> + Send + 'a>>
+    {
+        Box::pin(async move { self.replay(&request) })
+    }
+}
+
+fn path_and_query(url: &str) -> String {
+    match url::Url::parse(url) {
+        Ok(url) => match url.query() {
+            Some(query) => format!("{}?{}", url.path(), query),
+            None => url.path().to_string(),
+        },
+        Err(_) => url.to_string(),
+    }
+}
+
+fn redact(params: &str) -> String {
+    map_params(params, |key, value| match key {
+        "signature" => Some(format!("{}={}", key, REDACTED)),
+        _ => Some(format!("{}={}", key, value)),
+    })
+}
+
+// Parameters that change on every request
+fn normalize(params: &str) -> String {
+    map_params(params, |key, value| match key {
+        "signature" | "timestamp" => None,
+        _ => Some(format!("{}={}", key, value)),
+    })
+}
+
+fn map_params<F>(params: &str, f: F) -> String
+where
+    F: Fn(&str, &str) -> Option<String>,
+{
+    let (path, query) = match params.find('?') {
+        Some(index) => (&params[..=index], &params[index + 1..]),
+        None => ("", params),
+    };
+    let query: Vec<String> = query
+        .split('&')
+        .filter_map(|param| match param.find('=') {
+            Some(index) => f(&param[..index], &param[index + 1..]),
+            None => Some(param.to_string()),
+        })
+        .collect();
+    format!("{}{}", path, query.join("&"))
+}
@@ -1,181 +1,445 @@
 use error_chain::bail;
-use hex::encode as hex_encode;
-use hmac::{Hmac, Mac};
-use crate::errors::{BinanceContentError, ErrorKind, Result};
-use reqwest::StatusCode;
-use reqwest::blocking::Response;
-use reqwest::header::{HeaderMap, HeaderName, HeaderValue, USER_AGENT, CONTENT_TYPE};
-use sha2::Sha256;
+use crate::errors::{BinanceContentError, ErrorKind, HttpError, Result};
+use reqwest::{Method, StatusCode};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, USER_AGENT, CONTENT_TYPE, RETRY_AFTER};
 use serde::de::DeserializeOwned;
-use crate::api::API;
+use crate::api::{Futures, Spot, API};
+use crate::config::Config;
+use crate::ratelimit::RateLimiter;
+use crate::model::ServerTime;
+use crate::retry::RetryPolicy;
+use crate::signer::{HmacSigner, Signer};
+use crate::timesync::TimeSync;
+use crate::transport::{HttpRequest, ReqwestTransport, Transport};
+use crate::util::{encode_param, get_timestamp};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 
 #[derive(Clone)]
 pub struct Client {
     api_key: String,
-    secret_key: String,
+    signer: Arc<dyn Signer>,
     host: String,
-    inner_client: reqwest::blocking::Client,
+    rate_limiter: Arc<RateLimiter>,
+    retry_policy: RetryPolicy,
+    time_sync: Option<Arc<TimeSync>>,
+    time_endpoint: API,
+    signed_body: bool,
+    transport: Arc<dyn Transport>,
 }
 
 impl Client {
     pub fn new(api_key: Option<String>, secret_key: Option<String>, host: String) -> Self {
         Client {
             api_key: api_key.unwrap_or_default(),
-            secret_key: secret_key.unwrap_or_default(),
+            signer: Arc::new(HmacSigner::new(secret_key.unwrap_or_default())),
             host,
-            inner_client: reqwest::blocking::Client::builder()
-                .pool_idle_timeout(None)
-                .build()
-                .unwrap(),
+            rate_limiter: Arc::new(RateLimiter::default()),
+            retry_policy: RetryPolicy::default(),
+            time_sync: None,
+            time_endpoint: API::Spot(Spot::Time),
+            signed_body: false,
+            transport: Arc::new(ReqwestTransport::default()),
+        }
+    }
+
+    /// Client for the spot API, or the futures API if `futures`, set up from `config`.
+    pub(crate) fn from_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config, futures: bool,
+    ) -> Self {
+        let (host, rate_limiter, time_sync, time_endpoint) = if futures {
+            (
+                &config.futures_rest_api_endpoint,
+                &config.futures_rate_limiter,
+                &config.futures_time_sync,
+                API::Futures(Futures::Time),
+            )
+        } else {
+            (
+                &config.rest_api_endpoint,
+                &config.rate_limiter,
+                &config.time_sync,
+                API::Spot(Spot::Time),
+            )
+        };
+        Client::new(api_key, secret_key, host.clone())
+            .set_rate_limiter(rate_limiter.clone())
+            .set_signer(config.signer.clone())
+            .set_signed_body(config.signed_body)
+            .set_retry_policy(config.retry_policy.clone())
+            .set_transport(config.transport.clone())
+            .set_time_sync(time_sync.clone(), time_endpoint)
+    }
+
+    pub fn set_rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    pub fn set_signer(mut self, signer: Option<Arc<dyn Signer>>) -> Self {
+        if let Some(signer) = signer {
+            self.signer = signer;
+        }
+        self
+    }
+
+    pub fn set_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub fn set_time_sync(mut self, time_sync: Option<Arc<TimeSync>>, endpoint: API) -> Self {
+        self.time_sync = time_sync;
+        self.time_endpoint = endpoint;
+        self
+    }
+
+    /// Send the parameters of signed POST, PUT and DELETE requests in a form body.
+    pub fn set_signed_body(mut self, signed_body: bool) -> Self {
+        self.signed_body = signed_body;
+        self
+    }
+
+    pub fn set_transport(mut self, transport: Option<Arc<dyn Transport>>) -> Self {
+        if let Some(transport) = transport {
+            self.transport = transport;
+        }
+        self
+    }
+
+    pub fn rate_limiter(&self) -> &Arc<RateLimiter> {
+        &self.rate_limiter
+    }
+
+    pub fn time_sync(&self) -> Option<&Arc<TimeSync>> {
+        self.time_sync.as_ref()
+    }
+
+    /// Measure the server time offset now and return it in milliseconds.
+    pub fn sync_time(&self) -> Result<i64> {
+        let time_sync = match &self.time_sync {
+            Some(time_sync) => time_sync,
+            None => bail!("Time sync is not enabled"),
+        };
+        let sent = get_timestamp(SystemTime::now())?;
+        let server_time: ServerTime = self.execute(Request::public(self.time_endpoint, None))?;
+        let received = get_timestamp(SystemTime::now())?;
+
+        Ok(time_sync.record(sent, received, server_time.server_time))
+    }
+
+    fn timestamp(&self) -> Result<u64> {
+        match &self.time_sync {
+            Some(time_sync) => {
+                if time_sync.needs_sync() {
+                    self.sync_time()?;
+                }
+                time_sync.now()
+            }
+            None => get_timestamp(SystemTime::now()),
         }
     }
 
     pub fn get_signed<T: DeserializeOwned>(
         &self, endpoint: API, request: Option<String>,
     ) -> Result<T> {
-        let url = self.sign_request(endpoint, request);
-        let client = &self.inner_client;
-        let response = client
-            .get(url.as_str())
-            .headers(self.build_headers(true)?)
-            .send()?;
-
-        self.handler(response)
+        self.execute(Request::signed(Method::GET, endpoint, request))
     }
 
     pub fn post_signed<T: DeserializeOwned>(&self, endpoint: API, request: String) -> Result<T> {
-        let url = self.sign_request(endpoint, Some(request));
-        let client = &self.inner_client;
-        let response = client
-            .post(url.as_str())
-            .headers(self.build_headers(true)?)
-            .send()?;
+        self.execute(Request::signed(Method::POST, endpoint, Some(request)))
+    }
 
-        self.handler(response)
+    pub fn put_signed<T: DeserializeOwned>(&self, endpoint: API, request: String) -> Result<T> {
+        self.execute(Request::signed(Method::PUT, endpoint, Some(request)))
     }
 
     pub fn delete_signed<T: DeserializeOwned>(
         &self, endpoint: API, request: Option<String>,
     ) -> Result<T> {
-        let url = self.sign_request(endpoint, request);
-        let client = &self.inner_client;
-        let response = client
-            .delete(url.as_str())
-            .headers(self.build_headers(true)?)
-            .send()?;
-
-        self.handler(response)
+        self.execute(Request::signed(Method::DELETE, endpoint, request))
     }
 
     pub fn get<T: DeserializeOwned>(&self, endpoint: API, request: Option<String>) -> Result<T> {
-        let mut url: String = format!("{}{}", self.host, String::from(endpoint));
-        if let Some(request) = request {
-            if !request.is_empty() {
-                url.push_str(format!("?{}", request).as_str());
-            }
-        }
-
-        let client = &self.inner_client;
-        let response = client.get(url.as_str()).send()?;
+        self.execute(Request::public(endpoint, request))
+    }
 
-        self.handler(response)
+    // Public data that still needs the API key, e.g. historical trades
+    pub fn get_keyed<T: DeserializeOwned>(
+        &self, endpoint: API, request: Option<String>,
+    ) -> Result<T> {
+        self.execute(Request {
+            with_api_key: true,
+            ..Request::public(endpoint, request)
+        })
     }
 
     pub fn post<T: DeserializeOwned>(&self, endpoint: API) -> Result<T> {
-        let url: String = format!("{}{}", self.host, String::from(endpoint));
+        self.execute(Request::keyed(Method::POST, endpoint, None))
+    }
 
-        let client = &self.inner_client;
-        let response = client
-            .post(url.as_str())
-            .headers(self.build_headers(false)?)
-            .send()?;
+    pub fn put<T: DeserializeOwned>(&self, endpoint: API, listen_key: &str) -> Result<T> {
+        let data: String = format!("listenKey={}", encode_param(listen_key));
+        self.execute(Request::keyed(Method::PUT, endpoint, Some(data)))
+    }
 
-        self.handler(response)
+    pub fn delete<T: DeserializeOwned>(&self, endpoint: API, listen_key: &str) -> Result<T> {
+        let data: String = format!("listenKey={}", encode_param(listen_key));
+        self.execute(Request::keyed(Method::DELETE, endpoint, Some(data)))
     }
 
-    pub fn put<T: DeserializeOwned>(&self, endpoint: API, listen_key: &str) -> Result<T> {
-        let url: String = format!("{}{}", self.host, String::from(endpoint));
-        let data: String = format!("listenKey={}", listen_key);
+    /// Send a request to any `path`, e.g. an endpoint without a wrapper yet.
+    pub fn request<T: DeserializeOwned>(
+        &self, method: Method, path: &str, request: Option<String>, signed: bool,
+    ) -> Result<T> {
+        let with_api_key = signed || !self.api_key.is_empty();
+        self.execute(Request::raw(method, path, request, signed, with_api_key))
+    }
 
-        let client = &self.inner_client;
-        let response = client
-            .put(url.as_str())
-            .headers(self.build_headers(false)?)
-            .body(data)
-            .send()?;
+    fn execute<T: DeserializeOwned>(&self, request: Request) -> Result<T> {
+        let mut attempt = 1;
+        loop {
+            self.rate_limiter.check_ban()?;
+            self.rate_limiter.acquire(request.weight, request.orders)?;
 
-        self.handler(response)
+            let (url, body) = if request.signed {
+                let timestamp = self.timestamp()?;
+                request.sign(
+                    &self.host,
+                    self.signer.as_ref(),
+                    timestamp,
+                    self.signed_body,
+                )?
+            } else {
+                (request.url(&self.host), request.body.clone())
+            };
+            let headers = if request.with_api_key {
+                build_headers(&self.api_key, request.signed)?
+            } else {
+                HeaderMap::new()
+            };
+            let http_request = HttpRequest {
+                method: request.method.clone(),
+                url,
+                headers,
+                body,
+            };
+
+            let response = match self.transport.send(http_request) {
+                Ok(response) => response,
+                Err(e) => match self
+                    .retry_policy
+                    .retry_error(&e, attempt, request.idempotent())
+                {
+                    Some(delay) => {
+                        std::thread::sleep(delay);
+                        attempt += 1;
+                        continue;
+                    }
+                    None => return Err(e),
+                },
+            };
+
+            self.rate_limiter.update(&response.headers);
+            let status = response.status;
+            let retry_after = retry_after(&response.headers);
+            if status == StatusCode::IM_A_TEAPOT {
+                bail!(ErrorKind::IpBanned(self.rate_limiter.ban(retry_after)));
+            }
+            if let Some(delay) =
+                self.retry_policy
+                    .retry_status(status, retry_after, attempt, request.idempotent())
+            {
+                std::thread::sleep(delay);
+                attempt += 1;
+                continue;
+            }
+
+            let result = handle_response(status, retry_after, &response.body);
+            if let Some(time_sync) = &self.time_sync {
+                time_sync.check(&result);
+            }
+            return result;
+        }
     }
+}
 
-    pub fn delete<T: DeserializeOwned>(&self, endpoint: API, listen_key: &str) -> Result<T> {
-        let url: String = format!("{}{}", self.host, String::from(endpoint));
-        let data: String = format!("listenKey={}", listen_key);
-
-        let client = &self.inner_client;
-        let response = client
-            .delete(url.as_str())
-            .headers(self.build_headers(false)?)
-            .body(data)
-            .send()?;
-
-        self.handler(response)
-    }
-
-    // Request must be signed
-    fn sign_request(&self, endpoint: API, request: Option<String>) -> String {
-        if let Some(request) = request {
-            let mut signed_key =
-                Hmac::<Sha256>::new_from_slice(self.secret_key.as_bytes()).unwrap();
-            signed_key.update(request.as_bytes());
-            let signature = hex_encode(signed_key.finalize().into_bytes());
-            let request_body: String = format!("{}&signature={}", request, signature);
-            format!("{}{}?{}", self.host, String::from(endpoint), request_body)
+// Everything needed to send a request again on retry
+pub(crate) struct Request {
+    pub method: Method,
+    pub path: String,
+    pub query: Option<String>,
+    pub body: Option<String>,
+    pub signed: bool,
+    pub with_api_key: bool,
+    pub weight: u64,
+    pub orders: u64,
+}
+
+impl Request {
+    pub fn signed(method: Method, endpoint: API, query: Option<String>) -> Self {
+        let orders = if method == Method::POST {
+            u64::from(endpoint.is_order())
         } else {
-            let signed_key = Hmac::<Sha256>::new_from_slice(self.secret_key.as_bytes()).unwrap();
-            let signature = hex_encode(signed_key.finalize().into_bytes());
-            let request_body: String = format!("&signature={}", signature);
-            format!("{}{}?{}", self.host, String::from(endpoint), request_body)
+            0
+        };
+        Request {
+            method,
+            weight: endpoint.weight(),
+            orders,
+            path: String::from(endpoint),
+            query,
+            body: None,
+            signed: true,
+            with_api_key: true,
         }
     }
 
-    fn build_headers(&self, content_type: bool) -> Result<HeaderMap> {
-        let mut custom_headers = HeaderMap::new();
+    pub fn keyed(method: Method, endpoint: API, body: Option<String>) -> Self {
+        Request {
+            method,
+            weight: endpoint.weight(),
+            orders: 0,
+            path: String::from(endpoint),
+            query: None,
+            body,
+            signed: false,
+            with_api_key: true,
+        }
+    }
 
-        custom_headers.insert(USER_AGENT, HeaderValue::from_static("binance-rs"));
-        if content_type {
-            custom_headers.insert(
-                CONTENT_TYPE,
-                HeaderValue::from_static("application/x-www-form-urlencoded"),
-            );
+    pub fn public(endpoint: API, query: Option<String>) -> Self {
+        Request {
+            method: Method::GET,
+            weight: endpoint.weight(),
+            orders: 0,
+            path: String::from(endpoint),
+            query,
+            body: None,
+            signed: false,
+            with_api_key: false,
         }
-        custom_headers.insert(
-            HeaderName::from_static("x-mbx-apikey"),
-            HeaderValue::from_str(self.api_key.as_str())?,
-        );
+    }
 
-        Ok(custom_headers)
+    // Unknown endpoints count as weight 1, the headers of the response correct it
+    pub fn raw(
+        method: Method, path: &str, query: Option<String>, signed: bool, with_api_key: bool,
+    ) -> Self {
+        Request {
+            method,
+            weight: 1,
+            orders: 0,
+            path: path.to_string(),
+            query,
+            body: None,
+            signed,
+            with_api_key,
+        }
     }
 
-    fn handler<T: DeserializeOwned>(&self, response: Response) -> Result<T> {
-        match response.status() {
-            StatusCode::OK => Ok(response.json::<T>()?),
-            StatusCode::INTERNAL_SERVER_ERROR => {
-                bail!("Internal Server Error");
-            }
-            StatusCode::SERVICE_UNAVAILABLE => {
-                bail!("Service Unavailable");
-            }
-            StatusCode::UNAUTHORIZED => {
-                bail!("Unauthorized");
-            }
-            StatusCode::BAD_REQUEST => {
-                let error: BinanceContentError = response.json()?;
+    // Signed POSTs place orders or move funds
+    pub fn idempotent(&self) -> bool {
+        !(self.signed && self.method == Method::POST)
+    }
+
+    // Signed requests are stamped again on every attempt so a retry stays inside
+    // recvWindow, and so the server time offset applies. Returns the url and the body,
+    // the signature covers exactly what is sent
+    pub fn sign(
+        &self, host: &str, signer: &dyn Signer, timestamp: u64, in_body: bool,
+    ) -> Result<(String, Option<String>)> {
+        let query = self
+            .query
+            .as_ref()
+            .map(|query| set_timestamp(query, timestamp));
+        let payload = sign_request(signer, query)?;
+        if in_body && self.method != Method::GET {
+            Ok((format!("{}{}", host, self.path), Some(payload)))
+        } else {
+            Ok((format!("{}{}?{}", host, self.path, payload), None))
+        }
+    }
 
-                Err(ErrorKind::BinanceError(error).into())
+    pub fn url(&self, host: &str) -> String {
+        let mut url: String = format!("{}{}", host, self.path);
+        if let Some(query) = &self.query {
+            if !query.is_empty() {
+                url.push_str(format!("?{}", query).as_str());
             }
-            s => {
-                bail!(format!("Received response: {:?}", s));
+        }
+        url
+    }
+}
+
+fn set_timestamp(query: &str, timestamp: u64) -> String {
+    let params: Vec<String> = query
+        .split('&')
+        .map(|param| {
+            if param.starts_with("timestamp=") {
+                format!("timestamp={}", timestamp)
+            } else {
+                param.to_string()
             }
+        })
+        .collect();
+    params.join("&")
+}
+
+// Request must be signed
+pub(crate) fn sign_request(signer: &dyn Signer, request: Option<String>) -> Result<String> {
+    let request = request.unwrap_or_default();
+    // RSA and Ed25519 signatures are base64 and need escaping, hex is left untouched
+    let signature = encode_param(&signer.sign(&request)?);
+    Ok(format!("{}&signature={}", request, signature))
+}
+
+pub(crate) fn build_headers(api_key: &str, content_type: bool) -> Result<HeaderMap> {
+    let mut custom_headers = HeaderMap::new();
+
+    custom_headers.insert(USER_AGENT, HeaderValue::from_static("binance-rs"));
+    if content_type {
+        custom_headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/x-www-form-urlencoded"),
+        );
+    }
+    custom_headers.insert(
+        HeaderName::from_static("x-mbx-apikey"),
+        HeaderValue::from_str(api_key)?,
+    );
+
+    Ok(custom_headers)
+}
+
+// Binance sends Retry-After in seconds with 429 and 418 responses
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+// Shared by the blocking and the async client once the body has been read
+pub(crate) fn handle_response<T: DeserializeOwned>(
+    status: StatusCode, retry_after: Option<Duration>, body: &str,
+) -> Result<T> {
+    if status == StatusCode::OK {
+        return Ok(serde_json::from_str::<T>(body)?);
+    }
+
+    match serde_json::from_str::<BinanceContentError>(body) {
+        Ok(mut error) => {
+            error.status = status.as_u16();
+            error.body = body.to_string();
+            error.retry_after = retry_after;
+            Err(ErrorKind::BinanceError(error).into())
         }
+        Err(_) => Err(ErrorKind::HttpError(HttpError {
+            status: status.as_u16(),
+            body: body.to_string(),
+            retry_after,
+        })
+        .into()),
     }
 }
@@ -1,3 +1,13 @@
+use std::sync::Arc;
+
+use crate::ratelimit::RateLimiter;
+use crate::retry::RetryPolicy;
+use crate::signer::Signer;
+use crate::timesync::TimeSync;
+#[cfg(feature = "async")]
+use crate::transport::AsyncTransport;
+use crate::transport::Transport;
+
 #[derive(Clone, Debug)]
 pub struct Config {
     pub rest_api_endpoint: String,
@@ -9,6 +19,19 @@ pub struct Config {
     pub futures_ws_endpoint_vanilla: String,
 
     pub recv_window: u64,
+    pub signed_body: bool,
+    pub signer: Option<Arc<dyn Signer>>,
+
+    pub rate_limiter: Arc<RateLimiter>,
+    pub futures_rate_limiter: Arc<RateLimiter>,
+    pub retry_policy: RetryPolicy,
+
+    pub time_sync: Option<Arc<TimeSync>>,
+    pub futures_time_sync: Option<Arc<TimeSync>>,
+
+    pub transport: Option<Arc<dyn Transport>>,
+    #[cfg(feature = "async")]
+    pub async_transport: Option<Arc<dyn AsyncTransport>>,
 }
 
 impl Default for Config {
@@ -23,6 +46,19 @@ impl Default for Config {
             futures_ws_endpoint_vanilla: "wss://vstream.binance.com".into(),
 
             recv_window: 5000,
+            signed_body: false,
+            signer: None,
+
+            rate_limiter: Arc::new(RateLimiter::default()),
+            futures_rate_limiter: Arc::new(RateLimiter::default()),
+            retry_policy: RetryPolicy::default(),
+
+            time_sync: None,
+            futures_time_sync: None,
+
+            transport: None,
+            #[cfg(feature = "async")]
+            async_transport: None,
         }
     }
 }
@@ -77,4 +113,60 @@ impl Config {
         self.recv_window = recv_window;
         self
     }
+
+    /// Send the parameters of signed POST, PUT and DELETE requests in an
+    /// `application/x-www-form-urlencoded` body instead of the query string.
+    pub fn set_signed_body(mut self, signed_body: bool) -> Self {
+        self.signed_body = signed_body;
+        self
+    }
+
+    /// Limiter shared by every spot and SAPI client built from this config.
+    pub fn set_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Arc::new(rate_limiter);
+        self
+    }
+
+    /// Limiter shared by every futures client built from this config.
+    pub fn set_futures_rate_limiter(mut self, futures_rate_limiter: RateLimiter) -> Self {
+        self.futures_rate_limiter = Arc::new(futures_rate_limiter);
+        self
+    }
+
+    /// Sign requests with `signer` instead of HMAC with the secret key, e.g. for
+    /// Ed25519 or RSA API keys.
+    pub fn set_signer<S: Signer + 'static>(mut self, signer: S) -> Self {
+        self.signer = Some(Arc::new(signer));
+        self
+    }
+
+    pub fn set_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Stamp signed spot and SAPI requests with the server time.
+    pub fn set_time_sync(mut self, time_sync: TimeSync) -> Self {
+        self.time_sync = Some(Arc::new(time_sync));
+        self
+    }
+
+    /// Stamp signed futures requests with the server time.
+    pub fn set_futures_time_sync(mut self, futures_time_sync: TimeSync) -> Self {
+        self.futures_time_sync = Some(Arc::new(futures_time_sync));
+        self
+    }
+
+    /// Send the requests of blocking clients through `transport` instead of reqwest.
+    pub fn set_transport<T: Transport + 'static>(mut self, transport: T) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Send the requests of async clients through `transport` instead of reqwest.
+    #[cfg(feature = "async")]
+    pub fn set_async_transport<T: AsyncTransport + 'static>(mut self, transport: T) -> Self {
+        self.async_transport = Some(Arc::new(transport));
+        self
+    }
 }
@@ -1,20 +1,301 @@
 use serde::Deserialize;
 use error_chain::error_chain;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 #[derive(Debug, Deserialize)]
 pub struct BinanceContentError {
     pub code: i16,
     pub msg: String,
+
+    /// HTTP status of the response.
+    #[serde(skip)]
+    pub status: u16,
+    /// Raw response body.
+    #[serde(skip)]
+    pub body: String,
+    /// `Retry-After` header, sent with 429 and 418 responses.
+    #[serde(skip)]
+    pub retry_after: Option<Duration>,
+}
+
+impl BinanceContentError {
+    pub fn error_code(&self) -> BinanceErrorCode {
+        BinanceErrorCode::from(self.code)
+    }
+
+    pub fn category(&self) -> ErrorCategory {
+        use BinanceErrorCode::*;
+
+        match self.error_code() {
+            // -1003 is also used for IP bans: "Way too much request weight used; IP banned until ..."
+            TooManyRequests if self.status == 418 || self.msg.contains("banned") => {
+                ErrorCategory::Banned
+            }
+            TooManyRequests | TooManyOrders => ErrorCategory::RateLimited,
+            Unauthorized | InvalidSignature | BadApiId | BadApiKeyFmt | RejectedMbxKey => {
+                ErrorCategory::Unauthorized
+            }
+            InvalidTimestamp => ErrorCategory::Timestamp,
+            CancelRejected | NoSuchOrder | OrderArchived => ErrorCategory::UnknownOrder,
+            BalanceNotSufficient | MarginNotSufficient => ErrorCategory::InsufficientBalance,
+            NewOrderRejected | InvalidMessage => {
+                if self.msg.starts_with("Filter failure") {
+                    ErrorCategory::FilterFailure
+                } else if self.msg.to_lowercase().contains("insufficient balance") {
+                    ErrorCategory::InsufficientBalance
+                } else if self.code == NewOrderRejected.code() {
+                    ErrorCategory::OrderRejected
+                } else {
+                    ErrorCategory::InvalidRequest
+                }
+            }
+            UnknownError | Disconnected | UnexpectedResponse | Timeout | ServerBusy |
+            ServiceShuttingDown => ErrorCategory::ServerError,
+            Unknown(code) if (-1199..=-1100).contains(&code) => ErrorCategory::InvalidRequest,
+            Unknown(_) => ErrorCategory::from_status(self.status),
+            _ => ErrorCategory::InvalidRequest,
+        }
+    }
+}
+
+/// Error codes documented in the Binance spot and futures API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BinanceErrorCode {
+    UnknownError,
+    Disconnected,
+    Unauthorized,
+    TooManyRequests,
+    UnexpectedResponse,
+    Timeout,
+    ServerBusy,
+    InvalidMessage,
+    UnknownOrderComposition,
+    TooManyOrders,
+    ServiceShuttingDown,
+    UnsupportedOperation,
+    InvalidTimestamp,
+    InvalidSignature,
+    IllegalChars,
+    TooManyParameters,
+    MandatoryParamEmptyOrMalformed,
+    UnknownParam,
+    UnreadParameters,
+    ParamEmpty,
+    ParamNotRequired,
+    BadPrecision,
+    NoDepth,
+    TifNotRequired,
+    InvalidTif,
+    InvalidOrderType,
+    InvalidSide,
+    EmptyNewClientOrderId,
+    EmptyOrigClientOrderId,
+    BadInterval,
+    BadSymbol,
+    InvalidListenKey,
+    MoreThanXxHours,
+    OptionalParamsBadCombo,
+    InvalidParameter,
+    BadApiId,
+    NewOrderRejected,
+    CancelRejected,
+    NoSuchOrder,
+    BadApiKeyFmt,
+    RejectedMbxKey,
+    NoTradingWindow,
+    BalanceNotSufficient,
+    MarginNotSufficient,
+    CancelReplacePartiallyFailed,
+    CancelReplaceFailed,
+    OrderArchived,
+    Unknown(i16),
+}
+
+impl From<i16> for BinanceErrorCode {
+    fn from(code: i16) -> Self {
+        use BinanceErrorCode::*;
+
+        match code {
+            -1000 => UnknownError,
+            -1001 => Disconnected,
+            -1002 => Unauthorized,
+            -1003 => TooManyRequests,
+            -1006 => UnexpectedResponse,
+            -1007 => Timeout,
+            -1008 => ServerBusy,
+            -1013 => InvalidMessage,
+            -1014 => UnknownOrderComposition,
+            -1015 => TooManyOrders,
+            -1016 => ServiceShuttingDown,
+            -1020 => UnsupportedOperation,
+            -1021 => InvalidTimestamp,
+            -1022 => InvalidSignature,
+            -1100 => IllegalChars,
+            -1101 => TooManyParameters,
+            -1102 => MandatoryParamEmptyOrMalformed,
+            -1103 => UnknownParam,
+            -1104 => UnreadParameters,
+            -1105 => ParamEmpty,
+            -1106 => ParamNotRequired,
+            -1111 => BadPrecision,
+            -1112 => NoDepth,
+            -1114 => TifNotRequired,
+            -1115 => InvalidTif,
+            -1116 => InvalidOrderType,
+            -1117 => InvalidSide,
+            -1118 => EmptyNewClientOrderId,
+            -1119 => EmptyOrigClientOrderId,
+            -1120 => BadInterval,
+            -1121 => BadSymbol,
+            -1125 => InvalidListenKey,
+            -1127 => MoreThanXxHours,
+            -1128 => OptionalParamsBadCombo,
+            -1130 => InvalidParameter,
+            -2008 => BadApiId,
+            -2010 => NewOrderRejected,
+            -2011 => CancelRejected,
+            -2013 => NoSuchOrder,
+            -2014 => BadApiKeyFmt,
+            -2015 => RejectedMbxKey,
+            -2016 => NoTradingWindow,
+            -2018 => BalanceNotSufficient,
+            -2019 => MarginNotSufficient,
+            -2021 => CancelReplacePartiallyFailed,
+            -2022 => CancelReplaceFailed,
+            -2026 => OrderArchived,
+            code => Unknown(code),
+        }
+    }
+}
+
+impl BinanceErrorCode {
+    pub fn code(&self) -> i16 {
+        use BinanceErrorCode::*;
+
+        match self {
+            UnknownError => -1000,
+            Disconnected => -1001,
+            Unauthorized => -1002,
+            TooManyRequests => -1003,
+            UnexpectedResponse => -1006,
+            Timeout => -1007,
+            ServerBusy => -1008,
+            InvalidMessage => -1013,
+            UnknownOrderComposition => -1014,
+            TooManyOrders => -1015,
+            ServiceShuttingDown => -1016,
+            UnsupportedOperation => -1020,
+            InvalidTimestamp => -1021,
+            InvalidSignature => -1022,
+            IllegalChars => -1100,
+            TooManyParameters => -1101,
+            MandatoryParamEmptyOrMalformed => -1102,
+            UnknownParam => -1103,
+            UnreadParameters => -1104,
+            ParamEmpty => -1105,
+            ParamNotRequired => -1106,
+            BadPrecision => -1111,
+            NoDepth => -1112,
+            TifNotRequired => -1114,
+            InvalidTif => -1115,
+            InvalidOrderType => -1116,
+            InvalidSide => -1117,
+            EmptyNewClientOrderId => -1118,
+            EmptyOrigClientOrderId => -1119,
+            BadInterval => -1120,
+            BadSymbol => -1121,
+            InvalidListenKey => -1125,
+            MoreThanXxHours => -1127,
+            OptionalParamsBadCombo => -1128,
+            InvalidParameter => -1130,
+            BadApiId => -2008,
+            NewOrderRejected => -2010,
+            CancelRejected => -2011,
+            NoSuchOrder => -2013,
+            BadApiKeyFmt => -2014,
+            RejectedMbxKey => -2015,
+            NoTradingWindow => -2016,
+            BalanceNotSufficient => -2018,
+            MarginNotSufficient => -2019,
+            CancelReplacePartiallyFailed => -2021,
+            CancelReplaceFailed => -2022,
+            OrderArchived => -2026,
+            Unknown(code) => *code,
+        }
+    }
+}
+
+/// Coarse classification of an error, to decide whether to retry, back off or give up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCategory {
+    RateLimited,
+    Banned,
+    Unauthorized,
+    Timestamp,
+    FilterFailure,
+    InsufficientBalance,
+    UnknownOrder,
+    OrderRejected,
+    InvalidRequest,
+    ServerError,
+    Network,
+    Other,
+}
+
+impl ErrorCategory {
+    pub fn from_status(status: u16) -> Self {
+        match status {
+            418 => ErrorCategory::Banned,
+            429 => ErrorCategory::RateLimited,
+            401 | 403 => ErrorCategory::Unauthorized,
+            400..=499 => ErrorCategory::InvalidRequest,
+            500..=599 => ErrorCategory::ServerError,
+            _ => ErrorCategory::Other,
+        }
+    }
+}
+
+/// An error response without a Binance error body, e.g. from a proxy or the WAF.
+#[derive(Debug)]
+pub struct HttpError {
+    pub status: u16,
+    pub body: String,
+    pub retry_after: Option<Duration>,
 }
 
 error_chain! {
     errors {
         BinanceError(response: BinanceContentError)
 
+        HttpError(response: HttpError) {
+            description("unexpected HTTP status"),
+            display("Received response: {}", response.status),
+        }
+
         KlineValueMissingError(index: usize, name: &'static str) {
             description("invalid Vec for Kline"),
             display("{} at {} is missing", name, index),
         }
+
+        RateLimitExceeded(exceeded: crate::ratelimit::BudgetExceeded) {
+            description("rate limit budget exhausted"),
+            display("{}", exceeded),
+        }
+
+        IpBanned(until: u64) {
+            description("IP banned"),
+            display("IP banned until {}", until),
+        }
+
+        FilterViolations(violations: Vec<crate::filters::FilterViolation>) {
+            description("order breaks symbol filters"),
+            display("Order breaks symbol filters: {}", violations.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")),
+        }
+
+        CancelReplaceFailed(response: BinanceContentError, legs: crate::model::CancelReplaceResponse) {
+            description("cancel-replace failed"),
+            display("{}: cancel {}, new order {}", response.msg, legs.cancel_result, legs.new_order_result),
+        }
      }
 
     foreign_links {
@@ -28,3 +309,54 @@ error_chain! {
         TimestampError(std::time::SystemTimeError);
     }
 }
+
+impl Error {
+    pub fn category(&self) -> ErrorCategory {
+        match self.kind() {
+            ErrorKind::BinanceError(response) => response.category(),
+            ErrorKind::HttpError(response) => ErrorCategory::from_status(response.status),
+            ErrorKind::RateLimitExceeded(_) => ErrorCategory::RateLimited,
+            ErrorKind::IpBanned(_) => ErrorCategory::Banned,
+            ErrorKind::FilterViolations(_) => ErrorCategory::FilterFailure,
+            // Classified by the leg that failed, -2021 and -2022 only say that one did
+            ErrorKind::CancelReplaceFailed(response, legs) => match legs.leg_error() {
+                Some(error) => BinanceContentError {
+                    code: error.code,
+                    msg: error.msg.clone(),
+                    status: response.status,
+                    body: String::new(),
+                    retry_after: None,
+                }
+                .category(),
+                None => response.category(),
+            },
+            ErrorKind::ReqError(_) | ErrorKind::Tungstenite(_) => ErrorCategory::Network,
+            _ => ErrorCategory::Other,
+        }
+    }
+
+    /// The Binance error code, if the response had one.
+    pub fn error_code(&self) -> Option<BinanceErrorCode> {
+        match self.kind() {
+            ErrorKind::BinanceError(response) => Some(response.error_code()),
+            ErrorKind::CancelReplaceFailed(response, _) => Some(response.error_code()),
+            _ => None,
+        }
+    }
+
+    /// How long to wait before sending again, when Binance or the rate limiter said so.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self.kind() {
+            ErrorKind::BinanceError(response) => response.retry_after,
+            ErrorKind::HttpError(response) => response.retry_after,
+            ErrorKind::RateLimitExceeded(exceeded) => Some(exceeded.retry_after),
+            ErrorKind::IpBanned(until) => {
+                let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?;
+                Some(Duration::from_millis(
+                    until.saturating_sub(now.as_millis() as u64),
+                ))
+            }
+            _ => None,
+        }
+    }
+}
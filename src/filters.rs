@@ -0,0 +1,679 @@
+//! Local checks of orders against the exchange symbol filters.
+//!
+//! Binance rejects orders breaking a filter with `-1013 Filter failure`. The
+//! filters come with `exchangeInfo`, so most failures can be caught before
+//! the order is sent:
+//!
+//!```no_run
+//! use binance::api::Binance;
+//! use binance::account::*;
+//! use binance::filters::*;
+//! use binance::general::General;
+//!
+//! fn main() {
+//!     let general: General = Binance::new(None, None);
+//!     let symbol = general.get_symbol_info("LTCBTC").unwrap();
+//!
+//!     let price = symbol.round_price("0.004567891".parse().unwrap(), &OrderSide::Buy);
+//!     let qty = symbol.round_quantity("1.234567".parse().unwrap(), &OrderType::Limit);
+//!     let order = SpotOrderRequest::new("LTCBTC", OrderSide::Buy, OrderType::Limit)
+//!         .set_quantity(qty)
+//!         .set_price(price)
+//!         .set_time_in_force(TimeInForce::GTC);
+//!
+//!     for violation in symbol.check_order(&OrderCheck::from(&order)) {
+//!         println!("{}", violation);
+//!     }
+//! }
+//! ```
+use std::fmt;
+
+use crate::account::SpotOrderRequest;
+use crate::errors::{ErrorKind, Result};
+use crate::futures::account::CustomOrderRequest;
+use crate::model::{Filters, Number, OrderSide, OrderType, Symbol};
+
+/// A filter the order would fail on the exchange.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterViolation {
+    SymbolNotTrading {
+        status: String,
+    },
+    OrderTypeNotAllowed {
+        order_type: OrderType,
+    },
+    PriceTooLow {
+        price: Number,
+        min_price: Number,
+    },
+    PriceTooHigh {
+        price: Number,
+        max_price: Number,
+    },
+    PriceNotOnTick {
+        price: Number,
+        tick_size: Number,
+    },
+    /// PERCENT_PRICE and PERCENT_PRICE_BY_SIDE, around the reference price.
+    PriceOutsideBand {
+        price: Number,
+        min_price: Number,
+        max_price: Number,
+    },
+    QuantityTooLow {
+        qty: Number,
+        min_qty: Number,
+    },
+    QuantityTooHigh {
+        qty: Number,
+        max_qty: Number,
+    },
+    QuantityNotOnStep {
+        qty: Number,
+        step_size: Number,
+    },
+    NotionalTooLow {
+        notional: Number,
+        min_notional: Number,
+    },
+    NotionalTooHigh {
+        notional: Number,
+        max_notional: Number,
+    },
+    TooManyIcebergParts {
+        parts: Number,
+        limit: u16,
+    },
+    TrailingDeltaOutOfRange {
+        trailing_delta: u64,
+        min: u64,
+        max: u64,
+    },
+}
+
+impl fmt::Display for FilterViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SymbolNotTrading { status } => write!(f, "symbol is {}", status),
+            Self::OrderTypeNotAllowed { order_type } => {
+                write!(f, "{} orders are not allowed", order_type)
+            }
+            Self::PriceTooLow { price, min_price } => {
+                write!(f, "price {} is below {}", price, min_price)
+            }
+            Self::PriceTooHigh { price, max_price } => {
+                write!(f, "price {} is above {}", price, max_price)
+            }
+            Self::PriceNotOnTick { price, tick_size } => {
+                write!(f, "price {} is not a multiple of {}", price, tick_size)
+            }
+            Self::PriceOutsideBand {
+                price,
+                min_price,
+                max_price,
+            } => write!(
+                f,
+                "price {} is outside {} - {}",
+                price, min_price, max_price
+            ),
+            Self::QuantityTooLow { qty, min_qty } => {
+                write!(f, "quantity {} is below {}", qty, min_qty)
+            }
+            Self::QuantityTooHigh { qty, max_qty } => {
+                write!(f, "quantity {} is above {}", qty, max_qty)
+            }
+            Self::QuantityNotOnStep { qty, step_size } => {
+                write!(f, "quantity {} is not a multiple of {}", qty, step_size)
+            }
+            Self::NotionalTooLow {
+                notional,
+                min_notional,
+            } => write!(f, "notional {} is below {}", notional, min_notional),
+            Self::NotionalTooHigh {
+                notional,
+                max_notional,
+            } => write!(f, "notional {} is above {}", notional, max_notional),
+            Self::TooManyIcebergParts { parts, limit } => {
+                write!(f, "{} iceberg parts, at most {} allowed", parts, limit)
+            }
+            Self::TrailingDeltaOutOfRange {
+                trailing_delta,
+                min,
+                max,
+            } => write!(
+                f,
+                "trailing delta {} is outside {} - {}",
+                trailing_delta, min, max
+            ),
+        }
+    }
+}
+
+/// The parts of an order the filters look at.
+///
+/// `reference_price` is the average price the exchange compares against:
+/// without it PERCENT_PRICE is not checked, nor the notional of MARKET
+/// orders given in base quantity.
+#[derive(Debug, Clone)]
+pub struct OrderCheck {
+    pub side: OrderSide,
+    pub order_type: OrderType,
+    pub qty: Option<Number>,
+    pub quote_order_qty: Option<Number>,
+    pub price: Option<Number>,
+    pub stop_price: Option<Number>,
+    pub iceberg_qty: Option<Number>,
+    pub trailing_delta: Option<u64>,
+    pub reference_price: Option<Number>,
+}
+
+impl OrderCheck {
+    pub fn set_reference_price(mut self, reference_price: Number) -> Self {
+        self.reference_price = Some(reference_price);
+        self
+    }
+}
+
+impl From<&SpotOrderRequest> for OrderCheck {
+    fn from(order: &SpotOrderRequest) -> Self {
+        OrderCheck {
+            side: order.side.clone(),
+            order_type: order.order_type.clone(),
+            qty: order.qty,
+            quote_order_qty: order.quote_order_qty,
+            price: order.price,
+            stop_price: order.stop_price,
+            iceberg_qty: order.iceberg_qty,
+            trailing_delta: order.trailing_delta,
+            reference_price: None,
+        }
+    }
+}
+
+impl From<&CustomOrderRequest> for OrderCheck {
+    fn from(order: &CustomOrderRequest) -> Self {
+        OrderCheck {
+            side: order.side.clone(),
+            order_type: order.order_type.clone(),
+            qty: order.qty,
+            quote_order_qty: None,
+            price: order.price,
+            stop_price: order.stop_price,
+            iceberg_qty: None,
+            trailing_delta: None,
+            reference_price: None,
+        }
+    }
+}
+
+/// Bounds and increment of a PRICE_FILTER, LOT_SIZE or MARKET_LOT_SIZE filter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FilterRange {
+    pub min: Number,
+    pub max: Number,
+    pub step: Number,
+}
+
+/// Filter checks and rounding for spot and futures symbols.
+pub trait SymbolFilters {
+    fn status(&self) -> &str;
+    fn order_types(&self) -> &[String];
+    fn filters(&self) -> &[Filters];
+
+    fn price_filter(&self) -> Option<FilterRange> {
+        self.filters().iter().find_map(|filter| match filter {
+            Filters::PriceFilter {
+                min_price,
+                max_price,
+                tick_size,
+            } => filter_range(min_price, max_price, tick_size),
+            _ => None,
+        })
+    }
+
+    fn lot_size(&self) -> Option<FilterRange> {
+        self.filters().iter().find_map(|filter| match filter {
+            Filters::LotSize {
+                min_qty,
+                max_qty,
+                step_size,
+            } => filter_range(min_qty, max_qty, step_size),
+            _ => None,
+        })
+    }
+
+    fn market_lot_size(&self) -> Option<FilterRange> {
+        self.filters().iter().find_map(|filter| match filter {
+            Filters::MarketLotSize {
+                min_qty,
+                max_qty,
+                step_size,
+            } => filter_range(min_qty, max_qty, step_size),
+            _ => None,
+        })
+    }
+
+    /// Minimum order value in the quote asset, from MIN_NOTIONAL or NOTIONAL.
+    fn min_notional(&self) -> Option<Number> {
+        self.filters().iter().find_map(|filter| match filter {
+            Filters::MinNotional {
+                notional,
+                min_notional,
+                ..
+            } => min_notional
+                .as_deref()
+                .or(notional.as_deref())
+                .and_then(parse),
+            Filters::Notional { min_notional, .. } => min_notional.as_deref().and_then(parse),
+            _ => None,
+        })
+    }
+
+    /// Decimals allowed in a price, from the tick size.
+    fn price_precision(&self) -> Option<u32> {
+        self.filters().iter().find_map(|filter| match filter {
+            Filters::PriceFilter { tick_size, .. } => Some(precision(tick_size)),
+            _ => None,
+        })
+    }
+
+    /// Decimals allowed in a quantity, from the step size.
+    fn quantity_precision(&self) -> Option<u32> {
+        self.filters().iter().find_map(|filter| match filter {
+            Filters::LotSize { step_size, .. } => Some(precision(step_size)),
+            _ => None,
+        })
+    }
+
+    /// Every filter the order breaks, empty if it passes.
+    fn check_order(&self, order: &OrderCheck) -> Vec<FilterViolation> {
+        check_order(self.status(), self.order_types(), self.filters(), order)
+    }
+
+    /// Round a price to the tick size, down for buys and up for sells
+    /// so the order never ends up on the wrong side of the intended price.
+    fn round_price(&self, price: Number, side: &OrderSide) -> Number {
+        let up = *side == OrderSide::Sell;
+        self.filters()
+            .iter()
+            .find_map(|filter| match filter {
+                Filters::PriceFilter { tick_size, .. } => Some(round_to_step(price, tick_size, up)),
+                _ => None,
+            })
+            .unwrap_or(price)
+    }
+
+    /// Round a quantity down to the step size used by the order type.
+    fn round_quantity(&self, qty: Number, order_type: &OrderType) -> Number {
+        let market = is_market(order_type);
+        self.filters().iter().fold(qty, |qty, filter| match filter {
+            Filters::LotSize { step_size, .. } => round_to_step(qty, step_size, false),
+            Filters::MarketLotSize { step_size, .. } if market => {
+                round_to_step(qty, step_size, false)
+            }
+            _ => qty,
+        })
+    }
+}
+
+/// Fail with `ErrorKind::FilterViolations` if the order breaks any filter.
+pub(crate) fn ensure_filters<S: SymbolFilters + ?Sized>(
+    symbol: &S, order: OrderCheck,
+) -> Result<()> {
+    let violations = symbol.check_order(&order);
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(ErrorKind::FilterViolations(violations).into())
+    }
+}
+
+impl SymbolFilters for Symbol {
+    fn status(&self) -> &str {
+        &self.status
+    }
+
+    fn order_types(&self) -> &[String] {
+        &self.order_types
+    }
+
+    fn filters(&self) -> &[Filters] {
+        &self.filters
+    }
+}
+
+impl SymbolFilters for crate::futures::model::Symbol {
+    fn status(&self) -> &str {
+        &self.status
+    }
+
+    fn order_types(&self) -> &[String] {
+        &self.order_types
+    }
+
+    fn filters(&self) -> &[Filters] {
+        &self.filters
+    }
+}
+
+fn check_order(
+    status: &str, order_types: &[String], filters: &[Filters], order: &OrderCheck,
+) -> Vec<FilterViolation> {
+    let mut violations = Vec::new();
+    let market = is_market(&order.order_type);
+
+    if status != "TRADING" {
+        violations.push(FilterViolation::SymbolNotTrading {
+            status: status.to_string(),
+        });
+    }
+    if !order_types.is_empty() && !order_types.iter().any(|t| t == order.order_type.as_str()) {
+        violations.push(FilterViolation::OrderTypeNotAllowed {
+            order_type: order.order_type.clone(),
+        });
+    }
+
+    // The price the order would trade at, for the notional
+    let trade_price = if market {
+        order.reference_price
+    } else {
+        order.price.or(order.reference_price)
+    };
+    let notional = match (order.quote_order_qty, order.qty, trade_price) {
+        (Some(quote_order_qty), _, _) => Some(quote_order_qty),
+        (None, Some(qty), Some(price)) => Some(qty * price),
+        _ => None,
+    };
+
+    for filter in filters {
+        match filter {
+            Filters::PriceFilter {
+                min_price,
+                max_price,
+                tick_size,
+            } => {
+                for price in order.price.iter().chain(order.stop_price.iter()) {
+                    check_range(
+                        *price,
+                        min_price,
+                        max_price,
+                        tick_size,
+                        &mut violations,
+                        |price, min_price| FilterViolation::PriceTooLow { price, min_price },
+                        |price, max_price| FilterViolation::PriceTooHigh { price, max_price },
+                        |price, tick_size| FilterViolation::PriceNotOnTick { price, tick_size },
+                    );
+                }
+            }
+            Filters::LotSize {
+                min_qty,
+                max_qty,
+                step_size,
+            } => check_qty(order, min_qty, max_qty, step_size, &mut violations),
+            Filters::MarketLotSize {
+                min_qty,
+                max_qty,
+                step_size,
+            } if market => check_qty(order, min_qty, max_qty, step_size, &mut violations),
+            Filters::PercentPrice {
+                multiplier_up,
+                multiplier_down,
+                ..
+            } => check_band(order, multiplier_down, multiplier_up, &mut violations),
+            Filters::PercentPriceBySide {
+                bid_multiplier_up,
+                bid_multiplier_down,
+                ask_multiplier_up,
+                ask_multiplier_down,
+                ..
+            } => {
+                if order.side == OrderSide::Buy {
+                    check_band(
+                        order,
+                        bid_multiplier_down,
+                        bid_multiplier_up,
+                        &mut violations,
+                    );
+                } else {
+                    check_band(
+                        order,
+                        ask_multiplier_down,
+                        ask_multiplier_up,
+                        &mut violations,
+                    );
+                }
+            }
+            Filters::MinNotional {
+                notional: futures_min,
+                min_notional,
+                apply_to_market,
+                ..
+            } => {
+                if market && *apply_to_market == Some(false) {
+                    continue;
+                }
+                let min = min_notional.as_deref().or(futures_min.as_deref());
+                if let (Some(notional), Some(min)) = (notional, min.and_then(parse)) {
+                    if notional < min {
+                        violations.push(FilterViolation::NotionalTooLow {
+                            notional,
+                            min_notional: min,
+                        });
+                    }
+                }
+            }
+            Filters::Notional {
+                min_notional,
+                max_notional,
+                apply_min_to_market,
+                apply_max_to_market,
+                ..
+            } => {
+                let notional = match notional {
+                    Some(notional) => notional,
+                    None => continue,
+                };
+                let min = min_notional.as_deref().and_then(parse);
+                if let Some(min) = min.filter(|_| !market || *apply_min_to_market != Some(false)) {
+                    if notional < min {
+                        violations.push(FilterViolation::NotionalTooLow {
+                            notional,
+                            min_notional: min,
+                        });
+                    }
+                }
+                let max = max_notional.as_deref().and_then(parse);
+                if let Some(max) = max.filter(|_| !market || *apply_max_to_market != Some(false)) {
+                    if notional > max {
+                        violations.push(FilterViolation::NotionalTooHigh {
+                            notional,
+                            max_notional: max,
+                        });
+                    }
+                }
+            }
+            Filters::IcebergParts { limit: Some(limit) } => {
+                if let (Some(qty), Some(iceberg_qty)) = (order.qty, order.iceberg_qty) {
+                    if iceberg_qty > Number::default() {
+                        let parts = steps(qty, iceberg_qty).ceil();
+                        if parts > Number::from(*limit) {
+                            violations.push(FilterViolation::TooManyIcebergParts {
+                                parts,
+                                limit: *limit,
+                            });
+                        }
+                    }
+                }
+            }
+            Filters::TrailingData {
+                min_trailing_above_delta,
+                max_trailing_above_delta,
+                min_trailing_below_delta,
+                max_trailing_below_delta,
+            } => {
+                if let Some(trailing_delta) = order.trailing_delta {
+                    let (min, max) = if trails_above(order) {
+                        (min_trailing_above_delta, max_trailing_above_delta)
+                    } else {
+                        (min_trailing_below_delta, max_trailing_below_delta)
+                    };
+                    let min = min.unwrap_or(0) as u64;
+                    let max = max.map(u64::from).unwrap_or(u64::MAX);
+                    if trailing_delta < min || trailing_delta > max {
+                        violations.push(FilterViolation::TrailingDeltaOutOfRange {
+                            trailing_delta,
+                            min,
+                            max,
+                        });
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    violations
+}
+
+fn check_qty(
+    order: &OrderCheck, min_qty: &str, max_qty: &str, step_size: &str,
+    violations: &mut Vec<FilterViolation>,
+) {
+    if let Some(qty) = order.qty {
+        check_range(
+            qty,
+            min_qty,
+            max_qty,
+            step_size,
+            violations,
+            |qty, min_qty| FilterViolation::QuantityTooLow { qty, min_qty },
+            |qty, max_qty| FilterViolation::QuantityTooHigh { qty, max_qty },
+            |qty, step_size| FilterViolation::QuantityNotOnStep { qty, step_size },
+        );
+    }
+}
+
+// A zero bound or step disables that part of the filter
+#[allow(clippy::too_many_arguments)]
+fn check_range(
+    value: Number, min: &str, max: &str, step: &str, violations: &mut Vec<FilterViolation>,
+    too_low: fn(Number, Number) -> FilterViolation,
+    too_high: fn(Number, Number) -> FilterViolation,
+    off_step: fn(Number, Number) -> FilterViolation,
+) {
+    let zero = Number::default();
+    let min = parse(min).unwrap_or(zero);
+    if min > zero && value < min {
+        violations.push(too_low(value, min));
+    }
+    if let Some(max) = parse(max).filter(|max| *max > zero) {
+        if value > max {
+            violations.push(too_high(value, max));
+        }
+    }
+    if let Some(step) = parse(step).filter(|step| *step > zero) {
+        let steps = steps(value - min, step);
+        if steps != steps.floor() {
+            violations.push(off_step(value, step));
+        }
+    }
+}
+
+fn check_band(
+    order: &OrderCheck, multiplier_down: &str, multiplier_up: &str,
+    violations: &mut Vec<FilterViolation>,
+) {
+    if let (Some(price), Some(reference)) = (order.price, order.reference_price) {
+        if let (Some(down), Some(up)) = (parse(multiplier_down), parse(multiplier_up)) {
+            let (min_price, max_price) = (reference * down, reference * up);
+            if price < min_price || price > max_price {
+                violations.push(FilterViolation::PriceOutsideBand {
+                    price,
+                    min_price,
+                    max_price,
+                });
+            }
+        }
+    }
+}
+
+fn is_market(order_type: &OrderType) -> bool {
+    matches!(
+        order_type,
+        OrderType::Market |
+            OrderType::StopLoss |
+            OrderType::TakeProfit |
+            OrderType::StopMarket |
+            OrderType::TakeProfitMarket |
+            OrderType::TrailingStopMarket
+    )
+}
+
+// Buy stops and sell take profits trigger when the price rises
+fn trails_above(order: &OrderCheck) -> bool {
+    match order.order_type {
+        OrderType::StopLoss | OrderType::StopLossLimit => order.side == OrderSide::Buy,
+        _ => order.side == OrderSide::Sell,
+    }
+}
+
+fn parse(value: &str) -> Option<Number> {
+    value.parse().ok()
+}
+
+fn filter_range(min: &str, max: &str, step: &str) -> Option<FilterRange> {
+    Some(FilterRange {
+        min: parse(min)?,
+        max: parse(max)?,
+        step: parse(step)?,
+    })
+}
+
+// "0.00100000" has 3 significant decimals
+fn precision(step_size: &str) -> u32 {
+    step_size
+        .split('.')
+        .nth(1)
+        .map(|decimals| decimals.trim_end_matches('0').len() as u32)
+        .unwrap_or(0)
+}
+
+fn round_to_step(value: Number, step_size: &str, up: bool) -> Number {
+    let step = match parse(step_size) {
+        Some(step) if step > Number::default() => step,
+        _ => return value,
+    };
+    let steps = steps(value, step);
+    let steps = if up { steps.ceil() } else { steps.floor() };
+    to_precision(steps * step, step_size)
+}
+
+/// `value / step`, snapped to the whole number when only float error
+/// separates them.
+#[cfg(not(feature = "decimal"))]
+fn steps(value: Number, step: Number) -> Number {
+    let steps = value / step;
+    let whole = steps.round();
+    if (steps - whole).abs() < 1e-9 {
+        whole
+    } else {
+        steps
+    }
+}
+
+#[cfg(feature = "decimal")]
+fn steps(value: Number, step: Number) -> Number {
+    value / step
+}
+
+// 3.0 * 0.1 is 0.30000000000000004, which the exchange would reject
+#[cfg(not(feature = "decimal"))]
+fn to_precision(value: Number, step_size: &str) -> Number {
+    let decimals = precision(step_size) as usize;
+    format!("{:.*}", decimals, value).parse().unwrap_or(value)
+}
+
+#[cfg(feature = "decimal")]
+fn to_precision(value: Number, _step_size: &str) -> Number {
+    value
+}
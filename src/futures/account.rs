@@ -5,9 +5,11 @@ use crate::util::build_signed_request;
 use crate::errors::Result;
 use crate::client::Client;
 use crate::api::{API, Futures};
-use crate::model::Empty;
+use crate::model::{Empty, Number};
 use crate::account::OrderSide;
-use crate::futures::model::{Order, TradeHistory};
+pub use crate::model::{OrderType, TimeInForce};
+use crate::futures::model::{Order, Symbol, TradeHistory};
+use crate::filters::{ensure_filters, SymbolFilters};
 
 use super::model::{
     ChangeLeverageResponse, Transaction, CanceledOrder, PositionRisk, AccountBalance,
@@ -57,31 +59,6 @@ impl Display for PositionSide {
     }
 }
 
-#[derive(Clone)]
-pub enum OrderType {
-    Limit,
-    Market,
-    Stop,
-    StopMarket,
-    TakeProfit,
-    TakeProfitMarket,
-    TrailingStopMarket,
-}
-
-impl Display for OrderType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::Limit => write!(f, "LIMIT"),
-            Self::Market => write!(f, "MARKET"),
-            Self::Stop => write!(f, "STOP"),
-            Self::StopMarket => write!(f, "STOP_MARKET"),
-            Self::TakeProfit => write!(f, "TAKE_PROFIT"),
-            Self::TakeProfitMarket => write!(f, "TAKE_PROFIT_MARKET"),
-            Self::TrailingStopMarket => write!(f, "TRAILING_STOP_MARKET"),
-        }
-    }
-}
-
 #[derive(Clone)]
 pub enum WorkingType {
     MarkPrice,
@@ -97,41 +74,21 @@ impl Display for WorkingType {
     }
 }
 
-#[allow(clippy::all)]
-#[derive(Clone)]
-pub enum TimeInForce {
-    GTC,
-    IOC,
-    FOK,
-    GTX,
-}
-
-impl Display for TimeInForce {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::GTC => write!(f, "GTC"),
-            Self::IOC => write!(f, "IOC"),
-            Self::FOK => write!(f, "FOK"),
-            Self::GTX => write!(f, "GTX"),
-        }
-    }
-}
-
-struct OrderRequest {
+pub(crate) struct OrderRequest {
     pub symbol: String,
     pub side: OrderSide,
     pub position_side: Option<PositionSide>,
     pub order_type: OrderType,
     pub time_in_force: Option<TimeInForce>,
-    pub qty: Option<f64>,
+    pub qty: Option<Number>,
     pub reduce_only: Option<bool>,
-    pub price: Option<f64>,
-    pub stop_price: Option<f64>,
+    pub price: Option<Number>,
+    pub stop_price: Option<Number>,
     pub close_position: Option<bool>,
-    pub activation_price: Option<f64>,
-    pub callback_rate: Option<f64>,
+    pub activation_price: Option<Number>,
+    pub callback_rate: Option<Number>,
     pub working_type: Option<WorkingType>,
-    pub price_protect: Option<f64>,
+    pub price_protect: Option<Number>,
     pub client_order_id: Option<String>,
 }
 
@@ -142,18 +99,25 @@ pub struct CustomOrderRequest {
     pub position_side: Option<PositionSide>,
     pub order_type: OrderType,
     pub time_in_force: Option<TimeInForce>,
-    pub qty: Option<f64>,
+    pub qty: Option<Number>,
     pub reduce_only: Option<bool>,
-    pub price: Option<f64>,
-    pub stop_price: Option<f64>,
+    pub price: Option<Number>,
+    pub stop_price: Option<Number>,
     pub close_position: Option<bool>,
-    pub activation_price: Option<f64>,
-    pub callback_rate: Option<f64>,
+    pub activation_price: Option<Number>,
+    pub callback_rate: Option<Number>,
     pub working_type: Option<WorkingType>,
-    pub price_protect: Option<f64>,
+    pub price_protect: Option<Number>,
     pub client_order_id: Option<String>,
 }
 
+impl CustomOrderRequest {
+    /// Check the order against the symbol filters, see `binance::filters`.
+    pub fn check_filters<S: SymbolFilters + ?Sized>(&self, symbol: &S) -> Result<()> {
+        ensure_filters(symbol, self.into())
+    }
+}
+
 pub struct IncomeRequest {
     pub symbol: Option<String>,
     pub income_type: Option<IncomeType>,
@@ -213,7 +177,7 @@ impl Display for IncomeType {
 
 impl FuturesAccount {
     pub fn limit_buy(
-        &self, symbol: impl Into<String>, qty: impl Into<f64>, price: f64,
+        &self, symbol: impl Into<String>, qty: impl Into<Number>, price: Number,
         time_in_force: TimeInForce,
     ) -> Result<Transaction> {
         let buy = OrderRequest {
@@ -233,14 +197,14 @@ impl FuturesAccount {
             price_protect: None,
             client_order_id: None,
         };
-        let order = self.build_order(buy);
+        let order = build_order(buy);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed(API::Futures(Futures::Order), request)
     }
 
     pub fn limit_sell(
-        &self, symbol: impl Into<String>, qty: impl Into<f64>, price: f64,
+        &self, symbol: impl Into<String>, qty: impl Into<Number>, price: Number,
         time_in_force: TimeInForce,
     ) -> Result<Transaction> {
         let sell = OrderRequest {
@@ -260,7 +224,7 @@ impl FuturesAccount {
             price_protect: None,
             client_order_id: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed(API::Futures(Futures::Order), request)
@@ -270,7 +234,7 @@ impl FuturesAccount {
     pub fn market_buy<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let buy = OrderRequest {
             symbol: symbol.into(),
@@ -289,7 +253,7 @@ impl FuturesAccount {
             price_protect: None,
             client_order_id: None,
         };
-        let order = self.build_order(buy);
+        let order = build_order(buy);
         println!("{:#?}", &order);
         let request = build_signed_request(order, self.recv_window)?;
         println!("{:#?}", &request);
@@ -301,7 +265,7 @@ impl FuturesAccount {
     pub fn market_sell<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
@@ -320,7 +284,7 @@ impl FuturesAccount {
             price_protect: None,
             client_order_id: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         println!("{:#?}", &order);
         let request = build_signed_request(order, self.recv_window)?;
         println!("{:#?}", &request);
@@ -360,7 +324,7 @@ impl FuturesAccount {
     pub fn stop_market_close_buy<S, F>(&self, symbol: S, stop_price: F) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
@@ -379,7 +343,7 @@ impl FuturesAccount {
             price_protect: None,
             client_order_id: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed(API::Futures(Futures::Order), request)
@@ -389,7 +353,7 @@ impl FuturesAccount {
     pub fn stop_market_close_sell<S, F>(&self, symbol: S, stop_price: F) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
@@ -408,7 +372,7 @@ impl FuturesAccount {
             price_protect: None,
             client_order_id: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed(API::Futures(Futures::Order), request)
@@ -433,14 +397,21 @@ impl FuturesAccount {
             price_protect: order_request.price_protect,
             client_order_id: order_request.client_order_id,
         };
-        let order = self.build_order(order);
-        println!("{:#?}", &order);
+        let order = build_order(order);
         let request = build_signed_request(order, self.recv_window)?;
-        println!("{:#?}", &request);
         self.client
             .post_signed(API::Futures(Futures::Order), request)
     }
 
+    /// Custom order, checked against the symbol filters first so it fails
+    /// with `ErrorKind::FilterViolations` instead of a round trip to the exchange.
+    pub fn custom_order_checked(
+        &self, symbol: &Symbol, order_request: CustomOrderRequest,
+    ) -> Result<Transaction> {
+        order_request.check_filters(symbol)?;
+        self.custom_order(order_request)
+    }
+
     pub fn get_all_orders<S, F, N>(
         &self, symbol: S, order_id: F, start_time: F, end_time: F, limit: N,
     ) -> Result<Vec<Order>>
@@ -496,57 +467,6 @@ impl FuturesAccount {
         self.client
             .get_signed(API::Futures(Futures::UserTrades), Some(request))
     }
-    fn build_order(&self, order: OrderRequest) -> BTreeMap<String, String> {
-        let mut parameters = BTreeMap::new();
-        parameters.insert("symbol".into(), order.symbol);
-        parameters.insert("side".into(), order.side.to_string());
-        parameters.insert("type".into(), order.order_type.to_string());
-
-        if let Some(position_side) = order.position_side {
-            parameters.insert("positionSide".into(), position_side.to_string());
-        }
-        if let Some(time_in_force) = order.time_in_force {
-            parameters.insert("timeInForce".into(), time_in_force.to_string());
-        }
-        if let Some(qty) = order.qty {
-            parameters.insert("quantity".into(), qty.to_string());
-        }
-        if let Some(reduce_only) = order.reduce_only {
-            parameters.insert("reduceOnly".into(), reduce_only.to_string().to_uppercase());
-        }
-        if let Some(price) = order.price {
-            parameters.insert("price".into(), price.to_string());
-        }
-        if let Some(stop_price) = order.stop_price {
-            parameters.insert("stopPrice".into(), stop_price.to_string());
-        }
-        if let Some(close_position) = order.close_position {
-            parameters.insert(
-                "closePosition".into(),
-                close_position.to_string().to_uppercase(),
-            );
-        }
-        if let Some(activation_price) = order.activation_price {
-            parameters.insert("activationPrice".into(), activation_price.to_string());
-        }
-        if let Some(callback_rate) = order.callback_rate {
-            parameters.insert("callbackRate".into(), callback_rate.to_string());
-        }
-        if let Some(working_type) = order.working_type {
-            parameters.insert("workingType".into(), working_type.to_string());
-        }
-        if let Some(client_order_id) = order.client_order_id {
-            parameters.insert("newClientOrderId".into(), client_order_id.to_string());
-        }
-        if let Some(price_protect) = order.price_protect {
-            parameters.insert(
-                "priceProtect".into(),
-                price_protect.to_string().to_uppercase(),
-            );
-        }
-
-        parameters
-    }
 
     pub fn position_information<S>(&self, symbol: S) -> Result<Vec<PositionRisk>>
     where
@@ -651,3 +571,55 @@ impl FuturesAccount {
             .get_signed(API::Futures(Futures::Income), Some(request))
     }
 }
+
+pub(crate) fn build_order(order: OrderRequest) -> BTreeMap<String, String> {
+    let mut parameters = BTreeMap::new();
+    parameters.insert("symbol".into(), order.symbol);
+    parameters.insert("side".into(), order.side.to_string());
+    parameters.insert("type".into(), order.order_type.to_string());
+
+    if let Some(position_side) = order.position_side {
+        parameters.insert("positionSide".into(), position_side.to_string());
+    }
+    if let Some(time_in_force) = order.time_in_force {
+        parameters.insert("timeInForce".into(), time_in_force.to_string());
+    }
+    if let Some(qty) = order.qty {
+        parameters.insert("quantity".into(), qty.to_string());
+    }
+    if let Some(reduce_only) = order.reduce_only {
+        parameters.insert("reduceOnly".into(), reduce_only.to_string().to_uppercase());
+    }
+    if let Some(price) = order.price {
+        parameters.insert("price".into(), price.to_string());
+    }
+    if let Some(stop_price) = order.stop_price {
+        parameters.insert("stopPrice".into(), stop_price.to_string());
+    }
+    if let Some(close_position) = order.close_position {
+        parameters.insert(
+            "closePosition".into(),
+            close_position.to_string().to_uppercase(),
+        );
+    }
+    if let Some(activation_price) = order.activation_price {
+        parameters.insert("activationPrice".into(), activation_price.to_string());
+    }
+    if let Some(callback_rate) = order.callback_rate {
+        parameters.insert("callbackRate".into(), callback_rate.to_string());
+    }
+    if let Some(working_type) = order.working_type {
+        parameters.insert("workingType".into(), working_type.to_string());
+    }
+    if let Some(client_order_id) = order.client_order_id {
+        parameters.insert("newClientOrderId".into(), client_order_id);
+    }
+    if let Some(price_protect) = order.price_protect {
+        parameters.insert(
+            "priceProtect".into(),
+            price_protect.to_string().to_uppercase(),
+        );
+    }
+
+    parameters
+}
@@ -1,6 +1,7 @@
 use error_chain::bail;
 
 use crate::futures::model::{ExchangeInformation, ServerTime, Symbol};
+use crate::model::Empty;
 use crate::client::Client;
 use crate::errors::Result;
 use crate::api::API;
@@ -14,7 +15,8 @@ pub struct FuturesGeneral {
 impl FuturesGeneral {
     // Test connectivity
     pub fn ping(&self) -> Result<String> {
-        self.client.get(API::Futures(Futures::Ping), None)?;
+        self.client
+            .get::<Empty>(API::Futures(Futures::Ping), None)?;
         Ok("pong".into())
     }
 
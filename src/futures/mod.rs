@@ -2,5 +2,6 @@ pub mod account;
 pub mod general;
 pub mod market;
 pub mod model;
+pub mod rest;
 pub mod userstream;
 pub mod websockets;
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
-use crate::model::{string_or_float, string_or_float_opt, string_or_bool};
+use crate::model::{string_or_float, string_or_float_opt, string_or_bool, Number};
+
+pub use crate::model::{ExecutionType, OrderSide, OrderStatus, OrderType, TimeInForce};
 
 pub use crate::model::{
     Asks, Bids, BookTickers, Filters, KlineSummaries, KlineSummary, RateLimit, ServerTime,
@@ -58,19 +60,19 @@ pub struct PriceStats {
     pub price_change_percent: String,
     pub weighted_avg_price: String,
     #[serde(with = "string_or_float")]
-    pub last_price: f64,
+    pub last_price: Number,
     #[serde(with = "string_or_float")]
-    pub open_price: f64,
+    pub open_price: Number,
     #[serde(with = "string_or_float")]
-    pub high_price: f64,
+    pub high_price: Number,
     #[serde(with = "string_or_float")]
-    pub low_price: f64,
+    pub low_price: Number,
     #[serde(with = "string_or_float")]
-    pub volume: f64,
+    pub volume: Number,
     #[serde(with = "string_or_float")]
-    pub quote_volume: f64,
+    pub quote_volume: Number,
     #[serde(with = "string_or_float")]
-    pub last_qty: f64,
+    pub last_qty: Number,
     pub open_time: u64,
     pub close_time: u64,
     pub first_id: u64,
@@ -83,20 +85,20 @@ pub struct PriceStats {
 pub struct TradeHistory {
     pub buyer: bool,
     #[serde(with = "string_or_float")]
-    pub commission: f64,
+    pub commission: Number,
     pub commission_asset: String,
     pub id: u64,
     pub maker: bool,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
     #[serde(with = "string_or_float")]
-    pub quote_qty: f64,
+    pub quote_qty: Number,
     #[serde(with = "string_or_float")]
-    pub realized_pnl: f64,
-    pub side: String,
+    pub realized_pnl: Number,
+    pub side: OrderSide,
     pub position_side: String,
     pub symbol: String,
     pub time: u64,
@@ -114,11 +116,11 @@ pub struct Trade {
     pub id: u64,
     pub is_buyer_maker: bool,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
     #[serde(with = "string_or_float")]
-    pub quote_qty: f64,
+    pub quote_qty: Number,
     pub time: u64,
 }
 
@@ -142,9 +144,9 @@ pub struct AggTrade {
     #[serde(rename = "m")]
     pub maker: bool,
     #[serde(rename = "p", with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(rename = "q", with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -158,9 +160,9 @@ pub enum MarkPrices {
 pub struct MarkPrice {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub mark_price: f64,
+    pub mark_price: Number,
     #[serde(with = "string_or_float")]
-    pub last_funding_rate: f64,
+    pub last_funding_rate: Number,
     pub next_funding_time: u64,
     pub time: u64,
 }
@@ -175,26 +177,26 @@ pub enum LiquidationOrders {
 #[serde(rename_all = "camelCase")]
 pub struct LiquidationOrder {
     #[serde(with = "string_or_float")]
-    pub average_price: f64,
+    pub average_price: Number,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Number,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Number,
     #[serde(with = "string_or_float")]
-    pub price: f64,
-    pub side: String,
-    pub status: String,
+    pub price: Number,
+    pub side: OrderSide,
+    pub status: OrderStatus,
     pub symbol: String,
     pub time: u64,
-    pub time_in_force: String,
-    pub r#type: String,
+    pub time_in_force: TimeInForce,
+    pub r#type: OrderType,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct OpenInterest {
     #[serde(with = "string_or_float")]
-    pub open_interest: f64,
+    pub open_interest: Number,
     pub symbol: String,
 }
 
@@ -212,34 +214,34 @@ pub struct OpenInterestHist {
 pub struct Order {
     pub client_order_id: String,
     #[serde(with = "string_or_float", default = "default_stop_price")]
-    pub cum_qty: f64,
+    pub cum_qty: Number,
     #[serde(with = "string_or_float")]
-    pub cum_quote: f64,
+    pub cum_quote: Number,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Number,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub avg_price: f64,
+    pub avg_price: Number,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Number,
     #[serde(with = "string_or_float")]
-    pub price: f64,
-    pub side: String,
+    pub price: Number,
+    pub side: OrderSide,
     pub reduce_only: bool,
     pub position_side: String,
-    pub status: String,
+    pub status: OrderStatus,
     #[serde(with = "string_or_float", default = "default_stop_price")]
-    pub stop_price: f64,
+    pub stop_price: Number,
     pub close_position: bool,
     pub symbol: String,
-    pub time_in_force: String,
+    pub time_in_force: TimeInForce,
     #[serde(rename = "type")]
-    pub order_type: String,
-    pub orig_type: String,
+    pub order_type: OrderType,
+    pub orig_type: OrderType,
     #[serde(with = "string_or_float", default = "default_activation_price")]
-    pub activation_price: f64,
+    pub activation_price: Number,
     #[serde(with = "string_or_float", default = "default_price_rate")]
-    pub price_rate: f64,
+    pub price_rate: Number,
     pub update_time: u64,
     pub working_type: String,
     pub price_protect: bool,
@@ -250,34 +252,34 @@ pub struct Order {
 pub struct Transaction {
     pub client_order_id: String,
     #[serde(with = "string_or_float")]
-    pub cum_qty: f64,
+    pub cum_qty: Number,
     #[serde(with = "string_or_float")]
-    pub cum_quote: f64,
+    pub cum_quote: Number,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Number,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub avg_price: f64,
+    pub avg_price: Number,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Number,
     pub reduce_only: bool,
-    pub side: String,
+    pub side: OrderSide,
     pub position_side: String,
-    pub status: String,
+    pub status: OrderStatus,
     #[serde(with = "string_or_float")]
-    pub stop_price: f64,
+    pub stop_price: Number,
     pub close_position: bool,
     pub symbol: String,
-    pub time_in_force: String,
+    pub time_in_force: TimeInForce,
     #[serde(rename = "type")]
-    pub type_name: String,
-    pub orig_type: String,
+    pub type_name: OrderType,
+    pub orig_type: OrderType,
     #[serde(default)]
     #[serde(with = "string_or_float_opt")]
-    pub activate_price: Option<f64>,
+    pub activate_price: Option<Number>,
     #[serde(default)]
     #[serde(with = "string_or_float_opt")]
-    pub price_rate: Option<f64>,
+    pub price_rate: Option<Number>,
     pub update_time: u64,
     pub working_type: String,
     price_protect: bool,
@@ -288,34 +290,34 @@ pub struct Transaction {
 pub struct CanceledOrder {
     pub client_order_id: String,
     #[serde(with = "string_or_float")]
-    pub cum_qty: f64,
+    pub cum_qty: Number,
     #[serde(with = "string_or_float")]
-    pub cum_quote: f64,
+    pub cum_quote: Number,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Number,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
-    pub orig_type: String,
+    pub orig_qty: Number,
+    pub orig_type: OrderType,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     pub reduce_only: bool,
-    pub side: String,
+    pub side: OrderSide,
     pub position_side: String,
-    pub status: String,
+    pub status: OrderStatus,
     #[serde(with = "string_or_float")]
-    pub stop_price: f64,
+    pub stop_price: Number,
     pub close_position: bool,
     pub symbol: String,
-    pub time_in_force: String,
+    pub time_in_force: TimeInForce,
     #[serde(rename = "type")]
-    pub type_name: String,
+    pub type_name: OrderType,
     #[serde(default)]
     #[serde(with = "string_or_float_opt")]
-    pub activate_price: Option<f64>,
+    pub activate_price: Option<Number>,
     #[serde(default)]
     #[serde(with = "string_or_float_opt")]
-    pub price_rate: Option<f64>,
+    pub price_rate: Option<Number>,
     pub update_time: u64,
     pub working_type: String,
     price_protect: bool,
@@ -325,29 +327,29 @@ pub struct CanceledOrder {
 #[serde(rename_all = "camelCase")]
 pub struct PositionRisk {
     #[serde(with = "string_or_float")]
-    pub entry_price: f64,
+    pub entry_price: Number,
     pub margin_type: String,
     #[serde(with = "string_or_bool")]
     pub is_auto_add_margin: bool,
     #[serde(with = "string_or_float")]
-    pub isolated_margin: f64,
+    pub isolated_margin: Number,
     pub leverage: String,
     #[serde(with = "string_or_float")]
-    pub liquidation_price: f64,
+    pub liquidation_price: Number,
     #[serde(with = "string_or_float")]
-    pub mark_price: f64,
+    pub mark_price: Number,
     #[serde(with = "string_or_float")]
-    pub max_notional_value: f64,
+    pub max_notional_value: Number,
     #[serde(with = "string_or_float", rename = "positionAmt")]
-    pub position_amount: f64,
+    pub position_amount: Number,
     pub symbol: String,
     #[serde(with = "string_or_float", rename = "unRealizedProfit")]
-    pub unrealized_profit: f64,
+    pub unrealized_profit: Number,
     pub position_side: String,
     #[serde(with = "string_or_float")]
-    pub notional: f64,
+    pub notional: Number,
     #[serde(with = "string_or_float")]
-    pub isolated_wallet: f64,
+    pub isolated_wallet: Number,
     pub update_time: u64,
 }
 
@@ -356,27 +358,27 @@ pub struct PositionRisk {
 pub struct FuturesAsset {
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub wallet_balance: f64,
+    pub wallet_balance: Number,
     #[serde(with = "string_or_float")]
-    pub unrealized_profit: f64,
+    pub unrealized_profit: Number,
     #[serde(with = "string_or_float")]
-    pub margin_balance: f64,
+    pub margin_balance: Number,
     #[serde(with = "string_or_float")]
-    pub maint_margin: f64,
+    pub maint_margin: Number,
     #[serde(with = "string_or_float")]
-    pub initial_margin: f64,
+    pub initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub position_initial_margin: f64,
+    pub position_initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub open_order_initial_margin: f64,
+    pub open_order_initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub max_withdraw_amount: f64,
+    pub max_withdraw_amount: Number,
     #[serde(with = "string_or_float")]
-    pub cross_wallet_balance: f64,
+    pub cross_wallet_balance: Number,
     #[serde(with = "string_or_float")]
-    pub cross_un_pnl: f64,
+    pub cross_un_pnl: Number,
     #[serde(with = "string_or_float")]
-    pub available_balance: f64,
+    pub available_balance: Number,
     #[serde(with = "string_or_bool")]
     pub margin_available: bool,
     pub update_time: u64,
@@ -387,41 +389,41 @@ pub struct FuturesAsset {
 pub struct FuturesPosition {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub initial_margin: f64,
+    pub initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub maint_margin: f64,
+    pub maint_margin: Number,
     #[serde(with = "string_or_float")]
-    pub unrealized_profit: f64,
+    pub unrealized_profit: Number,
     #[serde(with = "string_or_float")]
-    pub position_initial_margin: f64,
+    pub position_initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub open_order_initial_margin: f64,
+    pub open_order_initial_margin: Number,
     pub leverage: String,
     #[serde(with = "string_or_bool")]
     pub isolated: bool,
     #[serde(with = "string_or_float")]
-    pub entry_price: f64,
+    pub entry_price: Number,
     #[serde(with = "string_or_float")]
-    pub max_notional: f64,
+    pub max_notional: Number,
     pub position_side: String,
     #[serde(with = "string_or_float", rename = "positionAmt")]
-    pub position_amount: f64,
+    pub position_amount: Number,
     #[serde(with = "string_or_float")]
-    pub notional: f64,
+    pub notional: Number,
     #[serde(with = "string_or_float")]
-    pub isolated_wallet: f64,
+    pub isolated_wallet: Number,
     pub update_time: u64,
     #[serde(with = "string_or_float")]
-    pub bid_notional: f64,
+    pub bid_notional: Number,
     #[serde(with = "string_or_float")]
-    pub ask_notional: f64,
+    pub ask_notional: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountInformation {
     #[serde(with = "string_or_float")]
-    pub fee_tier: f64,
+    pub fee_tier: Number,
     #[serde(with = "string_or_bool")]
     pub can_trade: bool,
     #[serde(with = "string_or_bool")]
@@ -429,29 +431,29 @@ pub struct AccountInformation {
     #[serde(with = "string_or_bool")]
     pub can_withdraw: bool,
     #[serde(with = "string_or_float")]
-    pub update_time: f64,
+    pub update_time: Number,
     #[serde(with = "string_or_float")]
-    pub total_initial_margin: f64,
+    pub total_initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub total_maint_margin: f64,
+    pub total_maint_margin: Number,
     #[serde(with = "string_or_float")]
-    pub total_wallet_balance: f64,
+    pub total_wallet_balance: Number,
     #[serde(with = "string_or_float")]
-    pub total_unrealized_profit: f64,
+    pub total_unrealized_profit: Number,
     #[serde(with = "string_or_float")]
-    pub total_margin_balance: f64,
+    pub total_margin_balance: Number,
     #[serde(with = "string_or_float")]
-    pub total_position_initial_margin: f64,
+    pub total_position_initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub total_open_order_initial_margin: f64,
+    pub total_open_order_initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub total_cross_wallet_balance: f64,
+    pub total_cross_wallet_balance: Number,
     #[serde(with = "string_or_float")]
-    pub total_cross_un_pnl: f64,
+    pub total_cross_un_pnl: Number,
     #[serde(with = "string_or_float")]
-    pub available_balance: f64,
+    pub available_balance: Number,
     #[serde(with = "string_or_float")]
-    pub max_withdraw_amount: f64,
+    pub max_withdraw_amount: Number,
     pub assets: Vec<FuturesAsset>,
     pub positions: Vec<FuturesPosition>,
 }
@@ -462,15 +464,15 @@ pub struct AccountBalance {
     pub account_alias: String,
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub balance: f64,
+    pub balance: Number,
     #[serde(with = "string_or_float")]
-    pub cross_wallet_balance: f64,
+    pub cross_wallet_balance: Number,
     #[serde(with = "string_or_float", rename = "crossUnPnl")]
-    pub cross_unrealized_pnl: f64,
+    pub cross_unrealized_pnl: Number,
     #[serde(with = "string_or_float")]
-    pub available_balance: f64,
+    pub available_balance: Number,
     #[serde(with = "string_or_float")]
-    pub max_withdraw_amount: f64,
+    pub max_withdraw_amount: Number,
     pub margin_available: bool,
     pub update_time: u64,
 }
@@ -480,18 +482,18 @@ pub struct AccountBalance {
 pub struct ChangeLeverageResponse {
     pub leverage: u8,
     #[serde(with = "string_or_float")]
-    pub max_notional_value: f64,
+    pub max_notional_value: Number,
     pub symbol: String,
 }
 
-fn default_stop_price() -> f64 {
-    0.0
+fn default_stop_price() -> Number {
+    Number::default()
 }
-fn default_activation_price() -> f64 {
-    0.0
+fn default_activation_price() -> Number {
+    Number::default()
 }
-fn default_price_rate() -> f64 {
-    0.0
+fn default_price_rate() -> Number {
+    Number::default()
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -504,13 +506,13 @@ pub struct OrderUpdate {
     pub new_client_order_id: String,
 
     #[serde(rename = "S")]
-    pub side: String,
+    pub side: OrderSide,
 
     #[serde(rename = "o")]
-    pub order_type: String,
+    pub order_type: OrderType,
 
     #[serde(rename = "f")]
-    pub time_in_force: String,
+    pub time_in_force: TimeInForce,
 
     #[serde(rename = "q")]
     pub qty: String,
@@ -525,10 +527,10 @@ pub struct OrderUpdate {
     pub stop_price: String,
 
     #[serde(rename = "x")]
-    pub execution_type: String,
+    pub execution_type: ExecutionType,
 
     #[serde(rename = "X")]
-    pub order_status: String,
+    pub order_status: OrderStatus,
 
     #[serde(rename = "i")]
     pub order_id: u64,
@@ -570,7 +572,7 @@ pub struct OrderUpdate {
     pub stop_price_working_type: String,
 
     #[serde(rename = "ot")]
-    pub original_order_type: String,
+    pub original_order_type: OrderType,
 
     #[serde(rename = "ps")]
     pub position_side: String,
@@ -619,7 +621,7 @@ pub struct Income {
     pub symbol: String,
     pub income_type: String,
     #[serde(with = "string_or_float")]
-    pub income: f64,
+    pub income: Number,
     pub asset: String,
     pub info: String,
     pub time: u64,
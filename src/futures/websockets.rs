@@ -1,3 +1,4 @@
+use crate::cassette::{Cassette, Recorder};
 use crate::errors::Result;
 use crate::config::Config;
 use crate::model::{
@@ -15,7 +16,7 @@ use tungstenite::{connect, Message};
 use tungstenite::protocol::WebSocket;
 use tungstenite::stream::MaybeTlsStream;
 use tungstenite::handshake::client::Response;
-#[allow(clippy::all)]
+#[allow(clippy::all, dead_code)]
 enum FuturesWebsocketAPI {
     Default,
     MultiStream,
@@ -75,6 +76,7 @@ pub enum FuturesWebsocketEvent {
 pub struct FuturesWebSockets<'a> {
     pub socket: Option<(WebSocket<MaybeTlsStream<TcpStream>>, Response)>,
     handler: Box<dyn FnMut(FuturesWebsocketEvent) -> Result<()> + 'a>,
+    recorder: Option<Recorder>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -109,9 +111,23 @@ impl<'a> FuturesWebSockets<'a> {
         FuturesWebSockets {
             socket: None,
             handler: Box::new(handler),
+            recorder: None,
         }
     }
 
+    /// Record every text frame received by `event_loop` in the recorder's cassette.
+    pub fn set_recorder(&mut self, recorder: Recorder) {
+        self.recorder = Some(recorder);
+    }
+
+    /// Feed the frames of `cassette` to the handler, without a connection.
+    pub fn replay(&mut self, cassette: &Cassette) -> Result<()> {
+        for frame in &cassette.frames {
+            self.handle_msg(frame)?;
+        }
+        Ok(())
+    }
+
     pub fn connect(&mut self, market: &FuturesMarket, subscription: &str) -> Result<()> {
         self.connect_wss(&FuturesWebsocketAPI::Default.params(
             market,
@@ -212,6 +228,9 @@ impl<'a> FuturesWebSockets<'a> {
                 let message = socket.0.read_message()?;
                 match message {
                     Message::Text(msg) => {
+                        if let Some(recorder) = &self.recorder {
+                            recorder.record_frame(&msg);
+                        }
                         if let Err(e) = self.handle_msg(&msg) {
                             bail!(format!("Error on handling stream message: {}", e));
                         }
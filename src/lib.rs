@@ -23,11 +23,26 @@ pub mod model;
 
 pub mod account;
 pub mod api;
+pub mod cassette;
 pub mod config;
+pub mod filters;
 pub mod general;
+pub mod margin;
 pub mod market;
+pub mod pagination;
+pub mod ratelimit;
+pub mod registry;
+pub mod rest;
+pub mod retry;
 pub mod savings;
+pub mod signer;
+pub mod subaccount;
+pub mod timesync;
+pub mod transport;
 pub mod userstream;
 pub mod websockets;
 
 pub mod futures;
+
+#[cfg(feature = "async")]
+pub mod nonblocking;
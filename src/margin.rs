@@ -0,0 +1,545 @@
+//! Cross and isolated margin trading, through `/sapi/v1/margin/*`.
+//!
+//! Orders reuse the spot request builders: a `MarginOrderRequest` wraps a
+//! `SpotOrderRequest` and adds the account it trades on and how it borrows.
+//!
+//!```no_run
+//! use binance::api::Binance;
+//! use binance::account::{OrderSide, OrderType, SpotOrderRequest};
+//! use binance::margin::*;
+//!
+//! fn main() {
+//!     let margin: Margin = Binance::new(Some("api_key".into()), Some("secret_key".into()));
+//!
+//!     let account = margin.get_account().unwrap();
+//!     println!("Margin level: {}", account.margin_level);
+//!
+//!     // Borrow the quote asset as needed to buy on the isolated BTCUSDT account
+//!     let order = SpotOrderRequest::new("BTCUSDT", OrderSide::Buy, OrderType::Market)
+//!         .set_quote_order_qty(100);
+//!     let order = MarginOrderRequest::isolated(order).set_side_effect_type(SideEffectType::MarginBuy);
+//!     let transaction = margin.place_order(order).unwrap();
+//!     println!("Borrowed {:?}", transaction.margin_buy_borrow_amount);
+//! }
+//! ```
+use error_chain::bail;
+
+use crate::account::{
+    build_oco_order, build_order_history, build_spot_order, build_trade_history, OcoOrderRequest,
+    OrderHistoryRequest, SpotOrderRequest, TradeHistoryRequest,
+};
+pub use crate::model::{BorrowRepayType, IsolatedTransferDirection, MarginTransferType, SideEffectType};
+use crate::model::{
+    InterestRecord, IsolatedMarginAccount, LoanRecord, MarginAccount, MarginOrder, Records,
+    MarginTrade, MarginTransaction, MaxBorrowable, MaxTransferable, NewOrderRespType, Number,
+    OrderCanceled, OrderList, TransactionId,
+};
+use crate::util::build_signed_request;
+use crate::client::Client;
+use crate::errors::Result;
+use std::collections::BTreeMap;
+use crate::api::API;
+use crate::api::MarginSapi;
+
+#[derive(Clone)]
+pub struct Margin {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+/// A spot order placed on the cross margin account, or on the isolated
+/// margin account of its symbol.
+#[derive(Clone, Debug)]
+pub struct MarginOrderRequest {
+    pub order: SpotOrderRequest,
+    pub is_isolated: bool,
+    pub side_effect_type: Option<SideEffectType>,
+    pub auto_repay_at_cancel: Option<bool>,
+}
+
+impl MarginOrderRequest {
+    /// Place `order` on the cross margin account.
+    pub fn new(order: SpotOrderRequest) -> Self {
+        Self {
+            order,
+            is_isolated: false,
+            side_effect_type: None,
+            auto_repay_at_cancel: None,
+        }
+    }
+
+    /// Place `order` on the isolated margin account of its symbol.
+    pub fn isolated(order: SpotOrderRequest) -> Self {
+        Self {
+            is_isolated: true,
+            ..Self::new(order)
+        }
+    }
+
+    pub fn set_side_effect_type(mut self, side_effect_type: SideEffectType) -> Self {
+        self.side_effect_type = Some(side_effect_type);
+        self
+    }
+
+    /// Whether canceling an `AUTO_REPAY` or `AUTO_BORROW_REPAY` order repays
+    /// what was borrowed for it, true by default.
+    pub fn set_auto_repay_at_cancel(mut self, auto_repay_at_cancel: bool) -> Self {
+        self.auto_repay_at_cancel = Some(auto_repay_at_cancel);
+        self
+    }
+
+    /// Check the order, without contacting the exchange.
+    pub fn validate(&self) -> Result<()> {
+        if self.order.new_order_resp_type == Some(NewOrderRespType::ACK) {
+            bail!("ACK responses have no order details, margin orders need RESULT or FULL");
+        }
+        self.order.validate()
+    }
+}
+
+/// An OCO order placed on the cross margin account, or on the isolated
+/// margin account of its symbol.
+#[derive(Clone, Debug)]
+pub struct MarginOcoRequest {
+    pub order: OcoOrderRequest,
+    pub is_isolated: bool,
+    pub side_effect_type: Option<SideEffectType>,
+    pub auto_repay_at_cancel: Option<bool>,
+}
+
+impl MarginOcoRequest {
+    /// Place `order` on the cross margin account.
+    pub fn new(order: OcoOrderRequest) -> Self {
+        Self {
+            order,
+            is_isolated: false,
+            side_effect_type: None,
+            auto_repay_at_cancel: None,
+        }
+    }
+
+    /// Place `order` on the isolated margin account of its symbol.
+    pub fn isolated(order: OcoOrderRequest) -> Self {
+        Self {
+            is_isolated: true,
+            ..Self::new(order)
+        }
+    }
+
+    pub fn set_side_effect_type(mut self, side_effect_type: SideEffectType) -> Self {
+        self.side_effect_type = Some(side_effect_type);
+        self
+    }
+
+    pub fn set_auto_repay_at_cancel(mut self, auto_repay_at_cancel: bool) -> Self {
+        self.auto_repay_at_cancel = Some(auto_repay_at_cancel);
+        self
+    }
+
+    /// Check both legs, without contacting the exchange.
+    pub fn validate(&self) -> Result<()> {
+        self.order.validate()
+    }
+}
+
+/// Filters and page of the interest and borrow/repay histories.
+///
+/// Pages start at 1 and hold up to 100 records, 10 by default.
+#[derive(Clone, Debug, Default)]
+pub struct MarginHistoryRequest {
+    pub asset: Option<String>,
+    pub isolated_symbol: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub current: Option<u32>,
+    pub size: Option<u32>,
+}
+
+impl MarginHistoryRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_asset<S: Into<String>>(mut self, asset: S) -> Self {
+        self.asset = Some(asset.into());
+        self
+    }
+
+    /// Only the records of this isolated margin account.
+    pub fn set_isolated_symbol<S: Into<String>>(mut self, symbol: S) -> Self {
+        self.isolated_symbol = Some(symbol.into());
+        self
+    }
+
+    pub fn set_start_time(mut self, start_time: u64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn set_end_time(mut self, end_time: u64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn set_page(mut self, current: u32, size: u32) -> Self {
+        self.current = Some(current);
+        self.size = Some(size);
+        self
+    }
+}
+
+impl Margin {
+    // Cross margin account details
+    pub fn get_account(&self) -> Result<MarginAccount> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Margin(MarginSapi::Account), Some(request))
+    }
+
+    /// Isolated margin account details of up to 5 symbols, or of all of them.
+    pub fn get_isolated_account(&self, symbols: &[&str]) -> Result<IsolatedMarginAccount> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if !symbols.is_empty() {
+            parameters.insert("symbols".into(), symbols.join(","));
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Margin(MarginSapi::IsolatedAccount), Some(request))
+    }
+
+    /// Borrow on the cross margin account, or on the isolated margin account of `isolated_symbol`.
+    pub fn borrow<S, F>(
+        &self, asset: S, amount: F, isolated_symbol: Option<String>,
+    ) -> Result<TransactionId>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        let parameters = build_borrow_repay(
+            BorrowRepayType::Borrow,
+            asset.into(),
+            amount.into(),
+            isolated_symbol,
+        );
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Margin(MarginSapi::BorrowRepay), request)
+    }
+
+    /// Repay a loan of the cross margin account, or of the isolated margin account of `isolated_symbol`.
+    pub fn repay<S, F>(
+        &self, asset: S, amount: F, isolated_symbol: Option<String>,
+    ) -> Result<TransactionId>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        let parameters = build_borrow_repay(
+            BorrowRepayType::Repay,
+            asset.into(),
+            amount.into(),
+            isolated_symbol,
+        );
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Margin(MarginSapi::BorrowRepay), request)
+    }
+
+    // Borrow or repay records, newest first
+    pub fn borrow_repay_history(
+        &self, borrow_repay_type: BorrowRepayType, request: MarginHistoryRequest,
+    ) -> Result<Records<LoanRecord>> {
+        let mut parameters = build_margin_history(request);
+        parameters.insert("type".into(), borrow_repay_type.to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Margin(MarginSapi::BorrowRepay), Some(request))
+    }
+
+    // Interest records, newest first
+    pub fn interest_history(
+        &self, request: MarginHistoryRequest,
+    ) -> Result<Records<InterestRecord>> {
+        let request = build_signed_request(build_margin_history(request), self.recv_window)?;
+        self.client
+            .get_signed(API::Margin(MarginSapi::InterestHistory), Some(request))
+    }
+
+    pub fn max_borrowable<S>(
+        &self, asset: S, isolated_symbol: Option<String>,
+    ) -> Result<MaxBorrowable>
+    where
+        S: Into<String>,
+    {
+        let request = build_signed_request(
+            build_asset_query(asset.into(), isolated_symbol),
+            self.recv_window,
+        )?;
+        self.client
+            .get_signed(API::Margin(MarginSapi::MaxBorrowable), Some(request))
+    }
+
+    pub fn max_transferable<S>(
+        &self, asset: S, isolated_symbol: Option<String>,
+    ) -> Result<MaxTransferable>
+    where
+        S: Into<String>,
+    {
+        let request = build_signed_request(
+            build_asset_query(asset.into(), isolated_symbol),
+            self.recv_window,
+        )?;
+        self.client
+            .get_signed(API::Margin(MarginSapi::MaxTransferable), Some(request))
+    }
+
+    /// Place a margin order built with `MarginOrderRequest`.
+    pub fn place_order(&self, order: MarginOrderRequest) -> Result<MarginTransaction> {
+        order.validate()?;
+        let request = build_signed_request(build_margin_order(order), self.recv_window)?;
+        self.client
+            .post_signed(API::Margin(MarginSapi::Order), request)
+    }
+
+    /// Place a margin OCO order built with `MarginOcoRequest`.
+    pub fn place_oco(&self, order: MarginOcoRequest) -> Result<OrderList> {
+        order.validate()?;
+        let request = build_signed_request(build_margin_oco(order), self.recv_window)?;
+        self.client
+            .post_signed(API::Margin(MarginSapi::Oco), request)
+    }
+
+    pub fn cancel_order<S>(
+        &self, symbol: S, order_id: u64, is_isolated: bool,
+    ) -> Result<OrderCanceled>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = build_symbol_query(symbol.into(), is_isolated);
+        parameters.insert("orderId".into(), order_id.to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Margin(MarginSapi::Order), Some(request))
+    }
+
+    pub fn cancel_order_with_client_id<S>(
+        &self, symbol: S, orig_client_order_id: String, is_isolated: bool,
+    ) -> Result<OrderCanceled>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = build_symbol_query(symbol.into(), is_isolated);
+        parameters.insert("origClientOrderId".into(), orig_client_order_id);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Margin(MarginSapi::Order), Some(request))
+    }
+
+    /// Cancel both orders of a margin OCO
+    pub fn cancel_oco<S>(
+        &self, symbol: S, order_list_id: u64, is_isolated: bool,
+    ) -> Result<OrderList>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = build_symbol_query(symbol.into(), is_isolated);
+        parameters.insert("orderListId".into(), order_list_id.to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Margin(MarginSapi::OrderList), Some(request))
+    }
+
+    // Check a margin order's status
+    pub fn order_status<S>(
+        &self, symbol: S, order_id: u64, is_isolated: bool,
+    ) -> Result<MarginOrder>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = build_symbol_query(symbol.into(), is_isolated);
+        parameters.insert("orderId".into(), order_id.to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Margin(MarginSapi::Order), Some(request))
+    }
+
+    pub fn get_open_orders<S>(&self, symbol: S, is_isolated: bool) -> Result<Vec<MarginOrder>>
+    where
+        S: Into<String>,
+    {
+        let parameters = build_symbol_query(symbol.into(), is_isolated);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Margin(MarginSapi::OpenOrders), Some(request))
+    }
+
+    /// All margin orders of a symbol: active, canceled or filled.
+    pub fn get_all_orders(
+        &self, request: OrderHistoryRequest, is_isolated: bool,
+    ) -> Result<Vec<MarginOrder>> {
+        let mut parameters = build_order_history(request);
+        insert_isolated(&mut parameters, is_isolated);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Margin(MarginSapi::AllOrders), Some(request))
+    }
+
+    /// Margin trades of a symbol, by order, trade ID or time window.
+    pub fn my_trades(
+        &self, request: TradeHistoryRequest, is_isolated: bool,
+    ) -> Result<Vec<MarginTrade>> {
+        let mut parameters = build_trade_history(request);
+        insert_isolated(&mut parameters, is_isolated);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Margin(MarginSapi::MyTrades), Some(request))
+    }
+
+    /// Move an asset between the spot and the cross margin account.
+    pub fn transfer<S, F>(
+        &self, asset: S, amount: F, transfer_type: MarginTransferType,
+    ) -> Result<TransactionId>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("asset".into(), asset.into());
+        parameters.insert("amount".into(), amount.into().to_string());
+        parameters.insert("type".into(), (transfer_type as u8).to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Margin(MarginSapi::Transfer), request)
+    }
+
+    /// Move an asset between the spot and the isolated margin account of `symbol`.
+    pub fn isolated_transfer<S1, S2, F>(
+        &self, asset: S1, symbol: S2, amount: F, direction: IsolatedTransferDirection,
+    ) -> Result<TransactionId>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        F: Into<Number>,
+    {
+        let parameters =
+            build_isolated_transfer(asset.into(), symbol.into(), amount.into(), direction);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Margin(MarginSapi::IsolatedTransfer), request)
+    }
+}
+
+pub(crate) fn insert_isolated(parameters: &mut BTreeMap<String, String>, is_isolated: bool) {
+    if is_isolated {
+        parameters.insert("isIsolated".into(), "TRUE".into());
+    }
+}
+
+pub(crate) fn build_symbol_query(symbol: String, is_isolated: bool) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+    parameters.insert("symbol".into(), symbol);
+    insert_isolated(&mut parameters, is_isolated);
+    parameters
+}
+
+pub(crate) fn build_asset_query(
+    asset: String, isolated_symbol: Option<String>,
+) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+    parameters.insert("asset".into(), asset);
+    if let Some(symbol) = isolated_symbol {
+        parameters.insert("isolatedSymbol".into(), symbol);
+    }
+    parameters
+}
+
+pub(crate) fn build_borrow_repay(
+    borrow_repay_type: BorrowRepayType, asset: String, amount: Number,
+    isolated_symbol: Option<String>,
+) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+    parameters.insert("asset".into(), asset);
+    parameters.insert("amount".into(), amount.to_string());
+    parameters.insert("type".into(), borrow_repay_type.to_string());
+    match isolated_symbol {
+        Some(symbol) => {
+            parameters.insert("isIsolated".into(), "TRUE".into());
+            parameters.insert("symbol".into(), symbol);
+        }
+        None => {
+            parameters.insert("isIsolated".into(), "FALSE".into());
+        }
+    }
+    parameters
+}
+
+pub(crate) fn build_margin_history(request: MarginHistoryRequest) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+    if let Some(asset) = request.asset {
+        parameters.insert("asset".into(), asset);
+    }
+    if let Some(symbol) = request.isolated_symbol {
+        parameters.insert("isolatedSymbol".into(), symbol);
+    }
+    if let Some(start_time) = request.start_time {
+        parameters.insert("startTime".into(), start_time.to_string());
+    }
+    if let Some(end_time) = request.end_time {
+        parameters.insert("endTime".into(), end_time.to_string());
+    }
+    if let Some(current) = request.current {
+        parameters.insert("current".into(), current.to_string());
+    }
+    if let Some(size) = request.size {
+        parameters.insert("size".into(), size.to_string());
+    }
+
+    parameters
+}
+
+pub(crate) fn build_margin_order(order: MarginOrderRequest) -> BTreeMap<String, String> {
+    let mut parameters = build_spot_order(order.order);
+    insert_isolated(&mut parameters, order.is_isolated);
+
+    if let Some(side_effect_type) = order.side_effect_type {
+        parameters.insert("sideEffectType".into(), side_effect_type.to_string());
+    }
+    if let Some(auto_repay_at_cancel) = order.auto_repay_at_cancel {
+        parameters.insert(
+            "autoRepayAtCancel".into(),
+            auto_repay_at_cancel.to_string().to_uppercase(),
+        );
+    }
+
+    parameters
+}
+
+pub(crate) fn build_margin_oco(order: MarginOcoRequest) -> BTreeMap<String, String> {
+    let mut parameters = build_oco_order(order.order);
+    insert_isolated(&mut parameters, order.is_isolated);
+
+    if let Some(side_effect_type) = order.side_effect_type {
+        parameters.insert("sideEffectType".into(), side_effect_type.to_string());
+    }
+    if let Some(auto_repay_at_cancel) = order.auto_repay_at_cancel {
+        parameters.insert(
+            "autoRepayAtCancel".into(),
+            auto_repay_at_cancel.to_string().to_uppercase(),
+        );
+    }
+
+    parameters
+}
+
+pub(crate) fn build_isolated_transfer(
+    asset: String, symbol: String, amount: Number, direction: IsolatedTransferDirection,
+) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+    parameters.insert("asset".into(), asset);
+    parameters.insert("symbol".into(), symbol);
+    parameters.insert("amount".into(), amount.to_string());
+    parameters.insert("transFrom".into(), direction.from_account().into());
+    parameters.insert("transTo".into(), direction.to_account().into());
+    parameters
+}
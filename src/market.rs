@@ -1,10 +1,11 @@
 use crate::util::build_request;
 use crate::model::{
     AggTrade, AveragePrice, BookTickers, KlineSummaries, KlineSummary, OrderBook, PriceStats,
-    Prices, SymbolPrice, Tickers,
+    Prices, SymbolPrice, Tickers, Trade,
 };
 use crate::client::Client;
 use crate::errors::Result;
+use crate::pagination::{PageRequest, Pages};
 use std::collections::BTreeMap;
 use serde_json::Value;
 use crate::api::API;
@@ -103,6 +104,75 @@ impl Market {
         self.client.get(API::Spot(Spot::Ticker24hr), None)
     }
 
+    /// Most recent trades, up to `limit` (default 500, max 1000).
+    pub fn get_trades<S1, S2>(&self, symbol: S1, limit: S2) -> Result<Vec<Trade>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{}", lt));
+        }
+        let request = build_request(parameters);
+        self.client.get(API::Spot(Spot::Trades), Some(request))
+    }
+
+    /// Older trades from `from_id` onwards, or the most recent ones.
+    ///
+    /// Needs the API key, but not the secret key.
+    pub fn get_historical_trades<S1, S2, S3>(
+        &self, symbol: S1, from_id: S2, limit: S3,
+    ) -> Result<Vec<Trade>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{}", lt));
+        }
+        if let Some(fi) = from_id.into() {
+            parameters.insert("fromId".into(), format!("{}", fi));
+        }
+        let request = build_request(parameters);
+        self.client
+            .get_keyed(API::Spot(Spot::HistoricalTrades), Some(request))
+    }
+
+    /// Every trade from `from_id` onwards, fetched `limit` at a time.
+    pub fn historical_trades_iter<S>(&self, symbol: S, from_id: u64, limit: u16) -> Pages<'_, Trade>
+    where
+        S: Into<String>,
+    {
+        let symbol = symbol.into();
+        let fetch = move |page: &PageRequest| {
+            self.get_historical_trades(symbol.as_str(), page.from_id, page.limit)
+        };
+        Pages::by_id(fetch, from_id, None, limit)
+    }
+
+    /// Trades from newest to oldest, starting before `before_id` or from the
+    /// most recent trade, fetched `limit` at a time.
+    ///
+    /// Meant for backfilling, stop with e.g. `take_while` on the trade time.
+    pub fn historical_trades_backward<S1, S2>(
+        &self, symbol: S1, before_id: S2, limit: u16,
+    ) -> Pages<'_, Trade>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+    {
+        let symbol = symbol.into();
+        let fetch = move |page: &PageRequest| {
+            self.get_historical_trades(symbol.as_str(), page.from_id, page.limit)
+        };
+        Pages::backward(fetch, before_id.into(), limit)
+    }
+
     /// Get aggregated historical trades.
     ///
     /// If you provide start_time, you also need to provide end_time.
@@ -3,6 +3,268 @@ use serde_json::{from_value, Value};
 use std::convert::TryFrom;
 use crate::errors::{Error, ErrorKind, Result};
 
+/// Prices, quantities and amounts: `f64`, or `rust_decimal::Decimal` with the
+/// `decimal` feature for lossless parsing and formatting.
+#[cfg(not(feature = "decimal"))]
+pub type Number = f64;
+#[cfg(feature = "decimal")]
+pub type Number = rust_decimal::Decimal;
+
+// Enum of the string values of a field, keeping values added by Binance later in `Unknown`
+macro_rules! string_enum {
+    ($(#[$meta:meta])* $name:ident { $($variant:ident => $value:expr,)* }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant,)*
+            Unknown(String),
+        }
+
+        impl $name {
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $value,)*
+                    Self::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($value => Self::$variant,)*
+                    _ => Self::Unknown(value.to_string()),
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+                let value = String::deserialize(deserializer)?;
+                Ok(Self::from(value.as_str()))
+            }
+        }
+    };
+}
+
+// Enum of the numeric codes of a field, keeping codes added by Binance later in `Unknown`
+macro_rules! code_enum {
+    ($(#[$meta:meta])* $name:ident { $($variant:ident => $value:expr,)* }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant,)*
+            Unknown(u8),
+        }
+
+        impl $name {
+            pub fn code(&self) -> u8 {
+                match self {
+                    $(Self::$variant => $value,)*
+                    Self::Unknown(value) => *value,
+                }
+            }
+        }
+
+        impl From<u8> for $name {
+            fn from(value: u8) -> Self {
+                match value {
+                    $($value => Self::$variant,)*
+                    _ => Self::Unknown(value),
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.code())
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+                serializer.serialize_u8(self.code())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+                let value = u8::deserialize(deserializer)?;
+                Ok(Self::from(value))
+            }
+        }
+    };
+}
+
+string_enum! {
+    OrderStatus {
+        New => "NEW",
+        PendingNew => "PENDING_NEW",
+        PartiallyFilled => "PARTIALLY_FILLED",
+        Filled => "FILLED",
+        Canceled => "CANCELED",
+        PendingCancel => "PENDING_CANCEL",
+        Rejected => "REJECTED",
+        Expired => "EXPIRED",
+        ExpiredInMatch => "EXPIRED_IN_MATCH",
+        NewInsurance => "NEW_INSURANCE",
+        NewAdl => "NEW_ADL",
+    }
+}
+
+string_enum! {
+    OrderSide {
+        Buy => "BUY",
+        Sell => "SELL",
+    }
+}
+
+string_enum! {
+    /// Spot and futures order types.
+    OrderType {
+        Limit => "LIMIT",
+        Market => "MARKET",
+        StopLoss => "STOP_LOSS",
+        StopLossLimit => "STOP_LOSS_LIMIT",
+        TakeProfit => "TAKE_PROFIT",
+        TakeProfitLimit => "TAKE_PROFIT_LIMIT",
+        LimitMaker => "LIMIT_MAKER",
+        Stop => "STOP",
+        StopMarket => "STOP_MARKET",
+        TakeProfitMarket => "TAKE_PROFIT_MARKET",
+        TrailingStopMarket => "TRAILING_STOP_MARKET",
+        Liquidation => "LIQUIDATION",
+    }
+}
+
+string_enum! {
+    #[allow(clippy::upper_case_acronyms)]
+    TimeInForce {
+        GTC => "GTC",
+        IOC => "IOC",
+        FOK => "FOK",
+        GTX => "GTX",
+        GTD => "GTD",
+    }
+}
+
+string_enum! {
+    ExecutionType {
+        New => "NEW",
+        Canceled => "CANCELED",
+        Replaced => "REPLACED",
+        Rejected => "REJECTED",
+        Trade => "TRADE",
+        Expired => "EXPIRED",
+        TradePrevention => "TRADE_PREVENTION",
+        Amendment => "AMENDMENT",
+        Calculated => "CALCULATED",
+    }
+}
+
+string_enum! {
+    RejectReason {
+        None => "NONE",
+        UnknownInstrument => "UNKNOWN_INSTRUMENT",
+        MarketClosed => "MARKET_CLOSED",
+        PriceQtyExceedHardLimits => "PRICE_QTY_EXCEED_HARD_LIMITS",
+        UnknownOrder => "UNKNOWN_ORDER",
+        DuplicateOrder => "DUPLICATE_ORDER",
+        UnknownAccount => "UNKNOWN_ACCOUNT",
+        InsufficientBalance => "INSUFFICIENT_BALANCE",
+        AccountInactive => "ACCOUNT_INACTIVE",
+        AccountCannotSettle => "ACCOUNT_CANNOT_SETTLE",
+    }
+}
+
+string_enum! {
+    /// Response detail requested with `newOrderRespType`.
+    #[allow(clippy::upper_case_acronyms)]
+    NewOrderRespType {
+        ACK => "ACK",
+        RESULT => "RESULT",
+        FULL => "FULL",
+    }
+}
+
+string_enum! {
+    SelfTradePreventionMode {
+        None => "NONE",
+        ExpireTaker => "EXPIRE_TAKER",
+        ExpireMaker => "EXPIRE_MAKER",
+        ExpireBoth => "EXPIRE_BOTH",
+    }
+}
+
+string_enum! {
+    #[allow(clippy::upper_case_acronyms)]
+    ContingencyType {
+        OCO => "OCO",
+        OTO => "OTO",
+    }
+}
+
+string_enum! {
+    ListStatusType {
+        Response => "RESPONSE",
+        ExecStarted => "EXEC_STARTED",
+        AllDone => "ALL_DONE",
+    }
+}
+
+string_enum! {
+    ListOrderStatus {
+        Executing => "EXECUTING",
+        AllDone => "ALL_DONE",
+        Reject => "REJECT",
+    }
+}
+
+string_enum! {
+    /// Whether cancel-replace still places the new order when the cancel fails.
+    CancelReplaceMode {
+        StopOnFailure => "STOP_ON_FAILURE",
+        AllowFailure => "ALLOW_FAILURE",
+    }
+}
+
+string_enum! {
+    /// Only cancel the order if it is in this status.
+    CancelRestrictions {
+        OnlyNew => "ONLY_NEW",
+        OnlyPartiallyFilled => "ONLY_PARTIALLY_FILLED",
+    }
+}
+
+string_enum! {
+    /// Whether cancel-replace still cancels once the order rate limit is exceeded.
+    OrderRateLimitExceededMode {
+        DoNothing => "DO_NOTHING",
+        CancelOnly => "CANCEL_ONLY",
+    }
+}
+
+string_enum! {
+    /// Outcome of one leg of a cancel-replace.
+    CancelReplaceResult {
+        Success => "SUCCESS",
+        Failure => "FAILURE",
+        NotAttempted => "NOT_ATTEMPTED",
+    }
+}
+
 #[derive(Deserialize, Clone)]
 pub struct Empty {}
 
@@ -46,7 +308,7 @@ pub struct Symbol {
     pub filters: Vec<Filters>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(tag = "filterType")]
 pub enum Filters {
     #[serde(rename = "PRICE_FILTER")]
@@ -92,7 +354,10 @@ pub enum Filters {
     Notional {
         notional: Option<String>,
         min_notional: Option<String>,
+        max_notional: Option<String>,
         apply_to_market: Option<bool>,
+        apply_min_to_market: Option<bool>,
+        apply_max_to_market: Option<bool>,
         avg_price_mins: Option<f64>,
     },
     #[serde(rename = "ICEBERG_PARTS")]
@@ -156,22 +421,30 @@ pub struct Order {
     pub order_list_id: i64,
     pub client_order_id: String,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     pub orig_qty: String,
     pub executed_qty: String,
     pub cummulative_quote_qty: String,
-    pub status: String,
-    pub time_in_force: String,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
     #[serde(rename = "type")]
-    pub type_name: String,
-    pub side: String,
+    pub type_name: OrderType,
+    pub side: OrderSide,
     #[serde(with = "string_or_float")]
-    pub stop_price: f64,
+    pub stop_price: Number,
     pub iceberg_qty: String,
     pub time: u64,
     pub update_time: u64,
     pub is_working: bool,
     pub orig_quote_order_qty: String,
+    /// When the order was added to the book.
+    pub working_time: Option<u64>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    pub strategy_id: Option<u64>,
+    pub strategy_type: Option<u64>,
+    /// Set when the order expired because of self-trade prevention.
+    pub prevented_match_id: Option<u64>,
+    pub prevented_quantity: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -182,6 +455,170 @@ pub struct OrderCanceled {
     pub order_id: Option<u64>,
     pub client_order_id: Option<String>,
 }
+/// An OCO or other order list, as placed, canceled or queried.
+///
+/// `order_reports` is only sent when placing or canceling the list.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderList {
+    pub order_list_id: i64,
+    pub contingency_type: ContingencyType,
+    pub list_status_type: ListStatusType,
+    pub list_order_status: ListOrderStatus,
+    pub list_client_order_id: String,
+    pub transaction_time: u64,
+    pub symbol: String,
+    pub orders: Vec<OrderListOrder>,
+    #[serde(default)]
+    pub order_reports: Vec<OrderReport>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderListOrder {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+}
+
+/// One order of an order list, after it was placed or canceled.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderReport {
+    pub symbol: String,
+    pub order_id: u64,
+    pub order_list_id: i64,
+    pub client_order_id: String,
+    pub orig_client_order_id: Option<String>,
+    pub transact_time: u64,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub cummulative_quote_qty: Number,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    #[serde(rename = "type")]
+    pub type_name: OrderType,
+    pub side: OrderSide,
+    #[serde(with = "string_or_float", default = "default_stop_price")]
+    pub stop_price: Number,
+}
+
+/// Error of one leg of a cancel-replace.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct LegError {
+    pub code: i16,
+    pub msg: String,
+}
+
+/// What one leg of a cancel-replace returned: its order, or why it failed.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum LegResponse<T> {
+    Ok(T),
+    Err(LegError),
+}
+
+impl<T> LegResponse<T> {
+    pub fn ok(&self) -> Option<&T> {
+        match self {
+            LegResponse::Ok(response) => Some(response),
+            LegResponse::Err(_) => None,
+        }
+    }
+
+    pub fn err(&self) -> Option<&LegError> {
+        match self {
+            LegResponse::Ok(_) => None,
+            LegResponse::Err(error) => Some(error),
+        }
+    }
+}
+
+/// Response to `POST /api/v3/order/cancelReplace`, also carried by
+/// `ErrorKind::CancelReplaceFailed` when a leg failed.
+///
+/// A leg that was not attempted has no response.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelReplaceResponse {
+    pub cancel_result: CancelReplaceResult,
+    pub new_order_result: CancelReplaceResult,
+    pub cancel_response: Option<LegResponse<OrderReport>>,
+    pub new_order_response: Option<LegResponse<Transaction>>,
+}
+
+impl CancelReplaceResponse {
+    /// Both the cancel and the new order went through.
+    pub fn is_success(&self) -> bool {
+        self.cancel_result == CancelReplaceResult::Success &&
+            self.new_order_result == CancelReplaceResult::Success
+    }
+
+    /// Error of the first leg that failed.
+    pub fn leg_error(&self) -> Option<&LegError> {
+        let cancel = self.cancel_response.as_ref().and_then(LegResponse::err);
+        let new_order = self.new_order_response.as_ref().and_then(LegResponse::err);
+        cancel.or(new_order)
+    }
+}
+
+/// Response to `PUT /api/v3/order/amend/keepPriority`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderAmendment {
+    pub transact_time: u64,
+    pub execution_id: u64,
+    pub amended_order: AmendedOrder,
+    /// Sent when the order is part of an order list.
+    pub list_status: Option<AmendedOrderList>,
+}
+
+/// The order after its quantity was reduced.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AmendedOrder {
+    pub symbol: String,
+    pub order_id: u64,
+    pub order_list_id: i64,
+    pub orig_client_order_id: String,
+    pub client_order_id: String,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub qty: Number,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub prevented_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub quote_order_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub cumulative_quote_qty: Number,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    #[serde(rename = "type")]
+    pub type_name: OrderType,
+    pub side: OrderSide,
+    pub working_time: Option<u64>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AmendedOrderList {
+    pub order_list_id: i64,
+    pub contingency_type: ContingencyType,
+    pub list_order_status: ListOrderStatus,
+    pub list_client_order_id: String,
+    pub symbol: String,
+    pub orders: Vec<OrderListOrder>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub enum SpotFuturesTransferType {
@@ -206,36 +643,49 @@ pub struct Transaction {
     pub client_order_id: String,
     pub transact_time: u64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Number,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Number,
     #[serde(with = "string_or_float")]
-    pub cummulative_quote_qty: f64,
+    pub cummulative_quote_qty: Number,
     #[serde(with = "string_or_float", default = "default_stop_price")]
-    pub stop_price: f64,
-    pub status: String,
-    pub time_in_force: String,
+    pub stop_price: Number,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
     #[serde(rename = "type")]
-    pub type_name: String,
-    pub side: String,
+    pub type_name: OrderType,
+    pub side: OrderSide,
     pub fills: Option<Vec<FillInfo>>,
+    pub working_time: Option<u64>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+}
+
+/// Response to an order placed with `newOrderRespType=ACK`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionAck {
+    pub symbol: String,
+    pub order_id: u64,
+    pub order_list_id: Option<i64>,
+    pub client_order_id: String,
+    pub transact_time: u64,
 }
 
-fn default_stop_price() -> f64 {
-    0.0
+fn default_stop_price() -> Number {
+    Number::default()
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct FillInfo {
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
     #[serde(with = "string_or_float")]
-    pub commission: f64,
+    pub commission: Number,
     pub commission_asset: String,
     pub trade_id: Option<u64>,
 }
@@ -258,13 +708,13 @@ pub struct OrderBook {
 #[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
 pub struct Bids {
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
 }
 
 impl Bids {
-    pub fn new(price: f64, qty: f64) -> Bids {
+    pub fn new(price: Number, qty: Number) -> Bids {
         Bids { price, qty }
     }
 }
@@ -272,9 +722,9 @@ impl Bids {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Asks {
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -297,14 +747,14 @@ pub enum Prices {
 pub struct SymbolPrice {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct AveragePrice {
     pub mins: u64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -324,23 +774,28 @@ pub enum KlineSummaries {
 pub struct Tickers {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub bid_price: f64,
+    pub bid_price: Number,
     #[serde(with = "string_or_float")]
-    pub bid_qty: f64,
+    pub bid_qty: Number,
     #[serde(with = "string_or_float")]
-    pub ask_price: f64,
+    pub ask_price: Number,
     #[serde(with = "string_or_float")]
-    pub ask_qty: f64,
+    pub ask_qty: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct TradeHistory {
+    pub symbol: String,
     pub id: u64,
+    pub order_id: u64,
+    pub order_list_id: i64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
+    #[serde(with = "string_or_float")]
+    pub quote_qty: Number,
     pub commission: String,
     pub commission_asset: String,
     pub time: u64,
@@ -357,21 +812,21 @@ pub struct PriceStats {
     pub price_change_percent: String,
     pub weighted_avg_price: String,
     #[serde(with = "string_or_float")]
-    pub prev_close_price: f64,
+    pub prev_close_price: Number,
     #[serde(with = "string_or_float")]
-    pub last_price: f64,
+    pub last_price: Number,
     #[serde(with = "string_or_float")]
-    pub bid_price: f64,
+    pub bid_price: Number,
     #[serde(with = "string_or_float")]
-    pub ask_price: f64,
+    pub ask_price: Number,
     #[serde(with = "string_or_float")]
-    pub open_price: f64,
+    pub open_price: Number,
     #[serde(with = "string_or_float")]
-    pub high_price: f64,
+    pub high_price: Number,
     #[serde(with = "string_or_float")]
-    pub low_price: f64,
+    pub low_price: Number,
     #[serde(with = "string_or_float")]
-    pub volume: f64,
+    pub volume: Number,
     pub open_time: u64,
     pub close_time: u64,
     pub first_id: i64,
@@ -379,6 +834,21 @@ pub struct PriceStats {
     pub count: u64,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    pub id: u64,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub qty: Number,
+    #[serde(with = "string_or_float")]
+    pub quote_qty: Number,
+    pub time: u64,
+    pub is_buyer_maker: bool,
+    pub is_best_match: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct AggTrade {
     #[serde(rename = "T")]
@@ -394,9 +864,9 @@ pub struct AggTrade {
     #[serde(rename = "M")]
     pub best_match: bool,
     #[serde(rename = "p", with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(rename = "q", with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -485,6 +955,56 @@ pub struct BalanceUpdateEvent {
     pub last_account_update_time: u64,
 }
 
+/// User stream update of an OCO or other order list.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ListStatusEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "g")]
+    pub order_list_id: i64,
+
+    #[serde(rename = "c")]
+    pub contingency_type: ContingencyType,
+
+    #[serde(rename = "l")]
+    pub list_status_type: ListStatusType,
+
+    #[serde(rename = "L")]
+    pub list_order_status: ListOrderStatus,
+
+    #[serde(rename = "r")]
+    pub list_reject_reason: String,
+
+    #[serde(rename = "C")]
+    pub list_client_order_id: String,
+
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+
+    #[serde(rename = "O")]
+    pub orders: Vec<ListStatusOrder>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ListStatusOrder {
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "i")]
+    pub order_id: u64,
+
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct OrderTradeEvent {
@@ -501,13 +1021,13 @@ pub struct OrderTradeEvent {
     pub new_client_order_id: String,
 
     #[serde(rename = "S")]
-    pub side: String,
+    pub side: OrderSide,
 
     #[serde(rename = "o")]
-    pub order_type: String,
+    pub order_type: OrderType,
 
     #[serde(rename = "f")]
-    pub time_in_force: String,
+    pub time_in_force: TimeInForce,
 
     #[serde(rename = "q")]
     pub qty: String,
@@ -528,13 +1048,13 @@ pub struct OrderTradeEvent {
     pub c_ignore: Option<String>,
 
     #[serde(rename = "x")]
-    pub execution_type: String,
+    pub execution_type: ExecutionType,
 
     #[serde(rename = "X")]
-    pub order_status: String,
+    pub order_status: OrderStatus,
 
     #[serde(rename = "r")]
-    pub order_reject_reason: String,
+    pub order_reject_reason: RejectReason,
 
     #[serde(rename = "i")]
     pub order_id: u64,
@@ -729,13 +1249,13 @@ pub struct LiquidationOrder {
     pub symbol: String,
 
     #[serde(rename = "S")]
-    pub side: String,
+    pub side: OrderSide,
 
     #[serde(rename = "o")]
-    pub order_type: String,
+    pub order_type: OrderType,
 
     #[serde(rename = "f")]
-    pub time_in_force: String,
+    pub time_in_force: TimeInForce,
 
     #[serde(rename = "q")]
     pub original_quantity: String,
@@ -747,7 +1267,7 @@ pub struct LiquidationOrder {
     pub average_price: String,
 
     #[serde(rename = "X")]
-    pub order_status: String,
+    pub order_status: OrderStatus,
 
     #[serde(rename = "l")]
     pub order_last_filled_quantity: String,
@@ -1199,24 +1719,24 @@ pub struct CoinInfo {
     pub coin: String,
     pub deposit_all_enable: bool,
     #[serde(with = "string_or_float")]
-    pub free: f64,
+    pub free: Number,
     #[serde(with = "string_or_float")]
-    pub freeze: f64,
+    pub freeze: Number,
     #[serde(with = "string_or_float")]
-    pub ipoable: f64,
+    pub ipoable: Number,
     #[serde(with = "string_or_float")]
-    pub ipoing: f64,
+    pub ipoing: Number,
     pub is_legal_money: bool,
     #[serde(with = "string_or_float")]
-    pub locked: f64,
+    pub locked: Number,
     pub name: String,
     pub network_list: Vec<Network>,
     #[serde(with = "string_or_float")]
-    pub storage: f64,
+    pub storage: Number,
     pub trading: bool,
     pub withdraw_all_enable: bool,
     #[serde(with = "string_or_float")]
-    pub withdrawing: f64,
+    pub withdrawing: Number,
 }
 
 /// Part of the Savings API get all coins response
@@ -1242,9 +1762,9 @@ pub struct Network {
     pub withdraw_desc: Option<String>,
     pub withdraw_enable: bool,
     #[serde(with = "string_or_float")]
-    pub withdraw_fee: f64,
+    pub withdraw_fee: Number,
     #[serde(with = "string_or_float")]
-    pub withdraw_min: f64,
+    pub withdraw_min: Number,
     // pub insert_time: Option<u64>, //commented out for now, because they are not inside the actual response (only the api doc example)
     // pub update_time: Option<u64>,
     pub withdraw_integer_multiple: Option<String>,
@@ -1254,11 +1774,11 @@ pub struct Network {
 #[serde(rename_all = "camelCase")]
 pub struct AssetDetail {
     #[serde(with = "string_or_float")]
-    pub min_withdraw_amount: f64,
+    pub min_withdraw_amount: Number,
     /// false if ALL of networks' are false
     pub deposit_status: bool,
     #[serde(with = "string_or_float")]
-    pub withdraw_fee: f64,
+    pub withdraw_fee: Number,
     /// false if ALL of networks' are false
     pub withdraw_status: bool,
     /// reason
@@ -1273,31 +1793,931 @@ pub struct DepositAddress {
     pub url: String,
 }
 
-pub(crate) mod string_or_float {
-    use std::fmt;
-
-    use serde::{de, Serializer, Deserialize, Deserializer};
+code_enum! {
+    /// Status of a deposit.
+    DepositStatus {
+        Pending => 0,
+        Success => 1,
+        Rejected => 2,
+        CreditedCannotWithdraw => 6,
+        WrongDeposit => 7,
+        WaitingUserConfirm => 8,
+    }
+}
 
-    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        T: fmt::Display,
-        S: Serializer,
-    {
-        serializer.collect_str(value)
+code_enum! {
+    /// Status of a withdrawal.
+    WithdrawStatus {
+        EmailSent => 0,
+        Cancelled => 1,
+        AwaitingApproval => 2,
+        Rejected => 3,
+        Processing => 4,
+        Failure => 5,
+        Completed => 6,
     }
+}
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<f64, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        #[derive(Deserialize)]
-        #[serde(untagged)]
-        enum StringOrFloat {
-            String(String),
-            Float(f64),
-        }
+code_enum! {
+    /// Wallet a withdrawal is paid from, or a deposit is credited to.
+    WalletType {
+        Spot => 0,
+        Funding => 1,
+    }
+}
 
-        match StringOrFloat::deserialize(deserializer)? {
+/// A deposit of the wallet history, `transfer_type` is 1 for transfers between Binance accounts.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositRecord {
+    pub id: String,
+    #[serde(with = "string_or_float")]
+    pub amount: Number,
+    pub coin: String,
+    pub network: String,
+    pub status: DepositStatus,
+    pub address: String,
+    pub address_tag: String,
+    pub tx_id: String,
+    pub insert_time: u64,
+    pub complete_time: Option<u64>,
+    pub transfer_type: u8,
+    /// Confirmations so far and needed, e.g. "12/12".
+    pub confirm_times: String,
+    pub unlock_confirm: u32,
+    pub wallet_type: WalletType,
+}
+
+/// A withdrawal of the wallet history. Binance sends its times as UTC "yyyy-MM-dd HH:mm:ss".
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawRecord {
+    pub id: String,
+    #[serde(with = "string_or_float")]
+    pub amount: Number,
+    #[serde(with = "string_or_float")]
+    pub transaction_fee: Number,
+    pub coin: String,
+    pub status: WithdrawStatus,
+    pub address: String,
+    pub tx_id: Option<String>,
+    pub apply_time: String,
+    pub network: String,
+    pub transfer_type: u8,
+    pub withdraw_order_id: Option<String>,
+    /// Reason of a failure.
+    pub info: Option<String>,
+    pub confirm_no: Option<u32>,
+    pub wallet_type: WalletType,
+    pub tx_key: Option<String>,
+    pub complete_time: Option<String>,
+}
+
+/// Response to a withdrawal, the ID of the `WithdrawRecord`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WithdrawResponse {
+    pub id: String,
+}
+
+string_enum! {
+    /// Source and destination wallets of a universal transfer.
+    ///
+    /// `MAIN` is the spot wallet, `UMFUTURE` and `CMFUTURE` the USD-M and
+    /// COIN-M futures wallets, `MARGIN` the cross margin account.
+    UniversalTransferType {
+        MainUmFuture => "MAIN_UMFUTURE",
+        MainCmFuture => "MAIN_CMFUTURE",
+        MainMargin => "MAIN_MARGIN",
+        UmFutureMain => "UMFUTURE_MAIN",
+        UmFutureMargin => "UMFUTURE_MARGIN",
+        CmFutureMain => "CMFUTURE_MAIN",
+        CmFutureMargin => "CMFUTURE_MARGIN",
+        MarginMain => "MARGIN_MAIN",
+        MarginUmFuture => "MARGIN_UMFUTURE",
+        MarginCmFuture => "MARGIN_CMFUTURE",
+        IsolatedMarginMargin => "ISOLATEDMARGIN_MARGIN",
+        MarginIsolatedMargin => "MARGIN_ISOLATEDMARGIN",
+        IsolatedMarginIsolatedMargin => "ISOLATEDMARGIN_ISOLATEDMARGIN",
+        MainFunding => "MAIN_FUNDING",
+        FundingMain => "FUNDING_MAIN",
+        FundingUmFuture => "FUNDING_UMFUTURE",
+        UmFutureFunding => "UMFUTURE_FUNDING",
+        MarginFunding => "MARGIN_FUNDING",
+        FundingMargin => "FUNDING_MARGIN",
+        FundingCmFuture => "FUNDING_CMFUTURE",
+        CmFutureFunding => "CMFUTURE_FUNDING",
+        MainOption => "MAIN_OPTION",
+        OptionMain => "OPTION_MAIN",
+        UmFutureOption => "UMFUTURE_OPTION",
+        OptionUmFuture => "OPTION_UMFUTURE",
+        MarginOption => "MARGIN_OPTION",
+        OptionMargin => "OPTION_MARGIN",
+        FundingOption => "FUNDING_OPTION",
+        OptionFunding => "OPTION_FUNDING",
+        MainPortfolioMargin => "MAIN_PORTFOLIO_MARGIN",
+        PortfolioMarginMain => "PORTFOLIO_MARGIN_MAIN",
+    }
+}
+
+impl UniversalTransferType {
+    /// Whether the transfer leaves an isolated margin account, which needs `fromSymbol`.
+    pub fn from_isolated(&self) -> bool {
+        self.as_str().starts_with("ISOLATEDMARGIN_")
+    }
+
+    /// Whether the transfer goes to an isolated margin account, which needs `toSymbol`.
+    pub fn to_isolated(&self) -> bool {
+        self.as_str().ends_with("_ISOLATEDMARGIN")
+    }
+}
+
+impl From<SpotFuturesTransferType> for UniversalTransferType {
+    fn from(transfer_type: SpotFuturesTransferType) -> Self {
+        match transfer_type {
+            SpotFuturesTransferType::SpotToUsdtFutures => UniversalTransferType::MainUmFuture,
+            SpotFuturesTransferType::UsdtFuturesToSpot => UniversalTransferType::UmFutureMain,
+            SpotFuturesTransferType::SpotToCoinFutures => UniversalTransferType::MainCmFuture,
+            SpotFuturesTransferType::CoinFuturesToSpot => UniversalTransferType::CmFutureMain,
+        }
+    }
+}
+
+string_enum! {
+    TransferStatus {
+        Pending => "PENDING",
+        Confirmed => "CONFIRMED",
+        Failed => "FAILED",
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UniversalTransferRecord {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: Number,
+    #[serde(rename = "type")]
+    pub transfer_type: UniversalTransferType,
+    pub status: TransferStatus,
+    pub tran_id: u64,
+    pub timestamp: u64,
+}
+
+string_enum! {
+    /// Wallet funding a Simple Earn subscription, or receiving a redemption.
+    EarnAccount {
+        Spot => "SPOT",
+        Fund => "FUND",
+        All => "ALL",
+    }
+}
+
+string_enum! {
+    /// Where a locked product is paid out at the end of its duration.
+    LockedRedeemTo {
+        Spot => "SPOT",
+        Flexible => "FLEXIBLE",
+    }
+}
+
+string_enum! {
+    EarnProductStatus {
+        Created => "CREATED",
+        Preheating => "PREHEATING",
+        Purchasing => "PURCHASING",
+        End => "END",
+    }
+}
+
+string_enum! {
+    /// Rewards of the flexible rewards history.
+    EarnRewardType {
+        Bonus => "BONUS",
+        Realtime => "REALTIME",
+        Rewards => "REWARDS",
+        All => "ALL",
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleProduct {
+    pub product_id: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub latest_annual_percentage_rate: Number,
+    #[serde(default, with = "string_or_float_opt")]
+    pub air_drop_percentage_rate: Option<Number>,
+    pub can_purchase: bool,
+    pub can_redeem: bool,
+    pub is_sold_out: bool,
+    pub hot: bool,
+    #[serde(with = "string_or_float")]
+    pub min_purchase_amount: Number,
+    pub subscription_start_time: u64,
+    pub status: EarnProductStatus,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedProduct {
+    pub project_id: String,
+    pub detail: LockedProductDetail,
+    pub quota: LockedProductQuota,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedProductDetail {
+    pub asset: String,
+    pub reward_asset: String,
+    /// Lock period in days.
+    pub duration: u32,
+    pub renewable: bool,
+    pub is_sold_out: bool,
+    #[serde(with = "string_or_float")]
+    pub apr: Number,
+    pub status: EarnProductStatus,
+    pub subscription_start_time: u64,
+    pub extra_reward_asset: Option<String>,
+    #[serde(rename = "extraRewardAPR", default, with = "string_or_float_opt")]
+    pub extra_reward_apr: Option<Number>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedProductQuota {
+    #[serde(with = "string_or_float")]
+    pub total_personal_quota: Number,
+    #[serde(with = "string_or_float")]
+    pub minimum: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexiblePosition {
+    pub product_id: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub total_amount: Number,
+    #[serde(with = "string_or_float")]
+    pub latest_annual_percentage_rate: Number,
+    pub can_redeem: bool,
+    #[serde(with = "string_or_float")]
+    pub collateral_amount: Number,
+    #[serde(with = "string_or_float")]
+    pub yesterday_real_time_rewards: Number,
+    #[serde(with = "string_or_float")]
+    pub cumulative_bonus_rewards: Number,
+    #[serde(with = "string_or_float")]
+    pub cumulative_real_time_rewards: Number,
+    #[serde(with = "string_or_float")]
+    pub cumulative_total_rewards: Number,
+    pub auto_subscribe: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedPosition {
+    #[serde(with = "string_or_u64")]
+    pub position_id: u64,
+    pub project_id: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: Number,
+    #[serde(with = "string_or_u64")]
+    pub purchase_time: u64,
+    /// Lock period in days.
+    #[serde(with = "string_or_u64")]
+    pub duration: u64,
+    #[serde(with = "string_or_u64")]
+    pub accrual_days: u64,
+    pub reward_asset: String,
+    #[serde(rename = "APY", with = "string_or_float")]
+    pub apy: Number,
+    #[serde(with = "string_or_float")]
+    pub reward_amt: Number,
+    #[serde(with = "string_or_u64")]
+    pub rewards_end_date: u64,
+    #[serde(with = "string_or_u64")]
+    pub deliver_date: u64,
+    pub redeem_to: LockedRedeemTo,
+    pub can_redeem_early: bool,
+    pub auto_subscribe: bool,
+    #[serde(rename = "type")]
+    pub position_type: String,
+    pub status: String,
+}
+
+/// Response of a Simple Earn subscription.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EarnPurchase {
+    pub purchase_id: u64,
+    /// Only sent for locked products.
+    #[serde(default, with = "string_or_u64_opt")]
+    pub position_id: Option<u64>,
+    pub success: bool,
+}
+
+/// Response of a Simple Earn redemption.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EarnRedemption {
+    pub redeem_id: u64,
+    pub success: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleSubscriptionRecord {
+    pub purchase_id: u64,
+    pub product_id: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: Number,
+    pub time: u64,
+    #[serde(rename = "type")]
+    pub subscription_type: String,
+    pub source_account: EarnAccount,
+    #[serde(default, with = "string_or_float_opt")]
+    pub amt_from_spot: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub amt_from_funding: Option<Number>,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedSubscriptionRecord {
+    pub purchase_id: u64,
+    #[serde(with = "string_or_u64")]
+    pub position_id: u64,
+    pub project_id: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: Number,
+    pub time: u64,
+    /// Lock period in days.
+    #[serde(with = "string_or_u64")]
+    pub lock_period: u64,
+    #[serde(rename = "type")]
+    pub subscription_type: String,
+    pub source_account: EarnAccount,
+    #[serde(default, with = "string_or_float_opt")]
+    pub amt_from_spot: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub amt_from_funding: Option<Number>,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleRedemptionRecord {
+    pub redeem_id: u64,
+    pub product_id: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: Number,
+    pub time: u64,
+    pub dest_account: EarnAccount,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedRedemptionRecord {
+    pub redeem_id: u64,
+    #[serde(with = "string_or_u64")]
+    pub position_id: u64,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: Number,
+    pub time: u64,
+    /// Lock period in days.
+    #[serde(with = "string_or_u64")]
+    pub lock_period: u64,
+    #[serde(rename = "type")]
+    pub redemption_type: String,
+    #[serde(with = "string_or_u64")]
+    pub deliver_date: u64,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleRewardRecord {
+    /// The flexible product ID, under the name Binance sends.
+    pub project_id: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub rewards: Number,
+    /// Description of the rewards, such as `Real-Time APR`.
+    #[serde(rename = "type")]
+    pub reward_type: String,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedRewardRecord {
+    #[serde(with = "string_or_u64")]
+    pub position_id: u64,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: Number,
+    pub time: u64,
+    /// Lock period in days.
+    #[serde(with = "string_or_u64")]
+    pub lock_period: u64,
+    #[serde(rename = "type")]
+    pub reward_type: String,
+}
+
+string_enum! {
+    /// How a margin order borrows and repays.
+    SideEffectType {
+        NoSideEffect => "NO_SIDE_EFFECT",
+        MarginBuy => "MARGIN_BUY",
+        AutoRepay => "AUTO_REPAY",
+        AutoBorrowRepay => "AUTO_BORROW_REPAY",
+    }
+}
+
+string_enum! {
+    BorrowRepayType {
+        Borrow => "BORROW",
+        Repay => "REPAY",
+    }
+}
+
+string_enum! {
+    LoanStatus {
+        Pending => "PENDING",
+        Confirmed => "CONFIRMED",
+        Failed => "FAILED",
+    }
+}
+
+/// Direction of `Margin::transfer`, between the spot and the cross margin account.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum MarginTransferType {
+    SpotToMargin = 1,
+    MarginToSpot = 2,
+}
+
+/// Direction of `Margin::isolated_transfer`, between the spot and an isolated margin account.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum IsolatedTransferDirection {
+    SpotToIsolated,
+    IsolatedToSpot,
+}
+
+impl IsolatedTransferDirection {
+    pub fn from_account(&self) -> &'static str {
+        match self {
+            IsolatedTransferDirection::SpotToIsolated => "SPOT",
+            IsolatedTransferDirection::IsolatedToSpot => "ISOLATED_MARGIN",
+        }
+    }
+
+    pub fn to_account(&self) -> &'static str {
+        match self {
+            IsolatedTransferDirection::SpotToIsolated => "ISOLATED_MARGIN",
+            IsolatedTransferDirection::IsolatedToSpot => "SPOT",
+        }
+    }
+}
+
+/// Cross margin account details.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginAccount {
+    pub borrow_enabled: bool,
+    #[serde(with = "string_or_float")]
+    pub margin_level: Number,
+    #[serde(with = "string_or_float")]
+    pub total_asset_of_btc: Number,
+    #[serde(with = "string_or_float")]
+    pub total_liability_of_btc: Number,
+    #[serde(with = "string_or_float")]
+    pub total_net_asset_of_btc: Number,
+    pub trade_enabled: bool,
+    pub transfer_in_enabled: Option<bool>,
+    pub transfer_out_enabled: Option<bool>,
+    pub user_assets: Vec<MarginAsset>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub borrowed: Number,
+    #[serde(with = "string_or_float")]
+    pub free: Number,
+    #[serde(with = "string_or_float")]
+    pub interest: Number,
+    #[serde(with = "string_or_float")]
+    pub locked: Number,
+    #[serde(with = "string_or_float")]
+    pub net_asset: Number,
+}
+
+/// Isolated margin account details, per symbol.
+///
+/// The totals are only sent when no symbols were asked for.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginAccount {
+    pub assets: Vec<IsolatedMarginPair>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub total_asset_of_btc: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub total_liability_of_btc: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub total_net_asset_of_btc: Option<Number>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginPair {
+    pub symbol: String,
+    pub base_asset: IsolatedMarginAsset,
+    pub quote_asset: IsolatedMarginAsset,
+    pub isolated_created: bool,
+    pub enabled: bool,
+    #[serde(with = "string_or_float")]
+    pub margin_level: Number,
+    pub margin_level_status: String,
+    #[serde(with = "string_or_float")]
+    pub margin_ratio: Number,
+    #[serde(with = "string_or_float")]
+    pub index_price: Number,
+    #[serde(with = "string_or_float")]
+    pub liquidate_price: Number,
+    #[serde(with = "string_or_float")]
+    pub liquidate_rate: Number,
+    pub trade_enabled: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginAsset {
+    pub asset: String,
+    pub borrow_enabled: bool,
+    #[serde(with = "string_or_float")]
+    pub borrowed: Number,
+    #[serde(with = "string_or_float")]
+    pub free: Number,
+    #[serde(with = "string_or_float")]
+    pub interest: Number,
+    #[serde(with = "string_or_float")]
+    pub locked: Number,
+    #[serde(with = "string_or_float")]
+    pub net_asset: Number,
+    #[serde(with = "string_or_float")]
+    pub net_asset_of_btc: Number,
+    pub repay_enabled: bool,
+    #[serde(with = "string_or_float")]
+    pub total_asset: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxBorrowable {
+    #[serde(with = "string_or_float")]
+    pub amount: Number,
+    #[serde(with = "string_or_float")]
+    pub borrow_limit: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxTransferable {
+    #[serde(with = "string_or_float")]
+    pub amount: Number,
+}
+
+/// Response to a margin order, a spot `Transaction` with what was borrowed for it.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginTransaction {
+    #[serde(flatten)]
+    pub transaction: Transaction,
+    #[serde(default)]
+    pub is_isolated: bool,
+    #[serde(default, with = "string_or_float_opt")]
+    pub margin_buy_borrow_amount: Option<Number>,
+    pub margin_buy_borrow_asset: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginTrade {
+    pub symbol: String,
+    pub id: u64,
+    pub order_id: u64,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub qty: Number,
+    pub commission: String,
+    pub commission_asset: String,
+    pub time: u64,
+    pub is_buyer: bool,
+    pub is_maker: bool,
+    pub is_best_match: bool,
+    pub is_isolated: bool,
+}
+
+/// A margin order as queried, without the spot order's list ID and quote quantity.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginOrder {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    pub orig_qty: String,
+    pub executed_qty: String,
+    pub cummulative_quote_qty: String,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    #[serde(rename = "type")]
+    pub type_name: OrderType,
+    pub side: OrderSide,
+    #[serde(with = "string_or_float")]
+    pub stop_price: Number,
+    pub iceberg_qty: String,
+    pub time: u64,
+    pub update_time: u64,
+    pub is_working: bool,
+    #[serde(default)]
+    pub is_isolated: bool,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+}
+
+/// One page of a history numbered from 1, `total` counts the records of all pages.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Records<T> {
+    /// Not sent by some endpoints when there are no records.
+    #[serde(default = "Vec::new")]
+    pub rows: Vec<T>,
+    pub total: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LoanRecord {
+    pub isolated_symbol: Option<String>,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: Number,
+    #[serde(with = "string_or_float")]
+    pub principal: Number,
+    #[serde(with = "string_or_float")]
+    pub interest: Number,
+    pub status: LoanStatus,
+    pub timestamp: u64,
+    pub tx_id: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InterestRecord {
+    pub tx_id: u64,
+    /// Sic, as spelled by Binance.
+    #[serde(rename = "interestAccuredTime")]
+    pub interest_accrued_time: u64,
+    pub asset: String,
+    pub raw_asset: Option<String>,
+    #[serde(with = "string_or_float")]
+    pub principal: Number,
+    #[serde(with = "string_or_float")]
+    pub interest: Number,
+    #[serde(with = "string_or_float")]
+    pub interest_rate: Number,
+    #[serde(rename = "type")]
+    pub interest_type: String,
+    pub isolated_symbol: Option<String>,
+}
+
+/// Response to the sub-account list request
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccounts {
+    pub sub_accounts: Vec<SubAccountInfo>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountInfo {
+    pub email: String,
+    pub is_freeze: bool,
+    pub create_time: u64,
+    pub is_managed_sub_account: Option<bool>,
+    pub is_asset_management_sub_account: Option<bool>,
+}
+
+/// Response to the sub-account spot assets request
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SubAccountBalances {
+    pub balances: Vec<SubAccountBalance>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountBalance {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub free: Number,
+    #[serde(with = "string_or_float")]
+    pub locked: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum SubAccountFuturesType {
+    UsdM = 1,
+    CoinM = 2,
+}
+
+/// Futures account of a sub-account, the USD-M or the COIN-M one depending on the request.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SubAccountFuturesAccount {
+    #[serde(rename = "futureAccountResp")]
+    pub usd_m: Option<SubAccountFuturesDetail>,
+    #[serde(rename = "deliveryAccountResp")]
+    pub coin_m: Option<SubAccountFuturesDetail>,
+}
+
+impl SubAccountFuturesAccount {
+    /// The account that was asked for.
+    pub fn account(&self) -> Option<&SubAccountFuturesDetail> {
+        self.usd_m.as_ref().or(self.coin_m.as_ref())
+    }
+}
+
+/// The totals are only sent for USD-M futures.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountFuturesDetail {
+    pub email: String,
+    pub asset: Option<String>,
+    pub assets: Vec<SubAccountFuturesAsset>,
+    pub can_deposit: bool,
+    pub can_trade: bool,
+    pub can_withdraw: bool,
+    pub fee_tier: u32,
+    #[serde(default, with = "string_or_float_opt")]
+    pub max_withdraw_amount: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub total_initial_margin: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub total_maintenance_margin: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub total_margin_balance: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub total_open_order_initial_margin: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub total_position_initial_margin: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub total_unrealized_profit: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub total_wallet_balance: Option<Number>,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountFuturesAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub initial_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub maintenance_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub margin_balance: Number,
+    #[serde(with = "string_or_float")]
+    pub max_withdraw_amount: Number,
+    #[serde(with = "string_or_float")]
+    pub open_order_initial_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub position_initial_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub unrealized_profit: Number,
+    #[serde(with = "string_or_float")]
+    pub wallet_balance: Number,
+}
+
+/// Cross margin account of a sub-account
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountMarginAccount {
+    pub email: String,
+    #[serde(with = "string_or_float")]
+    pub margin_level: Number,
+    #[serde(with = "string_or_float")]
+    pub total_asset_of_btc: Number,
+    #[serde(with = "string_or_float")]
+    pub total_liability_of_btc: Number,
+    #[serde(with = "string_or_float")]
+    pub total_net_asset_of_btc: Number,
+    #[serde(rename = "marginUserAssetVoList")]
+    pub user_assets: Vec<MarginAsset>,
+}
+
+string_enum! {
+    /// Wallet of a master or sub-account, in sub-account transfers.
+    SubAccountType {
+        Spot => "SPOT",
+        UsdtFuture => "USDT_FUTURE",
+        CoinFuture => "COIN_FUTURE",
+        Margin => "MARGIN",
+        IsolatedMargin => "ISOLATED_MARGIN",
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountTransferResponse {
+    pub tran_id: u64,
+    pub client_tran_id: Option<String>,
+}
+
+/// One page of the sub-account transfer history, `total_count` counts the transfers of all pages.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountTransferHistory {
+    #[serde(default)]
+    pub result: Vec<SubAccountTransfer>,
+    pub total_count: u64,
+}
+
+/// A transfer of the sub-account history, an empty email being the master account.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountTransfer {
+    pub tran_id: u64,
+    pub from_email: String,
+    pub to_email: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: Number,
+    pub create_time_stamp: u64,
+    pub from_account_type: SubAccountType,
+    pub to_account_type: SubAccountType,
+    pub status: String,
+    pub client_tran_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountFuturesEnabled {
+    pub email: String,
+    pub is_futures_enabled: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountMarginEnabled {
+    pub email: String,
+    pub is_margin_enabled: bool,
+}
+
+pub(crate) mod string_or_float {
+    use std::fmt;
+
+    #[cfg(not(feature = "decimal"))]
+    use serde::de;
+    use serde::{Serializer, Deserialize, Deserializer};
+
+    use super::Number;
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: fmt::Display,
+        S: Serializer,
+    {
+        serializer.collect_str(value)
+    }
+
+    #[cfg(not(feature = "decimal"))]
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Number, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum StringOrFloat {
+            String(String),
+            Float(f64),
+        }
+
+        match StringOrFloat::deserialize(deserializer)? {
             StringOrFloat::String(s) => {
                 if s == "INF" {
                     Ok(f64::INFINITY)
@@ -1308,12 +2728,22 @@ pub(crate) mod string_or_float {
             StringOrFloat::Float(i) => Ok(i),
         }
     }
+
+    /// Decimal's own deserializer reads JSON numbers back from their shortest text
+    /// instead of converting the binary f64, and has no value for "INF".
+    #[cfg(feature = "decimal")]
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Number, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <Number as Deserialize>::deserialize(deserializer)
+    }
 }
 
 pub(crate) mod string_or_float_opt {
     use std::fmt;
 
-    use serde::{Serializer, Deserialize, Deserializer};
+    use serde::{Serializer, Deserializer};
 
     pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -1326,17 +2756,10 @@ pub(crate) mod string_or_float_opt {
         }
     }
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<crate::model::Number>, D::Error>
     where
         D: Deserializer<'de>,
     {
-        #[derive(Deserialize)]
-        #[serde(untagged)]
-        enum StringOrFloat {
-            String(String),
-            Float(f64),
-        }
-
         Ok(Some(crate::model::string_or_float::deserialize(
             deserializer,
         )?))
@@ -1374,6 +2797,63 @@ pub(crate) mod string_or_bool {
     }
 }
 
+pub(crate) mod string_or_u64 {
+    use std::fmt;
+
+    use serde::{de, Serializer, Deserialize, Deserializer};
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: fmt::Display,
+        S: Serializer,
+    {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<u64, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum StringOrU64 {
+            String(String),
+            U64(u64),
+        }
+
+        match StringOrU64::deserialize(deserializer)? {
+            StringOrU64::String(s) => s.parse().map_err(de::Error::custom),
+            StringOrU64::U64(i) => Ok(i),
+        }
+    }
+}
+
+pub(crate) mod string_or_u64_opt {
+    use std::fmt;
+
+    use serde::{Serializer, Deserializer};
+
+    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: fmt::Display,
+        S: Serializer,
+    {
+        match value {
+            Some(v) => crate::model::string_or_u64::serialize(v, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Some(crate::model::string_or_u64::deserialize(
+            deserializer,
+        )?))
+    }
+}
+
 #[test]
 fn test_account_update_event() {
     let json = r#"
@@ -1438,3 +2918,20 @@ fn test_account_update_event() {
     assert_eq!(format!("{:?}", v), res);
     //let event =  from_value::<AccountUpdateEvent>(json).unwrap();
 }
+
+#[test]
+fn test_order_enums() {
+    let status: OrderStatus = serde_json::from_str(r#""PARTIALLY_FILLED""#).unwrap();
+    assert_eq!(status, OrderStatus::PartiallyFilled);
+    assert_eq!(status.to_string(), "PARTIALLY_FILLED");
+
+    let status: OrderStatus = serde_json::from_str(r#""PENDING_NEW""#).unwrap();
+    assert_eq!(status, OrderStatus::PendingNew);
+
+    let status: OrderStatus = serde_json::from_str(r#""NEW_STATUS""#).unwrap();
+    assert_eq!(status, OrderStatus::Unknown("NEW_STATUS".into()));
+    assert_eq!(serde_json::to_string(&status).unwrap(), r#""NEW_STATUS""#);
+
+    assert_eq!(OrderType::from("STOP_LOSS_LIMIT"), OrderType::StopLossLimit);
+    assert_eq!(TimeInForce::GTC.as_str(), "GTC");
+}
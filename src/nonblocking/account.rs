@@ -0,0 +1,915 @@
+use error_chain::bail;
+
+use crate::util::build_signed_request;
+use crate::model::{
+    AccountInformation, Balance, CancelReplaceResponse, Empty, Order, OrderAmendment,
+    OrderCanceled, OrderList, Symbol, TradeHistory, Transaction, TransactionAck, Number,
+};
+use crate::nonblocking::client::Client;
+use crate::errors::Result;
+use std::collections::BTreeMap;
+use crate::api::API;
+use crate::api::Spot;
+use crate::account::{
+    build_cancel_replace, build_oco_order, build_order, build_order_amend, build_order_history,
+    build_order_list_oco, build_quote_quantity_order, build_spot_order, build_trade_history,
+    cancel_replace_error, OrderQuoteQuantityRequest, OrderRequest,
+};
+pub use crate::account::{
+    CancelReplaceMode, CancelReplaceRequest, CancelRestrictions, NewOrderRespType, OcoLeg,
+    OcoOrderRequest, OrderAmendRequest, OrderHistoryRequest, OrderListOcoRequest,
+    OrderRateLimitExceededMode, OrderSide, OrderType, SelfTradePreventionMode, SpotOrderRequest,
+    TimeInForce, TradeHistoryRequest,
+};
+
+#[derive(Clone)]
+pub struct Account {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl Account {
+    // Account Information
+    pub async fn get_account(&self) -> Result<AccountInformation> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::Account), Some(request))
+            .await
+    }
+
+    // Balance for a single Asset
+    pub async fn get_balance<S>(&self, asset: S) -> Result<Balance>
+    where
+        S: Into<String>,
+    {
+        match self.get_account().await {
+            Ok(account) => {
+                let cmp_asset = asset.into();
+                for balance in account.balances {
+                    if balance.asset == cmp_asset {
+                        return Ok(balance);
+                    }
+                }
+                bail!("Asset not found");
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    // Current open orders for ONE symbol
+    pub async fn get_open_orders<S>(&self, symbol: S) -> Result<Vec<Order>>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::OpenOrders), Some(request))
+            .await
+    }
+
+    // All current open orders
+    pub async fn get_all_open_orders(&self) -> Result<Vec<Order>> {
+        let parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::OpenOrders), Some(request))
+            .await
+    }
+
+    // Cancel all open orders for a single symbol
+    pub async fn cancel_all_open_orders<S>(&self, symbol: S) -> Result<Vec<OrderCanceled>>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Spot(Spot::OpenOrders), Some(request))
+            .await
+    }
+
+    // Check an order's status
+    pub async fn order_status<S>(&self, symbol: S, order_id: u64) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::Order), Some(request))
+            .await
+    }
+
+    // Check an order's status by the client order ID it was placed with
+    pub async fn order_status_with_client_id<S>(
+        &self, symbol: S, orig_client_order_id: String,
+    ) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("origClientOrderId".into(), orig_client_order_id);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::Order), Some(request))
+            .await
+    }
+
+    /// Place a test status order
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching engine.
+    #[deprecated(note = "Binance has no test endpoint for order queries, use order_status")]
+    pub async fn test_order_status<S>(&self, symbol: S, order_id: u64) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed::<Empty>(API::Spot(Spot::OrderTest), Some(request))
+            .await
+            .map(|_| ())
+    }
+
+    // Place a LIMIT order - BUY
+    pub async fn limit_buy<S, F>(&self, symbol: S, qty: F, price: Number) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        let buy = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.into(),
+            price,
+            stop_price: None,
+            order_side: OrderSide::Buy,
+            order_type: OrderType::Limit,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+        };
+        let order = build_order(buy);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed(API::Spot(Spot::Order), request)
+            .await
+    }
+
+    /// Place a test limit order - BUY
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching engine.
+    pub async fn test_limit_buy<S, F>(&self, symbol: S, qty: F, price: Number) -> Result<()>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        let buy = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.into(),
+            price,
+            stop_price: None,
+            order_side: OrderSide::Buy,
+            order_type: OrderType::Limit,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+        };
+        let order = build_order(buy);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
+            .map(|_| ())
+    }
+
+    // Place a LIMIT order - SELL
+    pub async fn limit_sell<S, F>(&self, symbol: S, qty: F, price: Number) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        let sell = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.into(),
+            price,
+            stop_price: None,
+            order_side: OrderSide::Sell,
+            order_type: OrderType::Limit,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+        };
+        let order = build_order(sell);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed(API::Spot(Spot::Order), request)
+            .await
+    }
+
+    /// Place a test LIMIT order - SELL
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching engine.
+    pub async fn test_limit_sell<S, F>(&self, symbol: S, qty: F, price: Number) -> Result<()>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        let sell = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.into(),
+            price,
+            stop_price: None,
+            order_side: OrderSide::Sell,
+            order_type: OrderType::Limit,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+        };
+        let order = build_order(sell);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
+            .map(|_| ())
+    }
+
+    // Place a MARKET order - BUY
+    pub async fn market_buy<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        let buy = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.into(),
+            price: Number::default(),
+            stop_price: None,
+            order_side: OrderSide::Buy,
+            order_type: OrderType::Market,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+        };
+        let order = build_order(buy);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed(API::Spot(Spot::Order), request)
+            .await
+    }
+
+    /// Place a test MARKET order - BUY
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching engine.
+    pub async fn test_market_buy<S, F>(&self, symbol: S, qty: F) -> Result<()>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        let buy = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.into(),
+            price: Number::default(),
+            stop_price: None,
+            order_side: OrderSide::Buy,
+            order_type: OrderType::Market,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+        };
+        let order = build_order(buy);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
+            .map(|_| ())
+    }
+
+    // Place a MARKET order with quote quantity - BUY
+    pub async fn market_buy_using_quote_quantity<S, F>(
+        &self, symbol: S, quote_order_qty: F,
+    ) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        let buy = OrderQuoteQuantityRequest {
+            symbol: symbol.into(),
+            quote_order_qty: quote_order_qty.into(),
+            price: Number::default(),
+            order_side: OrderSide::Buy,
+            order_type: OrderType::Market,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+        };
+        let order = build_quote_quantity_order(buy);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed(API::Spot(Spot::Order), request)
+            .await
+    }
+
+    /// Place a test MARKET order with quote quantity - BUY
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching engine.
+    pub async fn test_market_buy_using_quote_quantity<S, F>(
+        &self, symbol: S, quote_order_qty: F,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        let buy = OrderQuoteQuantityRequest {
+            symbol: symbol.into(),
+            quote_order_qty: quote_order_qty.into(),
+            price: Number::default(),
+            order_side: OrderSide::Buy,
+            order_type: OrderType::Market,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+        };
+        let order = build_quote_quantity_order(buy);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
+            .map(|_| ())
+    }
+
+    // Place a MARKET order - SELL
+    pub async fn market_sell<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        let sell = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.into(),
+            price: Number::default(),
+            stop_price: None,
+            order_side: OrderSide::Sell,
+            order_type: OrderType::Market,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+        };
+        let order = build_order(sell);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed(API::Spot(Spot::Order), request)
+            .await
+    }
+
+    /// Place a test MARKET order - SELL
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching engine.
+    pub async fn test_market_sell<S, F>(&self, symbol: S, qty: F) -> Result<()>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        let sell = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.into(),
+            price: Number::default(),
+            stop_price: None,
+            order_side: OrderSide::Sell,
+            order_type: OrderType::Market,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+        };
+        let order = build_order(sell);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
+            .map(|_| ())
+    }
+
+    // Place a MARKET order with quote quantity - SELL
+    pub async fn market_sell_using_quote_quantity<S, F>(
+        &self, symbol: S, quote_order_qty: F,
+    ) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        let sell = OrderQuoteQuantityRequest {
+            symbol: symbol.into(),
+            quote_order_qty: quote_order_qty.into(),
+            price: Number::default(),
+            order_side: OrderSide::Sell,
+            order_type: OrderType::Market,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+        };
+        let order = build_quote_quantity_order(sell);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed(API::Spot(Spot::Order), request)
+            .await
+    }
+
+    /// Place a test MARKET order with quote quantity - SELL
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching engine.
+    pub async fn test_market_sell_using_quote_quantity<S, F>(
+        &self, symbol: S, quote_order_qty: F,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        let sell = OrderQuoteQuantityRequest {
+            symbol: symbol.into(),
+            quote_order_qty: quote_order_qty.into(),
+            price: Number::default(),
+            order_side: OrderSide::Sell,
+            order_type: OrderType::Market,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+        };
+        let order = build_quote_quantity_order(sell);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
+            .map(|_| ())
+    }
+
+    /// Create a stop limit buy order for the given symbol, price and stop price.
+    /// Returning a `Transaction` value with the same parameters sent on the order.
+    ///
+    ///```no_run
+    /// use binance::api::Binance;
+    /// use binance::nonblocking::account::*;
+    ///
+    /// async fn run() {
+    ///     let api_key = Some("api_key".into());
+    ///     let secret_key = Some("secret_key".into());
+    ///     let account: Account = Binance::new(api_key, secret_key);
+    ///     let result = account.stop_limit_buy_order("LTCBTC", 1, "0.1".parse().unwrap(), "0.09".parse().unwrap(), TimeInForce::GTC).await;
+    /// }
+    /// ```
+    pub async fn stop_limit_buy_order<S, F>(
+        &self, symbol: S, qty: F, price: Number, stop_price: Number, time_in_force: TimeInForce,
+    ) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        let sell = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.into(),
+            price,
+            stop_price: Some(stop_price),
+            order_side: OrderSide::Buy,
+            order_type: OrderType::StopLossLimit,
+            time_in_force,
+            new_client_order_id: None,
+        };
+        let order = build_order(sell);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed(API::Spot(Spot::Order), request)
+            .await
+    }
+
+    /// Create a stop limit buy test order for the given symbol, price and stop price.
+    /// Returning a `Transaction` value with the same parameters sent on the order.
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching engine.
+    ///
+    ///```no_run
+    /// use binance::api::Binance;
+    /// use binance::nonblocking::account::*;
+    ///
+    /// async fn run() {
+    ///     let api_key = Some("api_key".into());
+    ///     let secret_key = Some("secret_key".into());
+    ///     let account: Account = Binance::new(api_key, secret_key);
+    ///     let result = account.test_stop_limit_buy_order("LTCBTC", 1, "0.1".parse().unwrap(), "0.09".parse().unwrap(), TimeInForce::GTC).await;
+    /// }
+    /// ```
+    pub async fn test_stop_limit_buy_order<S, F>(
+        &self, symbol: S, qty: F, price: Number, stop_price: Number, time_in_force: TimeInForce,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        let sell = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.into(),
+            price,
+            stop_price: Some(stop_price),
+            order_side: OrderSide::Buy,
+            order_type: OrderType::StopLossLimit,
+            time_in_force,
+            new_client_order_id: None,
+        };
+        let order = build_order(sell);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
+            .map(|_| ())
+    }
+
+    /// Create a stop limit sell order for the given symbol, price and stop price.
+    /// Returning a `Transaction` value with the same parameters sent on the order.
+    ///
+    ///```no_run
+    /// use binance::api::Binance;
+    /// use binance::nonblocking::account::*;
+    ///
+    /// async fn run() {
+    ///     let api_key = Some("api_key".into());
+    ///     let secret_key = Some("secret_key".into());
+    ///     let account: Account = Binance::new(api_key, secret_key);
+    ///     let result = account.stop_limit_sell_order("LTCBTC", 1, "0.1".parse().unwrap(), "0.09".parse().unwrap(), TimeInForce::GTC).await;
+    /// }
+    /// ```
+    pub async fn stop_limit_sell_order<S, F>(
+        &self, symbol: S, qty: F, price: Number, stop_price: Number, time_in_force: TimeInForce,
+    ) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        let sell = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.into(),
+            price,
+            stop_price: Some(stop_price),
+            order_side: OrderSide::Sell,
+            order_type: OrderType::StopLossLimit,
+            time_in_force,
+            new_client_order_id: None,
+        };
+        let order = build_order(sell);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed(API::Spot(Spot::Order), request)
+            .await
+    }
+
+    /// Create a stop limit sell order for the given symbol, price and stop price.
+    /// Returning a `Transaction` value with the same parameters sent on the order.
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching engine.
+    ///
+    ///```no_run
+    /// use binance::api::Binance;
+    /// use binance::nonblocking::account::*;
+    ///
+    /// async fn run() {
+    ///     let api_key = Some("api_key".into());
+    ///     let secret_key = Some("secret_key".into());
+    ///     let account: Account = Binance::new(api_key, secret_key);
+    ///     let result = account.test_stop_limit_sell_order("LTCBTC", 1, "0.1".parse().unwrap(), "0.09".parse().unwrap(), TimeInForce::GTC).await;
+    /// }
+    /// ```
+    pub async fn test_stop_limit_sell_order<S, F>(
+        &self, symbol: S, qty: F, price: Number, stop_price: Number, time_in_force: TimeInForce,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        let sell = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.into(),
+            price,
+            stop_price: Some(stop_price),
+            order_side: OrderSide::Sell,
+            order_type: OrderType::StopLossLimit,
+            time_in_force,
+            new_client_order_id: None,
+        };
+        let order = build_order(sell);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
+            .map(|_| ())
+    }
+
+    /// Place a custom order
+    #[allow(clippy::too_many_arguments)]
+    pub async fn custom_order<S, F>(
+        &self, symbol: S, qty: F, price: Number, stop_price: Option<Number>, order_side: OrderSide,
+        order_type: OrderType, time_in_force: TimeInForce, new_client_order_id: Option<String>,
+    ) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        let sell = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.into(),
+            price,
+            stop_price,
+            order_side,
+            order_type,
+            time_in_force,
+            new_client_order_id,
+        };
+        let order = build_order(sell);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed(API::Spot(Spot::Order), request)
+            .await
+    }
+
+    /// Place a test custom order
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching engine.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn test_custom_order<S, F>(
+        &self, symbol: S, qty: F, price: Number, stop_price: Option<Number>, order_side: OrderSide,
+        order_type: OrderType, time_in_force: TimeInForce, new_client_order_id: Option<String>,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        let sell = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.into(),
+            price,
+            stop_price,
+            order_side,
+            order_type,
+            time_in_force,
+            new_client_order_id,
+        };
+        let order = build_order(sell);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
+            .map(|_| ())
+    }
+
+    /// Place an order built with `SpotOrderRequest`.
+    ///
+    /// The request is validated locally before it is sent. Orders asking
+    /// for an `ACK` response must use `place_order_ack` instead.
+    pub async fn place_order(&self, order: SpotOrderRequest) -> Result<Transaction> {
+        if order.new_order_resp_type == Some(NewOrderRespType::ACK) {
+            bail!("ACK responses have no order details, use place_order_ack");
+        }
+        order.validate()?;
+        let request = build_signed_request(build_spot_order(order), self.recv_window)?;
+        self.client
+            .post_signed(API::Spot(Spot::Order), request)
+            .await
+    }
+
+    /// Place an order built with `SpotOrderRequest`, after checking it against
+    /// the symbol filters so it fails with `ErrorKind::FilterViolations` instead
+    /// of a round trip to the exchange.
+    pub async fn place_order_checked(
+        &self, symbol: &Symbol, order: SpotOrderRequest,
+    ) -> Result<Transaction> {
+        order.check_filters(symbol)?;
+        self.place_order(order).await
+    }
+
+    /// Place an order built with `SpotOrderRequest`, asking only for an acknowledgement.
+    pub async fn place_order_ack(&self, order: SpotOrderRequest) -> Result<TransactionAck> {
+        let order = order.set_new_order_resp_type(NewOrderRespType::ACK);
+        order.validate()?;
+        let request = build_signed_request(build_spot_order(order), self.recv_window)?;
+        self.client
+            .post_signed(API::Spot(Spot::Order), request)
+            .await
+    }
+
+    /// Place a test order built with `SpotOrderRequest`
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching engine.
+    pub async fn test_place_order(&self, order: SpotOrderRequest) -> Result<()> {
+        order.validate()?;
+        let request = build_signed_request(build_spot_order(order), self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
+            .map(|_| ())
+    }
+
+    /// Place an OCO order through the legacy `order/oco` endpoint.
+    pub async fn place_oco(&self, order: OcoOrderRequest) -> Result<OrderList> {
+        order.validate()?;
+        let request = build_signed_request(build_oco_order(order), self.recv_window)?;
+        self.client.post_signed(API::Spot(Spot::Oco), request).await
+    }
+
+    /// Place an OCO order through `orderList/oco`.
+    pub async fn place_order_list_oco(&self, order: OrderListOcoRequest) -> Result<OrderList> {
+        order.validate()?;
+        let request = build_signed_request(build_order_list_oco(order), self.recv_window)?;
+        self.client
+            .post_signed(API::Spot(Spot::OrderListOco), request)
+            .await
+    }
+
+    /// Cancel an order and place a new one in a single request.
+    ///
+    /// Fails with `ErrorKind::CancelReplaceFailed` when either leg failed, the
+    /// cancel may still have gone through, see `CancelReplaceResponse`.
+    pub async fn cancel_replace_order(
+        &self, request: CancelReplaceRequest,
+    ) -> Result<CancelReplaceResponse> {
+        request.validate()?;
+        let request = build_signed_request(build_cancel_replace(request), self.recv_window)?;
+        self.client
+            .post_signed(API::Spot(Spot::CancelReplace), request)
+            .await
+            .map_err(cancel_replace_error)
+    }
+
+    /// Reduce the quantity of an open order, keeping its priority in the book.
+    pub async fn amend_order_keep_priority(
+        &self, request: OrderAmendRequest,
+    ) -> Result<OrderAmendment> {
+        request.validate()?;
+        let request = build_signed_request(build_order_amend(request), self.recv_window)?;
+        self.client
+            .put_signed(API::Spot(Spot::OrderAmendKeepPriority), request)
+            .await
+    }
+
+    /// Cancel every order of an order list
+    pub async fn cancel_order_list<S>(&self, symbol: S, order_list_id: u64) -> Result<OrderList>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderListId".into(), order_list_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Spot(Spot::OrderList), Some(request))
+            .await
+    }
+
+    pub async fn cancel_order_list_with_client_id<S>(
+        &self, symbol: S, list_client_order_id: String,
+    ) -> Result<OrderList>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("listClientOrderId".into(), list_client_order_id);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Spot(Spot::OrderList), Some(request))
+            .await
+    }
+
+    // Check an order list's status
+    pub async fn order_list_status(&self, order_list_id: u64) -> Result<OrderList> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("orderListId".into(), order_list_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::OrderList), Some(request))
+            .await
+    }
+
+    pub async fn order_list_status_with_client_id(
+        &self, orig_client_order_id: String,
+    ) -> Result<OrderList> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("origClientOrderId".into(), orig_client_order_id);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::OrderList), Some(request))
+            .await
+    }
+
+    /// All order lists, from `from_id` or within a time window
+    pub async fn get_all_order_lists<F, N>(
+        &self, from_id: F, start_time: F, end_time: F, limit: N,
+    ) -> Result<Vec<OrderList>>
+    where
+        F: Into<Option<u64>>,
+        N: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(from_id) = from_id.into() {
+            parameters.insert("fromId".into(), from_id.to_string());
+        }
+        if let Some(start_time) = start_time.into() {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = end_time.into() {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
+        if let Some(limit) = limit.into() {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::AllOrderList), Some(request))
+            .await
+    }
+
+    // All open order lists
+    pub async fn get_open_order_lists(&self) -> Result<Vec<OrderList>> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::OpenOrderList), Some(request))
+            .await
+    }
+
+    // Check an order's status
+    pub async fn cancel_order<S>(&self, symbol: S, order_id: u64) -> Result<OrderCanceled>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Spot(Spot::Order), Some(request))
+            .await
+    }
+
+    pub async fn cancel_order_with_client_id<S>(
+        &self, symbol: S, orig_client_order_id: String,
+    ) -> Result<OrderCanceled>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("origClientOrderId".into(), orig_client_order_id);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Spot(Spot::Order), Some(request))
+            .await
+    }
+    /// Place a test cancel order
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching engine.
+    pub async fn test_cancel_order<S>(&self, symbol: S, order_id: u64) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed::<Empty>(API::Spot(Spot::OrderTest), Some(request))
+            .await
+            .map(|_| ())
+    }
+
+    // Trade history
+    pub async fn trade_history<S>(&self, symbol: S) -> Result<Vec<TradeHistory>>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::MyTrades), Some(request))
+            .await
+    }
+
+    /// All orders of a symbol: active, canceled or filled.
+    pub async fn get_all_orders(&self, request: OrderHistoryRequest) -> Result<Vec<Order>> {
+        let request = build_signed_request(build_order_history(request), self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::AllOrders), Some(request))
+            .await
+    }
+
+    /// Trades of a symbol, by order, trade ID or time window.
+    pub async fn my_trades(&self, request: TradeHistoryRequest) -> Result<Vec<TradeHistory>> {
+        let request = build_signed_request(build_trade_history(request), self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::MyTrades), Some(request))
+            .await
+    }
+}
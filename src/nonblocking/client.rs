@@ -0,0 +1,284 @@
+use crate::client::{build_headers, handle_response, retry_after, Request};
+use crate::errors::{ErrorKind, Result};
+use error_chain::bail;
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode};
+use serde::de::DeserializeOwned;
+use crate::api::{Futures, Spot, API};
+use crate::config::Config;
+use crate::ratelimit::RateLimiter;
+use crate::model::ServerTime;
+use crate::retry::RetryPolicy;
+use crate::signer::{HmacSigner, Signer};
+use crate::timesync::TimeSync;
+use crate::transport::{AsyncTransport, HttpRequest, ReqwestAsyncTransport};
+use crate::util::{encode_param, get_timestamp};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+#[derive(Clone)]
+pub struct Client {
+    api_key: String,
+    signer: Arc<dyn Signer>,
+    host: String,
+    rate_limiter: Arc<RateLimiter>,
+    retry_policy: RetryPolicy,
+    time_sync: Option<Arc<TimeSync>>,
+    time_endpoint: API,
+    signed_body: bool,
+    transport: Arc<dyn AsyncTransport>,
+}
+
+impl Client {
+    pub fn new(api_key: Option<String>, secret_key: Option<String>, host: String) -> Self {
+        Client {
+            api_key: api_key.unwrap_or_default(),
+            signer: Arc::new(HmacSigner::new(secret_key.unwrap_or_default())),
+            host,
+            rate_limiter: Arc::new(RateLimiter::default()),
+            retry_policy: RetryPolicy::default(),
+            time_sync: None,
+            time_endpoint: API::Spot(Spot::Time),
+            signed_body: false,
+            transport: Arc::new(ReqwestAsyncTransport::default()),
+        }
+    }
+
+    /// Client for the spot API, or the futures API if `futures`, set up from `config`.
+    pub(crate) fn from_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config, futures: bool,
+    ) -> Self {
+        let (host, rate_limiter, time_sync, time_endpoint) = if futures {
+            (
+                &config.futures_rest_api_endpoint,
+                &config.futures_rate_limiter,
+                &config.futures_time_sync,
+                API::Futures(Futures::Time),
+            )
+        } else {
+            (
+                &config.rest_api_endpoint,
+                &config.rate_limiter,
+                &config.time_sync,
+                API::Spot(Spot::Time),
+            )
+        };
+        Client::new(api_key, secret_key, host.clone())
+            .set_rate_limiter(rate_limiter.clone())
+            .set_signer(config.signer.clone())
+            .set_signed_body(config.signed_body)
+            .set_retry_policy(config.retry_policy.clone())
+            .set_transport(config.async_transport.clone())
+            .set_time_sync(time_sync.clone(), time_endpoint)
+    }
+
+    pub fn set_rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    pub fn set_signer(mut self, signer: Option<Arc<dyn Signer>>) -> Self {
+        if let Some(signer) = signer {
+            self.signer = signer;
+        }
+        self
+    }
+
+    pub fn set_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub fn set_time_sync(mut self, time_sync: Option<Arc<TimeSync>>, endpoint: API) -> Self {
+        self.time_sync = time_sync;
+        self.time_endpoint = endpoint;
+        self
+    }
+
+    /// Send the parameters of signed POST, PUT and DELETE requests in a form body.
+    pub fn set_signed_body(mut self, signed_body: bool) -> Self {
+        self.signed_body = signed_body;
+        self
+    }
+
+    pub fn set_transport(mut self, transport: Option<Arc<dyn AsyncTransport>>) -> Self {
+        if let Some(transport) = transport {
+            self.transport = transport;
+        }
+        self
+    }
+
+    pub fn rate_limiter(&self) -> &Arc<RateLimiter> {
+        &self.rate_limiter
+    }
+
+    pub fn time_sync(&self) -> Option<&Arc<TimeSync>> {
+        self.time_sync.as_ref()
+    }
+
+    /// Measure the server time offset now and return it in milliseconds.
+    pub async fn sync_time(&self) -> Result<i64> {
+        let time_sync = match &self.time_sync {
+            Some(time_sync) => time_sync,
+            None => bail!("Time sync is not enabled"),
+        };
+        let sent = get_timestamp(SystemTime::now())?;
+        let server_time: ServerTime =
+            Box::pin(self.execute(Request::public(self.time_endpoint, None))).await?;
+        let received = get_timestamp(SystemTime::now())?;
+
+        Ok(time_sync.record(sent, received, server_time.server_time))
+    }
+
+    async fn timestamp(&self) -> Result<u64> {
+        match &self.time_sync {
+            Some(time_sync) => {
+                if time_sync.needs_sync() {
+                    self.sync_time().await?;
+                }
+                time_sync.now()
+            }
+            None => get_timestamp(SystemTime::now()),
+        }
+    }
+
+    pub async fn get_signed<T: DeserializeOwned>(
+        &self, endpoint: API, request: Option<String>,
+    ) -> Result<T> {
+        self.execute(Request::signed(Method::GET, endpoint, request))
+            .await
+    }
+
+    pub async fn post_signed<T: DeserializeOwned>(
+        &self, endpoint: API, request: String,
+    ) -> Result<T> {
+        self.execute(Request::signed(Method::POST, endpoint, Some(request)))
+            .await
+    }
+
+    pub async fn put_signed<T: DeserializeOwned>(
+        &self, endpoint: API, request: String,
+    ) -> Result<T> {
+        self.execute(Request::signed(Method::PUT, endpoint, Some(request)))
+            .await
+    }
+
+    pub async fn delete_signed<T: DeserializeOwned>(
+        &self, endpoint: API, request: Option<String>,
+    ) -> Result<T> {
+        self.execute(Request::signed(Method::DELETE, endpoint, request))
+            .await
+    }
+
+    pub async fn get<T: DeserializeOwned>(
+        &self, endpoint: API, request: Option<String>,
+    ) -> Result<T> {
+        self.execute(Request::public(endpoint, request)).await
+    }
+
+    // Public data that still needs the API key, e.g. historical trades
+    pub async fn get_keyed<T: DeserializeOwned>(
+        &self, endpoint: API, request: Option<String>,
+    ) -> Result<T> {
+        self.execute(Request {
+            with_api_key: true,
+            ..Request::public(endpoint, request)
+        })
+        .await
+    }
+
+    pub async fn post<T: DeserializeOwned>(&self, endpoint: API) -> Result<T> {
+        self.execute(Request::keyed(Method::POST, endpoint, None))
+            .await
+    }
+
+    pub async fn put<T: DeserializeOwned>(&self, endpoint: API, listen_key: &str) -> Result<T> {
+        let data: String = format!("listenKey={}", encode_param(listen_key));
+        self.execute(Request::keyed(Method::PUT, endpoint, Some(data)))
+            .await
+    }
+
+    pub async fn delete<T: DeserializeOwned>(&self, endpoint: API, listen_key: &str) -> Result<T> {
+        let data: String = format!("listenKey={}", encode_param(listen_key));
+        self.execute(Request::keyed(Method::DELETE, endpoint, Some(data)))
+            .await
+    }
+
+    /// Send a request to any `path`, e.g. an endpoint without a wrapper yet.
+    pub async fn request<T: DeserializeOwned>(
+        &self, method: Method, path: &str, request: Option<String>, signed: bool,
+    ) -> Result<T> {
+        let with_api_key = signed || !self.api_key.is_empty();
+        self.execute(Request::raw(method, path, request, signed, with_api_key))
+            .await
+    }
+
+    async fn execute<T: DeserializeOwned>(&self, request: Request) -> Result<T> {
+        let mut attempt = 1;
+        loop {
+            self.rate_limiter.check_ban()?;
+            self.rate_limiter
+                .acquire_async(request.weight, request.orders)
+                .await?;
+
+            let (url, body) = if request.signed {
+                let timestamp = self.timestamp().await?;
+                request.sign(
+                    &self.host,
+                    self.signer.as_ref(),
+                    timestamp,
+                    self.signed_body,
+                )?
+            } else {
+                (request.url(&self.host), request.body.clone())
+            };
+            let headers = if request.with_api_key {
+                build_headers(&self.api_key, request.signed)?
+            } else {
+                HeaderMap::new()
+            };
+            let http_request = HttpRequest {
+                method: request.method.clone(),
+                url,
+                headers,
+                body,
+            };
+
+            let response = match self.transport.send(http_request).await {
+                Ok(response) => response,
+                Err(e) => match self
+                    .retry_policy
+                    .retry_error(&e, attempt, request.idempotent())
+                {
+                    Some(delay) => {
+                        tokio::time::sleep(delay).await;
+                        attempt += 1;
+                        continue;
+                    }
+                    None => return Err(e),
+                },
+            };
+
+            self.rate_limiter.update(&response.headers);
+            let status = response.status;
+            let retry_after = retry_after(&response.headers);
+            if status == StatusCode::IM_A_TEAPOT {
+                bail!(ErrorKind::IpBanned(self.rate_limiter.ban(retry_after)));
+            }
+            if let Some(delay) =
+                self.retry_policy
+                    .retry_status(status, retry_after, attempt, request.idempotent())
+            {
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+                continue;
+            }
+
+            let result = handle_response(status, retry_after, &response.body);
+            if let Some(time_sync) = &self.time_sync {
+                time_sync.check(&result);
+            }
+            return result;
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use crate::util::{build_request, build_signed_request};
+use crate::nonblocking::client::Client;
+use crate::errors::Result;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+
+/// Requests to any path on the USD-M futures host, for endpoints without a wrapper.
+///
+/// Signed requests get `recvWindow`, `timestamp` and `signature` like every other
+/// call, and rate limits, retries and errors are handled the same way. Ask for a
+/// `serde_json::Value` to get the response as it is.
+#[derive(Clone)]
+pub struct FuturesRest {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl FuturesRest {
+    pub async fn get<T: DeserializeOwned>(
+        &self, path: &str, parameters: BTreeMap<String, String>,
+    ) -> Result<T> {
+        self.request(Method::GET, path, parameters, false).await
+    }
+
+    pub async fn get_signed<T: DeserializeOwned>(
+        &self, path: &str, parameters: BTreeMap<String, String>,
+    ) -> Result<T> {
+        self.request(Method::GET, path, parameters, true).await
+    }
+
+    pub async fn post<T: DeserializeOwned>(
+        &self, path: &str, parameters: BTreeMap<String, String>,
+    ) -> Result<T> {
+        self.request(Method::POST, path, parameters, false).await
+    }
+
+    pub async fn post_signed<T: DeserializeOwned>(
+        &self, path: &str, parameters: BTreeMap<String, String>,
+    ) -> Result<T> {
+        self.request(Method::POST, path, parameters, true).await
+    }
+
+    pub async fn put<T: DeserializeOwned>(
+        &self, path: &str, parameters: BTreeMap<String, String>,
+    ) -> Result<T> {
+        self.request(Method::PUT, path, parameters, false).await
+    }
+
+    pub async fn put_signed<T: DeserializeOwned>(
+        &self, path: &str, parameters: BTreeMap<String, String>,
+    ) -> Result<T> {
+        self.request(Method::PUT, path, parameters, true).await
+    }
+
+    pub async fn delete<T: DeserializeOwned>(
+        &self, path: &str, parameters: BTreeMap<String, String>,
+    ) -> Result<T> {
+        self.request(Method::DELETE, path, parameters, false).await
+    }
+
+    pub async fn delete_signed<T: DeserializeOwned>(
+        &self, path: &str, parameters: BTreeMap<String, String>,
+    ) -> Result<T> {
+        self.request(Method::DELETE, path, parameters, true).await
+    }
+
+    pub async fn request<T: DeserializeOwned>(
+        &self, method: Method, path: &str, parameters: BTreeMap<String, String>, signed: bool,
+    ) -> Result<T> {
+        let request = if signed {
+            Some(build_signed_request(parameters, self.recv_window)?)
+        } else if parameters.is_empty() {
+            None
+        } else {
+            Some(build_request(parameters))
+        };
+        self.client.request(method, path, request, signed).await
+    }
+}
@@ -0,0 +1,287 @@
+use crate::util::build_signed_request;
+use crate::model::{
+    InterestRecord, IsolatedMarginAccount, LoanRecord, MarginAccount, MarginOrder, Records,
+    MarginTrade, MarginTransaction, MaxBorrowable, MaxTransferable, Number, OrderCanceled,
+    OrderList, TransactionId,
+};
+use crate::nonblocking::client::Client;
+use crate::errors::Result;
+use std::collections::BTreeMap;
+use crate::api::API;
+use crate::api::MarginSapi;
+use crate::account::{build_order_history, build_trade_history};
+use crate::margin::{
+    build_asset_query, build_borrow_repay, build_isolated_transfer, build_margin_history,
+    build_margin_oco, build_margin_order, build_symbol_query, insert_isolated,
+};
+pub use crate::account::{OrderHistoryRequest, TradeHistoryRequest};
+pub use crate::margin::{
+    BorrowRepayType, IsolatedTransferDirection, MarginHistoryRequest, MarginOcoRequest,
+    MarginOrderRequest, MarginTransferType, SideEffectType,
+};
+
+#[derive(Clone)]
+pub struct Margin {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl Margin {
+    // Cross margin account details
+    pub async fn get_account(&self) -> Result<MarginAccount> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Margin(MarginSapi::Account), Some(request))
+            .await
+    }
+
+    /// Isolated margin account details of up to 5 symbols, or of all of them.
+    pub async fn get_isolated_account(&self, symbols: &[&str]) -> Result<IsolatedMarginAccount> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if !symbols.is_empty() {
+            parameters.insert("symbols".into(), symbols.join(","));
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Margin(MarginSapi::IsolatedAccount), Some(request))
+            .await
+    }
+
+    /// Borrow on the cross margin account, or on the isolated margin account of `isolated_symbol`.
+    pub async fn borrow<S, F>(
+        &self, asset: S, amount: F, isolated_symbol: Option<String>,
+    ) -> Result<TransactionId>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        let parameters = build_borrow_repay(
+            BorrowRepayType::Borrow,
+            asset.into(),
+            amount.into(),
+            isolated_symbol,
+        );
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Margin(MarginSapi::BorrowRepay), request)
+            .await
+    }
+
+    /// Repay a loan of the cross margin account, or of the isolated margin account of `isolated_symbol`.
+    pub async fn repay<S, F>(
+        &self, asset: S, amount: F, isolated_symbol: Option<String>,
+    ) -> Result<TransactionId>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        let parameters = build_borrow_repay(
+            BorrowRepayType::Repay,
+            asset.into(),
+            amount.into(),
+            isolated_symbol,
+        );
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Margin(MarginSapi::BorrowRepay), request)
+            .await
+    }
+
+    // Borrow or repay records, newest first
+    pub async fn borrow_repay_history(
+        &self, borrow_repay_type: BorrowRepayType, request: MarginHistoryRequest,
+    ) -> Result<Records<LoanRecord>> {
+        let mut parameters = build_margin_history(request);
+        parameters.insert("type".into(), borrow_repay_type.to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Margin(MarginSapi::BorrowRepay), Some(request))
+            .await
+    }
+
+    // Interest records, newest first
+    pub async fn interest_history(
+        &self, request: MarginHistoryRequest,
+    ) -> Result<Records<InterestRecord>> {
+        let request = build_signed_request(build_margin_history(request), self.recv_window)?;
+        self.client
+            .get_signed(API::Margin(MarginSapi::InterestHistory), Some(request))
+            .await
+    }
+
+    pub async fn max_borrowable<S>(
+        &self, asset: S, isolated_symbol: Option<String>,
+    ) -> Result<MaxBorrowable>
+    where
+        S: Into<String>,
+    {
+        let request = build_signed_request(
+            build_asset_query(asset.into(), isolated_symbol),
+            self.recv_window,
+        )?;
+        self.client
+            .get_signed(API::Margin(MarginSapi::MaxBorrowable), Some(request))
+            .await
+    }
+
+    pub async fn max_transferable<S>(
+        &self, asset: S, isolated_symbol: Option<String>,
+    ) -> Result<MaxTransferable>
+    where
+        S: Into<String>,
+    {
+        let request = build_signed_request(
+            build_asset_query(asset.into(), isolated_symbol),
+            self.recv_window,
+        )?;
+        self.client
+            .get_signed(API::Margin(MarginSapi::MaxTransferable), Some(request))
+            .await
+    }
+
+    /// Place a margin order built with `MarginOrderRequest`.
+    pub async fn place_order(&self, order: MarginOrderRequest) -> Result<MarginTransaction> {
+        order.validate()?;
+        let request = build_signed_request(build_margin_order(order), self.recv_window)?;
+        self.client
+            .post_signed(API::Margin(MarginSapi::Order), request)
+            .await
+    }
+
+    /// Place a margin OCO order built with `MarginOcoRequest`.
+    pub async fn place_oco(&self, order: MarginOcoRequest) -> Result<OrderList> {
+        order.validate()?;
+        let request = build_signed_request(build_margin_oco(order), self.recv_window)?;
+        self.client
+            .post_signed(API::Margin(MarginSapi::Oco), request)
+            .await
+    }
+
+    pub async fn cancel_order<S>(
+        &self, symbol: S, order_id: u64, is_isolated: bool,
+    ) -> Result<OrderCanceled>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = build_symbol_query(symbol.into(), is_isolated);
+        parameters.insert("orderId".into(), order_id.to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Margin(MarginSapi::Order), Some(request))
+            .await
+    }
+
+    pub async fn cancel_order_with_client_id<S>(
+        &self, symbol: S, orig_client_order_id: String, is_isolated: bool,
+    ) -> Result<OrderCanceled>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = build_symbol_query(symbol.into(), is_isolated);
+        parameters.insert("origClientOrderId".into(), orig_client_order_id);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Margin(MarginSapi::Order), Some(request))
+            .await
+    }
+
+    /// Cancel both orders of a margin OCO
+    pub async fn cancel_oco<S>(
+        &self, symbol: S, order_list_id: u64, is_isolated: bool,
+    ) -> Result<OrderList>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = build_symbol_query(symbol.into(), is_isolated);
+        parameters.insert("orderListId".into(), order_list_id.to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Margin(MarginSapi::OrderList), Some(request))
+            .await
+    }
+
+    // Check a margin order's status
+    pub async fn order_status<S>(
+        &self, symbol: S, order_id: u64, is_isolated: bool,
+    ) -> Result<MarginOrder>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = build_symbol_query(symbol.into(), is_isolated);
+        parameters.insert("orderId".into(), order_id.to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Margin(MarginSapi::Order), Some(request))
+            .await
+    }
+
+    pub async fn get_open_orders<S>(&self, symbol: S, is_isolated: bool) -> Result<Vec<MarginOrder>>
+    where
+        S: Into<String>,
+    {
+        let parameters = build_symbol_query(symbol.into(), is_isolated);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Margin(MarginSapi::OpenOrders), Some(request))
+            .await
+    }
+
+    /// All margin orders of a symbol: active, canceled or filled.
+    pub async fn get_all_orders(
+        &self, request: OrderHistoryRequest, is_isolated: bool,
+    ) -> Result<Vec<MarginOrder>> {
+        let mut parameters = build_order_history(request);
+        insert_isolated(&mut parameters, is_isolated);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Margin(MarginSapi::AllOrders), Some(request))
+            .await
+    }
+
+    /// Margin trades of a symbol, by order, trade ID or time window.
+    pub async fn my_trades(
+        &self, request: TradeHistoryRequest, is_isolated: bool,
+    ) -> Result<Vec<MarginTrade>> {
+        let mut parameters = build_trade_history(request);
+        insert_isolated(&mut parameters, is_isolated);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Margin(MarginSapi::MyTrades), Some(request))
+            .await
+    }
+
+    /// Move an asset between the spot and the cross margin account.
+    pub async fn transfer<S, F>(
+        &self, asset: S, amount: F, transfer_type: MarginTransferType,
+    ) -> Result<TransactionId>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("asset".into(), asset.into());
+        parameters.insert("amount".into(), amount.into().to_string());
+        parameters.insert("type".into(), (transfer_type as u8).to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Margin(MarginSapi::Transfer), request)
+            .await
+    }
+
+    /// Move an asset between the spot and the isolated margin account of `symbol`.
+    pub async fn isolated_transfer<S1, S2, F>(
+        &self, asset: S1, symbol: S2, amount: F, direction: IsolatedTransferDirection,
+    ) -> Result<TransactionId>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        F: Into<Number>,
+    {
+        let parameters =
+            build_isolated_transfer(asset.into(), symbol.into(), amount.into(), direction);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Margin(MarginSapi::IsolatedTransfer), request)
+            .await
+    }
+}
@@ -0,0 +1,20 @@
+//! Async counterparts of the blocking API modules.
+//!
+//! Every module here mirrors the blocking module of the same name and returns the same
+//! models, but is built on the async `reqwest::Client`. Construct them through
+//! [`Binance`](crate::api::Binance) exactly like the blocking ones.
+
+mod client;
+
+pub(crate) use client::Client;
+
+pub mod account;
+pub mod general;
+pub mod margin;
+pub mod market;
+pub mod rest;
+pub mod savings;
+pub mod subaccount;
+pub mod userstream;
+
+pub mod futures;
@@ -0,0 +1,291 @@
+use crate::util::build_signed_request;
+use crate::model::{
+    AssetDetail, CoinInfo, DepositAddress, DepositRecord, EarnPurchase, EarnRedemption,
+    FlexiblePosition, FlexibleProduct, FlexibleRedemptionRecord, FlexibleRewardRecord,
+    FlexibleSubscriptionRecord, LockedPosition, LockedProduct, LockedRedemptionRecord,
+    LockedRewardRecord, LockedSubscriptionRecord, Number, Records, TransactionId,
+    UniversalTransferRecord, WithdrawRecord, WithdrawResponse,
+};
+use crate::nonblocking::client::Client;
+use crate::errors::Result;
+use std::collections::BTreeMap;
+use crate::api::API;
+use crate::api::Sapi;
+use crate::savings::{
+    build_deposit_history, build_earn_history, build_earn_product, build_flexible_position,
+    build_flexible_redeem, build_flexible_subscribe, build_locked_position, build_locked_subscribe,
+    build_universal_transfer, build_universal_transfer_history, build_withdraw,
+    build_withdraw_history,
+};
+pub use crate::savings::{
+    DepositHistoryRequest, DepositStatus, EarnAccount, EarnHistoryRequest, EarnProductRequest,
+    EarnRewardType, FlexiblePositionRequest, FlexibleRedeemRequest, FlexibleSubscribeRequest,
+    LockedPositionRequest, LockedRedeemTo, LockedSubscribeRequest, SpotFuturesTransferType,
+    TransferStatus, UniversalTransferHistoryRequest, UniversalTransferRequest,
+    UniversalTransferType, WalletType, WithdrawHistoryRequest, WithdrawRequest, WithdrawStatus,
+};
+
+#[derive(Clone)]
+pub struct Savings {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl Savings {
+    /// Get all coins available for deposit and withdrawal
+    pub async fn get_all_coins(&self) -> Result<Vec<CoinInfo>> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::AllCoins), Some(request))
+            .await
+    }
+
+    /// Fetch details of assets supported on Binance.
+    pub async fn asset_detail(
+        &self, asset: Option<String>,
+    ) -> Result<BTreeMap<String, AssetDetail>> {
+        let mut parameters = BTreeMap::new();
+        if let Some(asset) = asset {
+            parameters.insert("asset".into(), asset);
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::AssetDetail), Some(request))
+            .await
+    }
+
+    /// Fetch deposit address with network.
+    ///
+    /// You can get the available networks using `get_all_coins`.
+    /// If no network is specified, the address for the default network is returned.
+    pub async fn deposit_address<S>(
+        &self, coin: S, network: Option<String>,
+    ) -> Result<DepositAddress>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("coin".into(), coin.into());
+        if let Some(network) = network {
+            parameters.insert("network".into(), network);
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::DepositAddress), Some(request))
+            .await
+    }
+
+    /// Move an asset between the spot and the futures wallets.
+    ///
+    /// The same transfer is `universal_transfer` with `UniversalTransferType::from(transfer_type)`,
+    /// which needs the "Permits Universal Transfer" API key permission.
+    #[deprecated(note = "Binance deprecated /sapi/v1/futures/transfer, use universal_transfer")]
+    pub async fn transfer_funds<S>(
+        &self, asset: S, amount: Number, transfer_type: SpotFuturesTransferType,
+    ) -> Result<TransactionId>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("asset".into(), asset.into());
+        parameters.insert("amount".into(), amount.to_string());
+        parameters.insert("type".into(), (transfer_type as u8).to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::SpotFuturesTransfer), request)
+            .await
+    }
+
+    /// Move an asset between any two wallets.
+    pub async fn universal_transfer(
+        &self, request: UniversalTransferRequest,
+    ) -> Result<TransactionId> {
+        request.validate()?;
+        let request = build_signed_request(build_universal_transfer(request), self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::UniversalTransfer), request)
+            .await
+    }
+
+    /// Universal transfers of one type, newest first.
+    pub async fn universal_transfer_history(
+        &self, request: UniversalTransferHistoryRequest,
+    ) -> Result<Records<UniversalTransferRecord>> {
+        let request =
+            build_signed_request(build_universal_transfer_history(request), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::UniversalTransfer), Some(request))
+            .await
+    }
+
+    /// Submit a withdrawal, returning its ID in the withdraw history.
+    pub async fn withdraw(&self, request: WithdrawRequest) -> Result<WithdrawResponse> {
+        let request = build_signed_request(build_withdraw(request), self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::Withdraw), request)
+            .await
+    }
+
+    /// Deposits inside a window of at most 90 days, newest first.
+    pub async fn deposit_history(
+        &self, request: DepositHistoryRequest,
+    ) -> Result<Vec<DepositRecord>> {
+        let request = build_signed_request(build_deposit_history(request), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::DepositHistory), Some(request))
+            .await
+    }
+
+    /// Withdrawals inside a window of at most 90 days, or 7 days for one
+    /// `withdraw_order_id`, newest first.
+    pub async fn withdraw_history(
+        &self, request: WithdrawHistoryRequest,
+    ) -> Result<Vec<WithdrawRecord>> {
+        let request = build_signed_request(build_withdraw_history(request), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::WithdrawHistory), Some(request))
+            .await
+    }
+
+    /// Flexible Simple Earn products.
+    pub async fn flexible_products(
+        &self, request: EarnProductRequest,
+    ) -> Result<Records<FlexibleProduct>> {
+        let request = build_signed_request(build_earn_product(request), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::FlexibleProducts), Some(request))
+            .await
+    }
+
+    /// Locked Simple Earn products.
+    pub async fn locked_products(
+        &self, request: EarnProductRequest,
+    ) -> Result<Records<LockedProduct>> {
+        let request = build_signed_request(build_earn_product(request), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::LockedProducts), Some(request))
+            .await
+    }
+
+    /// Subscribe to a flexible product.
+    pub async fn subscribe_flexible(
+        &self, request: FlexibleSubscribeRequest,
+    ) -> Result<EarnPurchase> {
+        let request = build_signed_request(build_flexible_subscribe(request), self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::FlexibleSubscribe), request)
+            .await
+    }
+
+    /// Subscribe to a locked product, opening a new position.
+    pub async fn subscribe_locked(&self, request: LockedSubscribeRequest) -> Result<EarnPurchase> {
+        let request = build_signed_request(build_locked_subscribe(request), self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::LockedSubscribe), request)
+            .await
+    }
+
+    /// Redeem part or all of a flexible position.
+    pub async fn redeem_flexible(&self, request: FlexibleRedeemRequest) -> Result<EarnRedemption> {
+        request.validate()?;
+        let request = build_signed_request(build_flexible_redeem(request), self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::FlexibleRedeem), request)
+            .await
+    }
+
+    /// Redeem a locked position before the end of its duration.
+    pub async fn redeem_locked(&self, position_id: u64) -> Result<EarnRedemption> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("positionId".into(), position_id.to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::LockedRedeem), request)
+            .await
+    }
+
+    /// Flexible positions.
+    pub async fn flexible_positions(
+        &self, request: FlexiblePositionRequest,
+    ) -> Result<Records<FlexiblePosition>> {
+        let request = build_signed_request(build_flexible_position(request), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::FlexiblePosition), Some(request))
+            .await
+    }
+
+    /// Locked positions.
+    pub async fn locked_positions(
+        &self, request: LockedPositionRequest,
+    ) -> Result<Records<LockedPosition>> {
+        let request = build_signed_request(build_locked_position(request), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::LockedPosition), Some(request))
+            .await
+    }
+
+    /// Flexible subscriptions, newest first.
+    pub async fn flexible_subscription_history(
+        &self, request: EarnHistoryRequest,
+    ) -> Result<Records<FlexibleSubscriptionRecord>> {
+        let request = build_signed_request(build_earn_history(request), self.recv_window)?;
+        self.client
+            .get_signed(
+                API::Savings(Sapi::FlexibleSubscriptionRecord),
+                Some(request),
+            )
+            .await
+    }
+
+    /// Locked subscriptions, newest first.
+    pub async fn locked_subscription_history(
+        &self, request: EarnHistoryRequest,
+    ) -> Result<Records<LockedSubscriptionRecord>> {
+        let request = build_signed_request(build_earn_history(request), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::LockedSubscriptionRecord), Some(request))
+            .await
+    }
+
+    /// Flexible redemptions, newest first.
+    pub async fn flexible_redemption_history(
+        &self, request: EarnHistoryRequest,
+    ) -> Result<Records<FlexibleRedemptionRecord>> {
+        let request = build_signed_request(build_earn_history(request), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::FlexibleRedemptionRecord), Some(request))
+            .await
+    }
+
+    /// Locked redemptions, newest first.
+    pub async fn locked_redemption_history(
+        &self, request: EarnHistoryRequest,
+    ) -> Result<Records<LockedRedemptionRecord>> {
+        let request = build_signed_request(build_earn_history(request), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::LockedRedemptionRecord), Some(request))
+            .await
+    }
+
+    /// Flexible rewards of one type, newest first.
+    pub async fn flexible_rewards_history(
+        &self, reward_type: EarnRewardType, request: EarnHistoryRequest,
+    ) -> Result<Records<FlexibleRewardRecord>> {
+        let mut parameters = build_earn_history(request);
+        parameters.insert("type".into(), reward_type.to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::FlexibleRewardsRecord), Some(request))
+            .await
+    }
+
+    /// Locked rewards, newest first.
+    pub async fn locked_rewards_history(
+        &self, request: EarnHistoryRequest,
+    ) -> Result<Records<LockedRewardRecord>> {
+        let request = build_signed_request(build_earn_history(request), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::LockedRewardsRecord), Some(request))
+            .await
+    }
+}
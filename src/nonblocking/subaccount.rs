@@ -0,0 +1,125 @@
+use crate::util::build_signed_request;
+use crate::model::{
+    SubAccountBalance, SubAccountBalances, SubAccountFuturesAccount, SubAccountFuturesEnabled,
+    SubAccountInfo, SubAccountMarginAccount, SubAccountMarginEnabled, SubAccountTransferHistory,
+    SubAccountTransferResponse, SubAccounts,
+};
+use crate::nonblocking::client::Client;
+use crate::errors::Result;
+use crate::api::API;
+use crate::api::SubAccountSapi;
+use crate::subaccount::{build_email, build_list, build_transfer, build_transfer_history};
+pub use crate::subaccount::{
+    SubAccountFuturesType, SubAccountListRequest, SubAccountTransferHistoryRequest,
+    SubAccountTransferRequest, SubAccountType,
+};
+
+#[derive(Clone)]
+pub struct SubAccount {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl SubAccount {
+    /// Sub-accounts of the master account.
+    pub async fn list(&self, request: SubAccountListRequest) -> Result<Vec<SubAccountInfo>> {
+        let request = build_signed_request(build_list(request), self.recv_window)?;
+        let list: SubAccounts = self
+            .client
+            .get_signed(API::SubAccount(SubAccountSapi::List), Some(request))
+            .await?;
+        Ok(list.sub_accounts)
+    }
+
+    /// Spot balances of a sub-account.
+    pub async fn spot_balances<S>(&self, email: S) -> Result<Vec<SubAccountBalance>>
+    where
+        S: Into<String>,
+    {
+        let request = build_signed_request(build_email(email.into()), self.recv_window)?;
+        let assets: SubAccountBalances = self
+            .client
+            .get_signed(API::SubAccount(SubAccountSapi::SpotAssets), Some(request))
+            .await?;
+        Ok(assets.balances)
+    }
+
+    /// USD-M or COIN-M futures account of a sub-account.
+    pub async fn futures_account<S>(
+        &self, email: S, futures_type: SubAccountFuturesType,
+    ) -> Result<SubAccountFuturesAccount>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = build_email(email.into());
+        parameters.insert("futuresType".into(), (futures_type as u8).to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(
+                API::SubAccount(SubAccountSapi::FuturesAccount),
+                Some(request),
+            )
+            .await
+    }
+
+    /// Cross margin account of a sub-account.
+    pub async fn margin_account<S>(&self, email: S) -> Result<SubAccountMarginAccount>
+    where
+        S: Into<String>,
+    {
+        let request = build_signed_request(build_email(email.into()), self.recv_window)?;
+        self.client
+            .get_signed(
+                API::SubAccount(SubAccountSapi::MarginAccount),
+                Some(request),
+            )
+            .await
+    }
+
+    /// Move an asset between the master and a sub-account, or between two sub-accounts.
+    pub async fn transfer(
+        &self, request: SubAccountTransferRequest,
+    ) -> Result<SubAccountTransferResponse> {
+        request.validate()?;
+        let request = build_signed_request(build_transfer(request), self.recv_window)?;
+        self.client
+            .post_signed(API::SubAccount(SubAccountSapi::UniversalTransfer), request)
+            .await
+    }
+
+    /// Transfers made with `transfer`, newest first.
+    pub async fn transfer_history(
+        &self, request: SubAccountTransferHistoryRequest,
+    ) -> Result<SubAccountTransferHistory> {
+        request.validate()?;
+        let request = build_signed_request(build_transfer_history(request), self.recv_window)?;
+        self.client
+            .get_signed(
+                API::SubAccount(SubAccountSapi::UniversalTransfer),
+                Some(request),
+            )
+            .await
+    }
+
+    /// Open the futures account of a sub-account.
+    pub async fn enable_futures<S>(&self, email: S) -> Result<SubAccountFuturesEnabled>
+    where
+        S: Into<String>,
+    {
+        let request = build_signed_request(build_email(email.into()), self.recv_window)?;
+        self.client
+            .post_signed(API::SubAccount(SubAccountSapi::EnableFutures), request)
+            .await
+    }
+
+    /// Open the margin account of a sub-account.
+    pub async fn enable_margin<S>(&self, email: S) -> Result<SubAccountMarginEnabled>
+    where
+        S: Into<String>,
+    {
+        let request = build_signed_request(build_email(email.into()), self.recv_window)?;
+        self.client
+            .post_signed(API::SubAccount(SubAccountSapi::EnableMargin), request)
+            .await
+    }
+}
@@ -0,0 +1,528 @@
+//! Iterators walking through history endpoints one page at a time.
+//!
+//! Binance history endpoints return at most `limit` items per call, either
+//! from an ID onwards or inside a bounded time window. `Pages` keeps asking
+//! for the next page until the history is exhausted, or walks it backward
+//! from the most recent items. `WindowPages` walks histories without
+//! numeric IDs, such as deposits, by offset inside each time window, and
+//! `NumberedPages` walks lists numbered from page 1, such as Simple Earn
+//! products, or numbered inside each time window, such as Simple Earn
+//! histories:
+//!
+//!```no_run
+//! use binance::api::Binance;
+//! use binance::account::*;
+//!
+//! fn main() {
+//!     let account: Account = Binance::new(Some("api_key".into()), Some("secret_key".into()));
+//!     let request = TradeHistoryRequest::new("BNBBTC").set_start_time(1_640_995_200_000);
+//!     for trade in account.my_trades_iter(request) {
+//!         match trade {
+//!             Ok(trade) => println!("{} {} @ {}", trade.id, trade.qty, trade.price),
+//!             Err(e) => println!("Error: {}", e),
+//!         }
+//!     }
+//! }
+//! ```
+use std::collections::VecDeque;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::errors::Result;
+use crate::model::{Order, Records, Trade, TradeHistory};
+
+/// Longest time window accepted by most history endpoints.
+pub const DAY: u64 = 24 * 60 * 60 * 1000;
+
+/// An item of a paged history, ordered by ID and time.
+pub trait PageItem {
+    fn id(&self) -> u64;
+    fn time(&self) -> u64;
+}
+
+impl PageItem for Order {
+    fn id(&self) -> u64 {
+        self.order_id
+    }
+
+    fn time(&self) -> u64 {
+        self.time
+    }
+}
+
+impl PageItem for Trade {
+    fn id(&self) -> u64 {
+        self.id
+    }
+
+    fn time(&self) -> u64 {
+        self.time
+    }
+}
+
+impl PageItem for TradeHistory {
+    fn id(&self) -> u64 {
+        self.id
+    }
+
+    fn time(&self) -> u64 {
+        self.time
+    }
+}
+
+/// The page `Pages` asks for: from `from_id` onwards, or inside
+/// `start_time` - `end_time` (both inclusive).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageRequest {
+    pub from_id: Option<u64>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: u16,
+}
+
+/// The page `WindowPages` asks for: `limit` items from `offset` onwards
+/// inside `start_time` - `end_time` (both inclusive).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WindowRequest {
+    pub start_time: u64,
+    pub end_time: u64,
+    pub offset: u32,
+    pub limit: u16,
+}
+
+/// The page `NumberedPages` asks for: page `current`, counted from 1, of
+/// `size` items, inside `start_time` - `end_time` (both inclusive) if set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberedRequest {
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub current: u32,
+    pub size: u32,
+}
+
+type Fetch<'a, T> = Box<dyn FnMut(&PageRequest) -> Result<Vec<T>> + 'a>;
+type WindowFetch<'a, T> = Box<dyn FnMut(&WindowRequest) -> Result<Vec<T>> + 'a>;
+type NumberedFetch<'a, T> = Box<dyn FnMut(&NumberedRequest) -> Result<Records<T>> + 'a>;
+
+/// Iterator over every item of a history, fetching pages as it goes.
+///
+/// The iterator ends after the first error it yields.
+pub struct Pages<'a, T> {
+    fetch: Fetch<'a, T>,
+    next: Option<PageRequest>,
+    // Time window mode: window length and end of the whole range
+    window: Option<(u64, u64)>,
+    backward: bool,
+    end_time: Option<u64>,
+    last_id: Option<u64>,
+    buffer: VecDeque<T>,
+}
+
+impl<'a, T: PageItem> Pages<'a, T> {
+    /// Page by ID, from `from_id` onwards, stopping after `end_time` if set.
+    pub fn by_id<F>(fetch: F, from_id: u64, end_time: Option<u64>, limit: u16) -> Self
+    where
+        F: FnMut(&PageRequest) -> Result<Vec<T>> + 'a,
+    {
+        Pages {
+            fetch: Box::new(fetch),
+            next: Some(PageRequest {
+                from_id: Some(from_id),
+                start_time: None,
+                end_time: None,
+                limit,
+            }),
+            window: None,
+            backward: false,
+            end_time,
+            last_id: None,
+            buffer: VecDeque::new(),
+        }
+    }
+
+    /// Page by ID from newest to oldest, starting before `before_id` or
+    /// from the most recent items, down to ID 0.
+    ///
+    /// Stop early with e.g. `take_while` on the item time.
+    pub fn backward<F>(fetch: F, before_id: Option<u64>, limit: u16) -> Self
+    where
+        F: FnMut(&PageRequest) -> Result<Vec<T>> + 'a,
+    {
+        Pages {
+            fetch: Box::new(fetch),
+            next: Some(PageRequest {
+                from_id: before_id.map(|id| id.saturating_sub(u64::from(limit))),
+                start_time: None,
+                end_time: None,
+                limit,
+            }),
+            window: None,
+            backward: true,
+            end_time: None,
+            last_id: before_id,
+            buffer: VecDeque::new(),
+        }
+    }
+
+    /// Page by time, in windows of at most `window` milliseconds from
+    /// `start_time` to `end_time`, or to now.
+    ///
+    /// Once a full page shares a single timestamp, paging goes on by ID.
+    ///
+    /// # Panics
+    ///
+    /// If `window` is 0.
+    pub fn by_time<F>(
+        fetch: F, start_time: u64, end_time: Option<u64>, window: u64, limit: u16,
+    ) -> Self
+    where
+        F: FnMut(&PageRequest) -> Result<Vec<T>> + 'a,
+    {
+        assert!(window > 0, "time window must be at least 1 ms");
+        let end_time = end_time.unwrap_or_else(now);
+        Pages {
+            fetch: Box::new(fetch),
+            next: window_from(start_time, end_time, window, limit),
+            window: Some((window, end_time)),
+            backward: false,
+            end_time: Some(end_time),
+            last_id: None,
+            buffer: VecDeque::new(),
+        }
+    }
+
+    fn fetch_next(&mut self) -> Result<()> {
+        let request = match self.next.take() {
+            Some(request) => request,
+            None => return Ok(()),
+        };
+        let page = (self.fetch)(&request)?;
+        if self.backward {
+            self.push_backward(page, request);
+            return Ok(());
+        }
+        let full = page.len() >= usize::from(request.limit);
+        let last = page.last().map(|item| (item.id(), item.time()));
+        let mut past_end = false;
+
+        for item in page {
+            if self.last_id.map_or(false, |last_id| item.id() <= last_id) {
+                continue;
+            }
+            if self
+                .end_time
+                .map_or(false, |end_time| item.time() > end_time)
+            {
+                past_end = true;
+                continue;
+            }
+            self.last_id = Some(item.id());
+            self.buffer.push_back(item);
+        }
+
+        self.next = match (self.window, last) {
+            (None, Some((last_id, _))) if full && !past_end => Some(PageRequest {
+                from_id: Some(last_id + 1),
+                ..request
+            }),
+            (None, _) => None,
+            (Some(_), Some((last_id, last_time)))
+                if full && Some(last_time) == request.start_time =>
+            {
+                // A whole page shares one timestamp, the rest of it is only reachable by ID
+                self.window = None;
+                Some(PageRequest {
+                    from_id: Some(last_id + 1),
+                    start_time: None,
+                    end_time: None,
+                    limit: request.limit,
+                })
+            }
+            (Some((window, end_time)), Some((_, last_time))) if full => {
+                // Items sharing the last timestamp are skipped by ID on the next page
+                window_from(last_time, end_time, window, request.limit)
+            }
+            (Some((window, end_time)), _) => request.end_time.and_then(|window_end| {
+                window_from(window_end + 1, end_time, window, request.limit)
+            }),
+        };
+        Ok(())
+    }
+
+    fn push_backward(&mut self, page: Vec<T>, request: PageRequest) {
+        let first_id = page.first().map(PageItem::id);
+        for item in page.into_iter().rev() {
+            if self.last_id.map_or(false, |last_id| item.id() >= last_id) {
+                continue;
+            }
+            self.last_id = Some(item.id());
+            self.buffer.push_back(item);
+        }
+
+        // Pages overlap once fewer than `limit` IDs are left, the overlap is skipped by ID
+        self.next = match first_id {
+            Some(first_id) if first_id > 0 && self.last_id == Some(first_id) => Some(PageRequest {
+                from_id: Some(first_id.saturating_sub(u64::from(request.limit))),
+                ..request
+            }),
+            _ => None,
+        };
+    }
+}
+
+impl<'a, T: PageItem> Iterator for Pages<'a, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.buffer.pop_front() {
+                return Some(Ok(item));
+            }
+            self.next.as_ref()?;
+            if let Err(e) = self.fetch_next() {
+                self.next = None;
+                return Some(Err(e));
+            }
+        }
+    }
+}
+
+/// Iterator over a history sorted newest first, fetched by offset inside
+/// windows of at most `window` milliseconds, from the most recent window
+/// back to the oldest.
+///
+/// Items recorded while iterating may shift the offsets and be yielded
+/// twice. The iterator ends after the first error it yields.
+pub struct WindowPages<'a, T> {
+    fetch: WindowFetch<'a, T>,
+    next: Option<WindowRequest>,
+    start_time: u64,
+    window: u64,
+    buffer: VecDeque<T>,
+}
+
+impl<'a, T> WindowPages<'a, T> {
+    /// Page from `end_time`, or now, back to `start_time`, or back one
+    /// window if not set.
+    ///
+    /// # Panics
+    ///
+    /// If `window` is 0.
+    pub fn new<F>(
+        fetch: F, start_time: Option<u64>, end_time: Option<u64>, window: u64, limit: u16,
+    ) -> Self
+    where
+        F: FnMut(&WindowRequest) -> Result<Vec<T>> + 'a,
+    {
+        assert!(window > 0, "time window must be at least 1 ms");
+        let end_time = end_time.unwrap_or_else(now);
+        let start_time = start_time.unwrap_or_else(|| end_time.saturating_sub(window - 1));
+        WindowPages {
+            fetch: Box::new(fetch),
+            next: window_request(start_time, end_time, window, limit),
+            start_time,
+            window,
+            buffer: VecDeque::new(),
+        }
+    }
+
+    fn fetch_next(&mut self) -> Result<()> {
+        let request = match self.next.take() {
+            Some(request) => request,
+            None => return Ok(()),
+        };
+        let page = (self.fetch)(&request)?;
+        let full = page.len() >= usize::from(request.limit);
+        self.buffer.extend(page);
+
+        self.next = if full {
+            Some(WindowRequest {
+                offset: request.offset + u32::from(request.limit),
+                ..request
+            })
+        } else if request.start_time > self.start_time {
+            window_request(
+                self.start_time,
+                request.start_time - 1,
+                self.window,
+                request.limit,
+            )
+        } else {
+            None
+        };
+        Ok(())
+    }
+}
+
+impl<'a, T> Iterator for WindowPages<'a, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.buffer.pop_front() {
+                return Some(Ok(item));
+            }
+            self.next.as_ref()?;
+            if let Err(e) = self.fetch_next() {
+                self.next = None;
+                return Some(Err(e));
+            }
+        }
+    }
+}
+
+/// Iterator over a list numbered from page 1, up to the `total` Binance
+/// reports, in a single list or in each time window.
+///
+/// Items added while iterating may shift the pages and be yielded twice.
+/// The iterator ends after the first error it yields.
+pub struct NumberedPages<'a, T> {
+    fetch: NumberedFetch<'a, T>,
+    next: Option<NumberedRequest>,
+    // Time window mode: window length and start of the whole range
+    window: Option<(u64, u64)>,
+    fetched: u64,
+    buffer: VecDeque<T>,
+}
+
+impl<'a, T> NumberedPages<'a, T> {
+    /// Page from page 1, `size` items at a time.
+    pub fn new<F>(fetch: F, size: u32) -> Self
+    where
+        F: FnMut(&NumberedRequest) -> Result<Records<T>> + 'a,
+    {
+        NumberedPages {
+            fetch: Box::new(fetch),
+            next: Some(NumberedRequest {
+                start_time: None,
+                end_time: None,
+                current: 1,
+                size,
+            }),
+            window: None,
+            fetched: 0,
+            buffer: VecDeque::new(),
+        }
+    }
+
+    /// Page from page 1 inside windows of at most `window` milliseconds,
+    /// from `end_time`, or now, back to `start_time`, or back one window if
+    /// not set.
+    ///
+    /// # Panics
+    ///
+    /// If `window` is 0.
+    pub fn by_time<F>(
+        fetch: F, start_time: Option<u64>, end_time: Option<u64>, window: u64, size: u32,
+    ) -> Self
+    where
+        F: FnMut(&NumberedRequest) -> Result<Records<T>> + 'a,
+    {
+        assert!(window > 0, "time window must be at least 1 ms");
+        let end_time = end_time.unwrap_or_else(now);
+        let start_time = start_time.unwrap_or_else(|| end_time.saturating_sub(window - 1));
+        NumberedPages {
+            fetch: Box::new(fetch),
+            next: numbered_window(start_time, end_time, window, size),
+            window: Some((window, start_time)),
+            fetched: 0,
+            buffer: VecDeque::new(),
+        }
+    }
+
+    fn fetch_next(&mut self) -> Result<()> {
+        let request = match self.next.take() {
+            Some(request) => request,
+            None => return Ok(()),
+        };
+        let page = (self.fetch)(&request)?;
+        let full = page.rows.len() >= request.size as usize;
+        self.fetched += page.rows.len() as u64;
+        self.buffer.extend(page.rows);
+
+        self.next = if full && self.fetched < page.total {
+            Some(NumberedRequest {
+                current: request.current + 1,
+                ..request
+            })
+        } else {
+            // The window before, from page 1
+            self.fetched = 0;
+            match (self.window, request.start_time) {
+                (Some((window, start_time)), Some(window_start)) if window_start > start_time => {
+                    numbered_window(start_time, window_start - 1, window, request.size)
+                }
+                _ => None,
+            }
+        };
+        Ok(())
+    }
+}
+
+impl<'a, T> Iterator for NumberedPages<'a, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.buffer.pop_front() {
+                return Some(Ok(item));
+            }
+            self.next.as_ref()?;
+            if let Err(e) = self.fetch_next() {
+                self.next = None;
+                return Some(Err(e));
+            }
+        }
+    }
+}
+
+// The last window of at most `window` milliseconds inside `start_time` - `end_time`
+fn window_before(start_time: u64, end_time: u64, window: u64) -> Option<(u64, u64)> {
+    if start_time > end_time {
+        return None;
+    }
+    Some((
+        start_time.max(end_time.saturating_sub(window - 1)),
+        end_time,
+    ))
+}
+
+fn window_request(
+    start_time: u64, end_time: u64, window: u64, limit: u16,
+) -> Option<WindowRequest> {
+    window_before(start_time, end_time, window).map(|(start_time, end_time)| WindowRequest {
+        start_time,
+        end_time,
+        offset: 0,
+        limit,
+    })
+}
+
+fn numbered_window(
+    start_time: u64, end_time: u64, window: u64, size: u32,
+) -> Option<NumberedRequest> {
+    window_before(start_time, end_time, window).map(|(start_time, end_time)| NumberedRequest {
+        start_time: Some(start_time),
+        end_time: Some(end_time),
+        current: 1,
+        size,
+    })
+}
+
+fn window_from(start_time: u64, end_time: u64, window: u64, limit: u16) -> Option<PageRequest> {
+    if start_time > end_time {
+        return None;
+    }
+    Some(PageRequest {
+        from_id: None,
+        start_time: Some(start_time),
+        end_time: Some(end_time.min(start_time + window - 1)),
+        limit,
+    })
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|now| now.as_millis() as u64)
+        .unwrap_or(u64::MAX)
+}
@@ -0,0 +1,383 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use reqwest::header::HeaderMap;
+
+use crate::errors::{ErrorKind, Result};
+use crate::model::RateLimit;
+
+const USED_WEIGHT_HEADER: &str = "x-mbx-used-weight-";
+const ORDER_COUNT_HEADER: &str = "x-mbx-order-count-";
+// Binance bans last from 2 minutes up to 3 days and always send Retry-After
+const DEFAULT_BAN: Duration = Duration::from_secs(120);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RateLimitType {
+    RequestWeight,
+    Orders,
+}
+
+impl fmt::Display for RateLimitType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RequestWeight => write!(f, "REQUEST_WEIGHT"),
+            Self::Orders => write!(f, "ORDERS"),
+        }
+    }
+}
+
+/// What the client does when a request would exceed a configured budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitBehavior {
+    /// Sleep until the interval resets, then send the request.
+    Wait,
+    /// Return `ErrorKind::RateLimitExceeded` without sending the request.
+    FailFast,
+}
+
+/// Current usage of one rate limit interval, as reported by Binance and
+/// adjusted for the requests sent since the last response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitUsage {
+    pub rate_limit_type: RateLimitType,
+    pub interval: Duration,
+    pub used: u64,
+    pub limit: Option<u64>,
+}
+
+/// A request was not sent because it would have exceeded a budget.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BudgetExceeded {
+    pub rate_limit_type: RateLimitType,
+    pub interval: Duration,
+    pub used: u64,
+    pub limit: u64,
+    /// Weight or order count of the request that was not sent.
+    pub cost: u64,
+    /// Time left until the interval resets.
+    pub retry_after: Duration,
+}
+
+impl BudgetExceeded {
+    /// Whether the request fits in the budget once the interval resets.
+    pub fn fits_budget(&self) -> bool {
+        self.cost <= self.limit
+    }
+}
+
+impl fmt::Display for BudgetExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.fits_budget() {
+            return write!(
+                f,
+                "request costs {}, more than the {} budget of {} per {:?}",
+                self.cost, self.rate_limit_type, self.limit, self.interval
+            );
+        }
+        write!(
+            f,
+            "{} budget of {} per {:?} exhausted ({} used), retry in {:?}",
+            self.rate_limit_type, self.limit, self.interval, self.used, self.retry_after
+        )
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Counter {
+    window: u64,
+    used: u64,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    counters: BTreeMap<(RateLimitType, Duration), Counter>,
+    budgets: BTreeMap<(RateLimitType, Duration), u64>,
+    banned_until: Option<u64>,
+}
+
+/// Tracks the request weight and order counts reported in the `X-MBX-USED-WEIGHT-*`
+/// and `X-MBX-ORDER-COUNT-*` response headers and enforces optional budgets.
+///
+/// Binance counts per IP (weight) and per account (orders), so every client talking
+/// to the same API should share one limiter. `Config` holds one for the spot API and
+/// one for the futures API, and all modules built from that config share them.
+///
+/// ```no_run
+/// use binance::api::*;
+/// use binance::config::Config;
+/// use binance::general::General;
+/// use binance::ratelimit::{RateLimitBehavior, RateLimiter};
+///
+/// let general: General = Binance::new(None, None);
+/// let info = general.exchange_info().unwrap();
+/// let limiter = RateLimiter::from_rate_limits(&info.rate_limits, RateLimitBehavior::Wait);
+/// let config = Config::default().set_rate_limiter(limiter);
+/// ```
+#[derive(Debug)]
+pub struct RateLimiter {
+    behavior: RateLimitBehavior,
+    state: Mutex<State>,
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new(RateLimitBehavior::Wait)
+    }
+}
+
+impl RateLimiter {
+    /// A limiter that only tracks usage until budgets are added.
+    pub fn new(behavior: RateLimitBehavior) -> Self {
+        RateLimiter {
+            behavior,
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    /// Build budgets from the `rateLimits` of `exchangeInfo`.
+    ///
+    /// `RAW_REQUESTS` limits are not reported in headers and are ignored, as
+    /// are limits over an empty interval.
+    pub fn from_rate_limits(rate_limits: &[RateLimit], behavior: RateLimitBehavior) -> Self {
+        let mut limiter = Self::new(behavior);
+        for rate_limit in rate_limits {
+            let rate_limit_type = match rate_limit.rate_limit_type.as_str() {
+                "REQUEST_WEIGHT" => RateLimitType::RequestWeight,
+                "ORDERS" => RateLimitType::Orders,
+                _ => continue,
+            };
+            let unit = match rate_limit.interval.as_str() {
+                "SECOND" => 1,
+                "MINUTE" => 60,
+                "HOUR" => 3600,
+                "DAY" => 86400,
+                _ => continue,
+            };
+            if rate_limit.interval_num == 0 {
+                continue;
+            }
+            let interval = Duration::from_secs(unit * u64::from(rate_limit.interval_num));
+            limiter = limiter.set_budget(rate_limit_type, interval, rate_limit.limit);
+        }
+        limiter
+    }
+
+    /// Allow at most `limit` per `interval`, counted in whole milliseconds
+    /// and at least 1 ms.
+    pub fn set_budget(
+        self, rate_limit_type: RateLimitType, interval: Duration, limit: u64,
+    ) -> Self {
+        let interval = Duration::from_millis(as_millis(interval).max(1));
+        self.state
+            .lock()
+            .unwrap()
+            .budgets
+            .insert((rate_limit_type, interval), limit);
+        self
+    }
+
+    pub fn behavior(&self) -> RateLimitBehavior {
+        self.behavior
+    }
+
+    /// Usage of every interval seen in a response or configured as a budget.
+    pub fn usage(&self) -> Vec<RateLimitUsage> {
+        let now = now_millis();
+        let state = self.state.lock().unwrap();
+        let mut keys: Vec<_> = state.counters.keys().chain(state.budgets.keys()).collect();
+        keys.sort();
+        keys.dedup();
+        keys.into_iter()
+            .map(|key| RateLimitUsage {
+                rate_limit_type: key.0,
+                interval: key.1,
+                used: current(&state, key, now),
+                limit: state.budgets.get(key).copied(),
+            })
+            .collect()
+    }
+
+    /// Weight used in the current `interval`, if Binance reported it.
+    pub fn used_weight(&self, interval: Duration) -> Option<u64> {
+        self.used(RateLimitType::RequestWeight, interval)
+    }
+
+    /// Orders placed in the current `interval`, if Binance reported it.
+    pub fn order_count(&self, interval: Duration) -> Option<u64> {
+        self.used(RateLimitType::Orders, interval)
+    }
+
+    /// End of the current IP ban in milliseconds since epoch, after a 418 response.
+    pub fn banned_until(&self) -> Option<u64> {
+        let now = now_millis();
+        self.state
+            .lock()
+            .unwrap()
+            .banned_until
+            .filter(|until| *until > now)
+    }
+
+    fn used(&self, rate_limit_type: RateLimitType, interval: Duration) -> Option<u64> {
+        let state = self.state.lock().unwrap();
+        let key = (rate_limit_type, interval);
+        state
+            .counters
+            .get(&key)
+            .map(|_| current(&state, &key, now_millis()))
+    }
+
+    /// Reserve `weight` and `orders` against every budget, or report the first
+    /// budget that would be exceeded, flagging requests that never fit in it.
+    /// Nothing is reserved on failure.
+    pub(crate) fn reserve(
+        &self, weight: u64, orders: u64,
+    ) -> std::result::Result<(), BudgetExceeded> {
+        let now = now_millis();
+        let mut state = self.state.lock().unwrap();
+
+        for (key, limit) in &state.budgets {
+            let cost = cost(key.0, weight, orders);
+            let used = current(&state, key, now);
+            if cost > 0 && used + cost > *limit {
+                let interval = as_millis(key.1);
+                return Err(BudgetExceeded {
+                    rate_limit_type: key.0,
+                    interval: key.1,
+                    used,
+                    limit: *limit,
+                    cost,
+                    retry_after: Duration::from_millis(interval - now % interval),
+                });
+            }
+        }
+
+        let keys: Vec<_> = state
+            .counters
+            .keys()
+            .chain(state.budgets.keys())
+            .copied()
+            .collect();
+        for key in keys {
+            let window = now / as_millis(key.1);
+            let counter = state.counters.entry(key).or_default();
+            if counter.window != window {
+                *counter = Counter { window, used: 0 };
+            }
+            counter.used += cost(key.0, weight, orders);
+        }
+        Ok(())
+    }
+
+    /// Block the current thread until the request fits in every budget, or fail
+    /// when it costs more than a whole budget.
+    pub(crate) fn acquire(&self, weight: u64, orders: u64) -> Result<()> {
+        loop {
+            match self.reserve(weight, orders) {
+                Ok(()) => return Ok(()),
+                // Waiting does not help a request costing more than the whole budget
+                Err(exceeded)
+                    if self.behavior == RateLimitBehavior::Wait && exceeded.fits_budget() =>
+                {
+                    std::thread::sleep(exceeded.retry_after);
+                }
+                Err(exceeded) => return Err(ErrorKind::RateLimitExceeded(exceeded).into()),
+            }
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub(crate) async fn acquire_async(&self, weight: u64, orders: u64) -> Result<()> {
+        loop {
+            match self.reserve(weight, orders) {
+                Ok(()) => return Ok(()),
+                // Waiting does not help a request costing more than the whole budget
+                Err(exceeded)
+                    if self.behavior == RateLimitBehavior::Wait && exceeded.fits_budget() =>
+                {
+                    tokio::time::sleep(exceeded.retry_after).await;
+                }
+                Err(exceeded) => return Err(ErrorKind::RateLimitExceeded(exceeded).into()),
+            }
+        }
+    }
+
+    /// Refuse to send anything while the IP is banned.
+    pub(crate) fn check_ban(&self) -> Result<()> {
+        match self.banned_until() {
+            Some(until) => Err(ErrorKind::IpBanned(until).into()),
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) fn ban(&self, retry_after: Option<Duration>) -> u64 {
+        let until = now_millis() + as_millis(retry_after.unwrap_or(DEFAULT_BAN));
+        self.state.lock().unwrap().banned_until = Some(until);
+        until
+    }
+
+    /// Replace the local estimates with the usage reported by Binance.
+    pub(crate) fn update(&self, headers: &HeaderMap) {
+        let now = now_millis();
+        let mut state = self.state.lock().unwrap();
+        for (name, value) in headers {
+            let name = name.as_str();
+            let (rate_limit_type, interval) = if let Some(i) = name.strip_prefix(USED_WEIGHT_HEADER)
+            {
+                (RateLimitType::RequestWeight, i)
+            } else if let Some(i) = name.strip_prefix(ORDER_COUNT_HEADER) {
+                (RateLimitType::Orders, i)
+            } else {
+                continue;
+            };
+            let used = value.to_str().ok().and_then(|v| v.parse::<u64>().ok());
+            if let (Some(interval), Some(used)) = (parse_interval(interval), used) {
+                let window = now / as_millis(interval);
+                state
+                    .counters
+                    .insert((rate_limit_type, interval), Counter { window, used });
+            }
+        }
+    }
+}
+
+fn cost(rate_limit_type: RateLimitType, weight: u64, orders: u64) -> u64 {
+    match rate_limit_type {
+        RateLimitType::RequestWeight => weight,
+        RateLimitType::Orders => orders,
+    }
+}
+
+// Binance intervals are aligned to the clock, e.g. the minute window resets at :00
+fn current(state: &State, key: &(RateLimitType, Duration), now: u64) -> u64 {
+    match state.counters.get(key) {
+        Some(counter) if counter.window == now / as_millis(key.1) => counter.used,
+        _ => 0,
+    }
+}
+
+// Header suffixes look like `1m`, `10s` or `1d`
+fn parse_interval(interval: &str) -> Option<Duration> {
+    let (num, unit) = interval.split_at(interval.len().checked_sub(1)?);
+    let num: u64 = num.parse().ok()?;
+    let unit = match unit.to_ascii_lowercase().as_str() {
+        "s" => 1,
+        "m" => 60,
+        "h" => 3600,
+        "d" => 86400,
+        _ => return None,
+    };
+    Some(Duration::from_secs(num * unit)).filter(|d| !d.is_zero())
+}
+
+fn as_millis(duration: Duration) -> u64 {
+    duration.as_secs() * 1000 + u64::from(duration.subsec_millis())
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(as_millis)
+        .unwrap_or_default()
+}
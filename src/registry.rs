@@ -0,0 +1,315 @@
+//! Cached `exchangeInfo` with lookups by symbol, base and quote asset.
+//!
+//! `General::get_symbol_info` downloads the whole exchange information on
+//! every call. A `SymbolRegistry` downloads it once, can be shared between
+//! threads, and tells what changed when it is refreshed:
+//!
+//!```no_run
+//! use std::time::Duration;
+//! use binance::api::Binance;
+//! use binance::filters::SymbolFilters;
+//! use binance::general::General;
+//! use binance::registry::SymbolRegistry;
+//!
+//! fn main() {
+//!     let general: General = Binance::new(None, None);
+//!     let registry = SymbolRegistry::spot(general)
+//!         .unwrap()
+//!         .set_max_age(Duration::from_secs(3600));
+//!
+//!     let symbol = registry.get("ETHBTC").unwrap();
+//!     println!("{:?} {:?}", symbol.price_filter(), symbol.quantity_precision());
+//!
+//!     if let Some(diff) = registry.refresh_if_stale().unwrap() {
+//!         for status in diff.status_changed {
+//!             println!("{} is now {}", status.symbol, status.to);
+//!         }
+//!     }
+//! }
+//! ```
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use error_chain::bail;
+
+use crate::errors::Result;
+use crate::filters::SymbolFilters;
+use crate::futures::general::FuturesGeneral;
+use crate::general::General;
+use crate::model::Symbol;
+
+/// A symbol of spot or futures `exchangeInfo`.
+pub trait ExchangeSymbol: SymbolFilters + Send + Sync + 'static {
+    fn name(&self) -> &str;
+    fn base_asset(&self) -> &str;
+    fn quote_asset(&self) -> &str;
+}
+
+impl ExchangeSymbol for Symbol {
+    fn name(&self) -> &str {
+        &self.symbol
+    }
+
+    fn base_asset(&self) -> &str {
+        &self.base_asset
+    }
+
+    fn quote_asset(&self) -> &str {
+        &self.quote_asset
+    }
+}
+
+impl ExchangeSymbol for crate::futures::model::Symbol {
+    fn name(&self) -> &str {
+        &self.symbol
+    }
+
+    fn base_asset(&self) -> &str {
+        &self.base_asset
+    }
+
+    fn quote_asset(&self) -> &str {
+        &self.quote_asset
+    }
+}
+
+/// A symbol whose trading status changed, e.g. from TRADING to BREAK or HALT.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusChange {
+    pub symbol: String,
+    pub from: String,
+    pub to: String,
+}
+
+/// What changed between two versions of the exchange information.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RegistryDiff {
+    pub listed: Vec<String>,
+    pub delisted: Vec<String>,
+    pub status_changed: Vec<StatusChange>,
+    pub filters_changed: Vec<String>,
+}
+
+impl RegistryDiff {
+    pub fn is_empty(&self) -> bool {
+        self.listed.is_empty() &&
+            self.delisted.is_empty() &&
+            self.status_changed.is_empty() &&
+            self.filters_changed.is_empty()
+    }
+}
+
+type Source<S> = Box<dyn Fn() -> Result<Vec<S>> + Send + Sync>;
+
+struct Index<S> {
+    symbols: HashMap<String, Arc<S>>,
+    by_base: HashMap<String, Vec<Arc<S>>>,
+    by_quote: HashMap<String, Vec<Arc<S>>>,
+    updated: Instant,
+}
+
+impl<S: ExchangeSymbol> Index<S> {
+    fn new(symbols: Vec<S>) -> Self {
+        let mut symbols: Vec<Arc<S>> = symbols.into_iter().map(Arc::new).collect();
+        symbols.sort_by(|a, b| a.name().cmp(b.name()));
+
+        let mut by_base: HashMap<String, Vec<Arc<S>>> = HashMap::new();
+        let mut by_quote: HashMap<String, Vec<Arc<S>>> = HashMap::new();
+        for symbol in &symbols {
+            by_base
+                .entry(symbol.base_asset().to_string())
+                .or_default()
+                .push(symbol.clone());
+            by_quote
+                .entry(symbol.quote_asset().to_string())
+                .or_default()
+                .push(symbol.clone());
+        }
+
+        Index {
+            symbols: symbols
+                .into_iter()
+                .map(|symbol| (symbol.name().to_string(), symbol))
+                .collect(),
+            by_base,
+            by_quote,
+            updated: Instant::now(),
+        }
+    }
+
+    fn diff(&self, next: &Index<S>) -> RegistryDiff {
+        let mut diff = RegistryDiff::default();
+        for (name, symbol) in &next.symbols {
+            match self.symbols.get(name) {
+                None => diff.listed.push(name.clone()),
+                Some(previous) => {
+                    if previous.status() != symbol.status() {
+                        diff.status_changed.push(StatusChange {
+                            symbol: name.clone(),
+                            from: previous.status().to_string(),
+                            to: symbol.status().to_string(),
+                        });
+                    }
+                    if previous.filters() != symbol.filters() {
+                        diff.filters_changed.push(name.clone());
+                    }
+                }
+            }
+        }
+        diff.delisted = self
+            .symbols
+            .keys()
+            .filter(|name| !next.symbols.contains_key(*name))
+            .cloned()
+            .collect();
+
+        diff.listed.sort();
+        diff.delisted.sort();
+        diff.status_changed.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+        diff.filters_changed.sort();
+        diff
+    }
+}
+
+/// Thread-safe cache of exchange symbols.
+pub struct SymbolRegistry<S> {
+    index: RwLock<Index<S>>,
+    source: Option<Source<S>>,
+    max_age: Option<Duration>,
+}
+
+impl<S> fmt::Debug for SymbolRegistry<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let index = self.index.read().unwrap();
+        f.debug_struct("SymbolRegistry")
+            .field("symbols", &index.symbols.len())
+            .field("updated", &index.updated)
+            .field("max_age", &self.max_age)
+            .finish()
+    }
+}
+
+impl SymbolRegistry<Symbol> {
+    /// Spot symbols, downloaded now and on every refresh.
+    pub fn spot(general: General) -> Result<Self> {
+        Self::with_source(move || Ok(general.exchange_info()?.symbols))
+    }
+}
+
+impl SymbolRegistry<crate::futures::model::Symbol> {
+    /// USD-M futures symbols, downloaded now and on every refresh.
+    pub fn futures(general: FuturesGeneral) -> Result<Self> {
+        Self::with_source(move || Ok(general.exchange_info()?.symbols))
+    }
+}
+
+impl<S: ExchangeSymbol> SymbolRegistry<S> {
+    /// A registry of the given symbols, updated only through `update`.
+    pub fn new(symbols: Vec<S>) -> Self {
+        SymbolRegistry {
+            index: RwLock::new(Index::new(symbols)),
+            source: None,
+            max_age: None,
+        }
+    }
+
+    /// A registry loading its symbols from `source`, now and on every refresh.
+    pub fn with_source<F>(source: F) -> Result<Self>
+    where
+        F: Fn() -> Result<Vec<S>> + Send + Sync + 'static,
+    {
+        let symbols = source()?;
+        Ok(SymbolRegistry {
+            index: RwLock::new(Index::new(symbols)),
+            source: Some(Box::new(source)),
+            max_age: None,
+        })
+    }
+
+    /// How long the symbols stay fresh, see `refresh_if_stale`.
+    pub fn set_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    pub fn get(&self, symbol: &str) -> Option<Arc<S>> {
+        let index = self.index.read().unwrap();
+        match index.symbols.get(symbol) {
+            Some(found) => Some(found.clone()),
+            None => index.symbols.get(&symbol.to_uppercase()).cloned(),
+        }
+    }
+
+    pub fn contains(&self, symbol: &str) -> bool {
+        self.get(symbol).is_some()
+    }
+
+    /// Symbols trading `asset` against any quote asset, sorted by name.
+    pub fn by_base(&self, asset: &str) -> Vec<Arc<S>> {
+        let index = self.index.read().unwrap();
+        index.by_base.get(asset).cloned().unwrap_or_default()
+    }
+
+    /// Symbols quoted in `asset`, sorted by name.
+    pub fn by_quote(&self, asset: &str) -> Vec<Arc<S>> {
+        let index = self.index.read().unwrap();
+        index.by_quote.get(asset).cloned().unwrap_or_default()
+    }
+
+    /// All symbols, sorted by name.
+    pub fn symbols(&self) -> Vec<Arc<S>> {
+        let index = self.index.read().unwrap();
+        let mut symbols: Vec<Arc<S>> = index.symbols.values().cloned().collect();
+        symbols.sort_by(|a, b| a.name().cmp(b.name()));
+        symbols
+    }
+
+    pub fn len(&self) -> usize {
+        self.index.read().unwrap().symbols.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Time since the symbols were last loaded.
+    pub fn age(&self) -> Duration {
+        self.index.read().unwrap().updated.elapsed()
+    }
+
+    pub fn is_stale(&self) -> bool {
+        match self.max_age {
+            Some(max_age) => self.age() >= max_age,
+            None => false,
+        }
+    }
+
+    /// Replace the symbols, e.g. with exchange information fetched by the
+    /// async client, and return what changed.
+    pub fn update(&self, symbols: Vec<S>) -> RegistryDiff {
+        let next = Index::new(symbols);
+        let mut index = self.index.write().unwrap();
+        let diff = index.diff(&next);
+        *index = next;
+        diff
+    }
+
+    /// Load the symbols from the source again.
+    pub fn refresh(&self) -> Result<RegistryDiff> {
+        match &self.source {
+            Some(source) => Ok(self.update(source()?)),
+            None => bail!("Registry has no source to refresh from"),
+        }
+    }
+
+    /// Refresh if the symbols are older than the max age.
+    pub fn refresh_if_stale(&self) -> Result<Option<RegistryDiff>> {
+        if self.is_stale() {
+            self.refresh().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+}
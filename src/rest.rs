@@ -0,0 +1,93 @@
+use crate::util::{build_request, build_signed_request};
+use crate::client::Client;
+use crate::errors::Result;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+
+/// Requests to any path on the spot and SAPI host, for endpoints without a wrapper.
+///
+/// Signed requests get `recvWindow`, `timestamp` and `signature` like every other
+/// call, and rate limits, retries and errors are handled the same way. Ask for a
+/// `serde_json::Value` to get the response as it is.
+///
+/// ```no_run
+/// use binance::api::*;
+/// use binance::rest::Rest;
+/// use std::collections::BTreeMap;
+///
+/// let rest: Rest = Binance::new(Some("api_key".into()), Some("secret_key".into()));
+/// let mut parameters = BTreeMap::new();
+/// parameters.insert("asset".into(), "BNB".into());
+/// let status: serde_json::Value = rest
+///     .get_signed("/sapi/v1/asset/assetDividend", parameters)
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct Rest {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl Rest {
+    pub fn get<T: DeserializeOwned>(
+        &self, path: &str, parameters: BTreeMap<String, String>,
+    ) -> Result<T> {
+        self.request(Method::GET, path, parameters, false)
+    }
+
+    pub fn get_signed<T: DeserializeOwned>(
+        &self, path: &str, parameters: BTreeMap<String, String>,
+    ) -> Result<T> {
+        self.request(Method::GET, path, parameters, true)
+    }
+
+    pub fn post<T: DeserializeOwned>(
+        &self, path: &str, parameters: BTreeMap<String, String>,
+    ) -> Result<T> {
+        self.request(Method::POST, path, parameters, false)
+    }
+
+    pub fn post_signed<T: DeserializeOwned>(
+        &self, path: &str, parameters: BTreeMap<String, String>,
+    ) -> Result<T> {
+        self.request(Method::POST, path, parameters, true)
+    }
+
+    pub fn put<T: DeserializeOwned>(
+        &self, path: &str, parameters: BTreeMap<String, String>,
+    ) -> Result<T> {
+        self.request(Method::PUT, path, parameters, false)
+    }
+
+    pub fn put_signed<T: DeserializeOwned>(
+        &self, path: &str, parameters: BTreeMap<String, String>,
+    ) -> Result<T> {
+        self.request(Method::PUT, path, parameters, true)
+    }
+
+    pub fn delete<T: DeserializeOwned>(
+        &self, path: &str, parameters: BTreeMap<String, String>,
+    ) -> Result<T> {
+        self.request(Method::DELETE, path, parameters, false)
+    }
+
+    pub fn delete_signed<T: DeserializeOwned>(
+        &self, path: &str, parameters: BTreeMap<String, String>,
+    ) -> Result<T> {
+        self.request(Method::DELETE, path, parameters, true)
+    }
+
+    pub fn request<T: DeserializeOwned>(
+        &self, method: Method, path: &str, parameters: BTreeMap<String, String>, signed: bool,
+    ) -> Result<T> {
+        let request = if signed {
+            Some(build_signed_request(parameters, self.recv_window)?)
+        } else if parameters.is_empty() {
+            None
+        } else {
+            Some(build_request(parameters))
+        };
+        self.client.request(method, path, request, signed)
+    }
+}
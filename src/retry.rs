@@ -0,0 +1,131 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use reqwest::StatusCode;
+
+use crate::errors::{Error, ErrorKind};
+
+/// When and how often a failed REST request is sent again.
+///
+/// The default policy never retries. Signed POSTs (orders, transfers, ...) are not
+/// idempotent: a 503 from Binance means the execution status is unknown, so they are
+/// only retried after `set_retry_non_idempotent(true)`.
+///
+/// ```
+/// use binance::config::Config;
+/// use binance::retry::RetryPolicy;
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::new(3)
+///     .set_backoff(Duration::from_millis(200), Duration::from_secs(5))
+///     .set_retryable_statuses(&[429, 500, 503]);
+/// let config = Config::default().set_retry_policy(policy);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    retryable_statuses: Vec<u16>,
+    retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 1,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            retryable_statuses: vec![429, 500, 502, 503, 504],
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// `max_attempts` counts the first request, so `1` disables retries.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            ..Self::default()
+        }
+    }
+
+    /// Delay before the first retry, doubled on every attempt up to `max_delay`.
+    pub fn set_backoff(mut self, base_delay: Duration, max_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self.max_delay = max_delay;
+        self
+    }
+
+    pub fn set_retryable_statuses(mut self, statuses: &[u16]) -> Self {
+        self.retryable_statuses = statuses.to_vec();
+        self
+    }
+
+    pub fn set_retry_non_idempotent(mut self, retry_non_idempotent: bool) -> Self {
+        self.retry_non_idempotent = retry_non_idempotent;
+        self
+    }
+
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Exponential backoff with jitter for the given retry (starting at 1), in
+    /// `[delay / 2, delay]`.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+        let delay = self
+            .base_delay
+            .checked_mul(factor)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+        let half = delay / 2;
+        let jitter = RandomState::new().build_hasher().finish() % (half.as_millis() as u64 + 1);
+        half + Duration::from_millis(jitter)
+    }
+
+    // Delay before sending attempt `attempt + 1` after `status`, if it should be sent
+    pub(crate) fn retry_status(
+        &self, status: StatusCode, retry_after: Option<Duration>, attempt: u32, idempotent: bool,
+    ) -> Option<Duration> {
+        if !self.retryable_statuses.contains(&status.as_u16()) {
+            return None;
+        }
+        self.retry(retry_after, attempt, idempotent)
+    }
+
+    // Connection failures never reached Binance and are safe to send again. Errors
+    // from other transports are only retried when they wrap a reqwest error
+    pub(crate) fn retry_error(
+        &self, error: &Error, attempt: u32, idempotent: bool,
+    ) -> Option<Duration> {
+        let error = match error.kind() {
+            ErrorKind::ReqError(error) => error,
+            _ => return None,
+        };
+        if error.is_connect() {
+            self.retry(None, attempt, true)
+        } else if error.is_timeout() {
+            self.retry(None, attempt, idempotent)
+        } else {
+            None
+        }
+    }
+
+    fn retry(
+        &self, retry_after: Option<Duration>, attempt: u32, idempotent: bool,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts || !(idempotent || self.retry_non_idempotent) {
+            return None;
+        }
+        match retry_after {
+            // Waiting longer than we are willing to back off is left to the caller
+            Some(retry_after) if retry_after > self.max_delay => None,
+            Some(retry_after) => Some(retry_after),
+            None => Some(self.backoff(attempt)),
+        }
+    }
+}
@@ -1,17 +1,602 @@
+use error_chain::bail;
+
 use crate::util::build_signed_request;
-use crate::model::{AssetDetail, CoinInfo, DepositAddress, SpotFuturesTransferType, TransactionId};
+use crate::pagination::{NumberedPages, NumberedRequest, WindowPages, WindowRequest, DAY};
+pub use crate::model::{
+    DepositStatus, EarnAccount, EarnRewardType, LockedRedeemTo, SpotFuturesTransferType,
+    TransferStatus, UniversalTransferType, WalletType, WithdrawStatus,
+};
+use crate::model::{
+    AssetDetail, CoinInfo, DepositAddress, DepositRecord, EarnPurchase, EarnRedemption,
+    FlexiblePosition, FlexibleProduct, FlexibleRedemptionRecord, FlexibleRewardRecord,
+    FlexibleSubscriptionRecord, LockedPosition, LockedProduct, LockedRedemptionRecord,
+    LockedRewardRecord, LockedSubscriptionRecord, Number, Records, TransactionId,
+    UniversalTransferRecord, WithdrawRecord, WithdrawResponse,
+};
 use crate::client::Client;
 use crate::errors::Result;
 use std::collections::BTreeMap;
 use crate::api::API;
 use crate::api::Sapi;
 
+/// Longest time window of the deposit and withdraw histories.
+pub const WALLET_HISTORY_WINDOW: u64 = 90 * DAY;
+
+/// Longest time window of the withdraw history of one `withdraw_order_id`.
+pub const WITHDRAW_ORDER_ID_WINDOW: u64 = 7 * DAY;
+
+/// Longest time window of the Simple Earn subscription, redemption and rewards histories.
+pub const EARN_HISTORY_WINDOW: u64 = 90 * DAY;
+
 #[derive(Clone)]
 pub struct Savings {
     pub client: Client,
     pub recv_window: u64,
 }
 
+/// Parameters of `POST /sapi/v1/capital/withdraw/apply`.
+#[derive(Clone, Debug)]
+pub struct WithdrawRequest {
+    pub coin: String,
+    pub address: String,
+    pub amount: Number,
+    pub network: Option<String>,
+    pub address_tag: Option<String>,
+    pub withdraw_order_id: Option<String>,
+    pub transaction_fee_flag: Option<bool>,
+    pub name: Option<String>,
+    pub wallet_type: Option<WalletType>,
+}
+
+impl WithdrawRequest {
+    pub fn new<S1, S2, F>(coin: S1, address: S2, amount: F) -> Self
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        F: Into<Number>,
+    {
+        Self {
+            coin: coin.into(),
+            address: address.into(),
+            amount: amount.into(),
+            network: None,
+            address_tag: None,
+            withdraw_order_id: None,
+            transaction_fee_flag: None,
+            name: None,
+            wallet_type: None,
+        }
+    }
+
+    /// Network to withdraw on, the coin's default network if not set.
+    pub fn set_network<S: Into<String>>(mut self, network: S) -> Self {
+        self.network = Some(network.into());
+        self
+    }
+
+    /// Secondary address identifier, such as a memo.
+    pub fn set_address_tag<S: Into<String>>(mut self, address_tag: S) -> Self {
+        self.address_tag = Some(address_tag.into());
+        self
+    }
+
+    /// Client ID of the withdrawal, to find it in the withdraw history.
+    pub fn set_withdraw_order_id<S: Into<String>>(mut self, withdraw_order_id: S) -> Self {
+        self.withdraw_order_id = Some(withdraw_order_id.into());
+        self
+    }
+
+    /// For internal transfers, whether the fee is paid by the destination account.
+    pub fn set_transaction_fee_flag(mut self, transaction_fee_flag: bool) -> Self {
+        self.transaction_fee_flag = Some(transaction_fee_flag);
+        self
+    }
+
+    /// Description of the address in the address book.
+    pub fn set_name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn set_wallet_type(mut self, wallet_type: WalletType) -> Self {
+        self.wallet_type = Some(wallet_type);
+        self
+    }
+}
+
+/// Parameters of `POST /sapi/v1/asset/transfer`.
+///
+/// Transfers from or to an isolated margin account need its symbol.
+#[derive(Clone, Debug)]
+pub struct UniversalTransferRequest {
+    pub transfer_type: UniversalTransferType,
+    pub asset: String,
+    pub amount: Number,
+    pub from_symbol: Option<String>,
+    pub to_symbol: Option<String>,
+}
+
+impl UniversalTransferRequest {
+    pub fn new<T, S, F>(transfer_type: T, asset: S, amount: F) -> Self
+    where
+        T: Into<UniversalTransferType>,
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        Self {
+            transfer_type: transfer_type.into(),
+            asset: asset.into(),
+            amount: amount.into(),
+            from_symbol: None,
+            to_symbol: None,
+        }
+    }
+
+    /// Isolated margin account the asset leaves.
+    pub fn set_from_symbol<S: Into<String>>(mut self, from_symbol: S) -> Self {
+        self.from_symbol = Some(from_symbol.into());
+        self
+    }
+
+    /// Isolated margin account the asset goes to.
+    pub fn set_to_symbol<S: Into<String>>(mut self, to_symbol: S) -> Self {
+        self.to_symbol = Some(to_symbol.into());
+        self
+    }
+
+    /// Check the isolated margin symbols, without contacting the exchange.
+    pub fn validate(&self) -> Result<()> {
+        if self.transfer_type.from_isolated() != self.from_symbol.is_some() {
+            bail!(
+                "{} transfers need a from symbol only when leaving an isolated margin account",
+                self.transfer_type
+            );
+        }
+        if self.transfer_type.to_isolated() != self.to_symbol.is_some() {
+            bail!(
+                "{} transfers need a to symbol only when going to an isolated margin account",
+                self.transfer_type
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Parameters of `GET /sapi/v1/asset/transfer`.
+///
+/// Without a time window, Binance returns the last 7 days. Pages start at 1
+/// and hold up to 100 records, 10 by default.
+#[derive(Clone, Debug)]
+pub struct UniversalTransferHistoryRequest {
+    pub transfer_type: UniversalTransferType,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub current: Option<u32>,
+    pub size: Option<u32>,
+    pub from_symbol: Option<String>,
+    pub to_symbol: Option<String>,
+}
+
+impl UniversalTransferHistoryRequest {
+    pub fn new<T: Into<UniversalTransferType>>(transfer_type: T) -> Self {
+        Self {
+            transfer_type: transfer_type.into(),
+            start_time: None,
+            end_time: None,
+            current: None,
+            size: None,
+            from_symbol: None,
+            to_symbol: None,
+        }
+    }
+
+    pub fn set_start_time(mut self, start_time: u64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn set_end_time(mut self, end_time: u64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn set_page(mut self, current: u32, size: u32) -> Self {
+        self.current = Some(current);
+        self.size = Some(size);
+        self
+    }
+
+    pub fn set_from_symbol<S: Into<String>>(mut self, from_symbol: S) -> Self {
+        self.from_symbol = Some(from_symbol.into());
+        self
+    }
+
+    pub fn set_to_symbol<S: Into<String>>(mut self, to_symbol: S) -> Self {
+        self.to_symbol = Some(to_symbol.into());
+        self
+    }
+}
+
+/// Parameters of `GET /sapi/v1/capital/deposit/hisrec`.
+///
+/// Without a time window, Binance returns the last 90 days.
+#[derive(Clone, Debug, Default)]
+pub struct DepositHistoryRequest {
+    pub coin: Option<String>,
+    pub status: Option<DepositStatus>,
+    pub tx_id: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub offset: Option<u32>,
+    pub limit: Option<u16>,
+}
+
+impl DepositHistoryRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_coin<S: Into<String>>(mut self, coin: S) -> Self {
+        self.coin = Some(coin.into());
+        self
+    }
+
+    pub fn set_status(mut self, status: DepositStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn set_tx_id<S: Into<String>>(mut self, tx_id: S) -> Self {
+        self.tx_id = Some(tx_id.into());
+        self
+    }
+
+    pub fn set_start_time(mut self, start_time: u64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn set_end_time(mut self, end_time: u64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn set_offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Deposits per request, up to 1000.
+    pub fn set_limit(mut self, limit: u16) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// Parameters of `GET /sapi/v1/capital/withdraw/history`.
+///
+/// Without a time window, Binance returns the last 90 days.
+#[derive(Clone, Debug, Default)]
+pub struct WithdrawHistoryRequest {
+    pub coin: Option<String>,
+    pub withdraw_order_id: Option<String>,
+    pub status: Option<WithdrawStatus>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub offset: Option<u32>,
+    pub limit: Option<u16>,
+}
+
+impl WithdrawHistoryRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_coin<S: Into<String>>(mut self, coin: S) -> Self {
+        self.coin = Some(coin.into());
+        self
+    }
+
+    /// Only the withdrawal submitted with this client ID.
+    pub fn set_withdraw_order_id<S: Into<String>>(mut self, withdraw_order_id: S) -> Self {
+        self.withdraw_order_id = Some(withdraw_order_id.into());
+        self
+    }
+
+    pub fn set_status(mut self, status: WithdrawStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn set_start_time(mut self, start_time: u64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn set_end_time(mut self, end_time: u64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn set_offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Withdrawals per request, up to 1000.
+    pub fn set_limit(mut self, limit: u16) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// Parameters of `GET /sapi/v1/simple-earn/flexible/list` and
+/// `GET /sapi/v1/simple-earn/locked/list`.
+///
+/// Pages start at 1 and hold up to 100 products, 10 by default.
+#[derive(Clone, Debug, Default)]
+pub struct EarnProductRequest {
+    pub asset: Option<String>,
+    pub current: Option<u32>,
+    pub size: Option<u32>,
+}
+
+impl EarnProductRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_asset<S: Into<String>>(mut self, asset: S) -> Self {
+        self.asset = Some(asset.into());
+        self
+    }
+
+    pub fn set_page(mut self, current: u32, size: u32) -> Self {
+        self.current = Some(current);
+        self.size = Some(size);
+        self
+    }
+}
+
+/// Parameters of `GET /sapi/v1/simple-earn/flexible/position`.
+#[derive(Clone, Debug, Default)]
+pub struct FlexiblePositionRequest {
+    pub asset: Option<String>,
+    pub product_id: Option<String>,
+    pub current: Option<u32>,
+    pub size: Option<u32>,
+}
+
+impl FlexiblePositionRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_asset<S: Into<String>>(mut self, asset: S) -> Self {
+        self.asset = Some(asset.into());
+        self
+    }
+
+    pub fn set_product_id<S: Into<String>>(mut self, product_id: S) -> Self {
+        self.product_id = Some(product_id.into());
+        self
+    }
+
+    pub fn set_page(mut self, current: u32, size: u32) -> Self {
+        self.current = Some(current);
+        self.size = Some(size);
+        self
+    }
+}
+
+/// Parameters of `GET /sapi/v1/simple-earn/locked/position`.
+#[derive(Clone, Debug, Default)]
+pub struct LockedPositionRequest {
+    pub asset: Option<String>,
+    pub position_id: Option<u64>,
+    pub project_id: Option<String>,
+    pub current: Option<u32>,
+    pub size: Option<u32>,
+}
+
+impl LockedPositionRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_asset<S: Into<String>>(mut self, asset: S) -> Self {
+        self.asset = Some(asset.into());
+        self
+    }
+
+    pub fn set_position_id(mut self, position_id: u64) -> Self {
+        self.position_id = Some(position_id);
+        self
+    }
+
+    pub fn set_project_id<S: Into<String>>(mut self, project_id: S) -> Self {
+        self.project_id = Some(project_id.into());
+        self
+    }
+
+    pub fn set_page(mut self, current: u32, size: u32) -> Self {
+        self.current = Some(current);
+        self.size = Some(size);
+        self
+    }
+}
+
+/// Parameters of `POST /sapi/v1/simple-earn/flexible/subscribe`.
+#[derive(Clone, Debug)]
+pub struct FlexibleSubscribeRequest {
+    pub product_id: String,
+    pub amount: Number,
+    pub auto_subscribe: Option<bool>,
+    pub source_account: Option<EarnAccount>,
+}
+
+impl FlexibleSubscribeRequest {
+    pub fn new<S, F>(product_id: S, amount: F) -> Self
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        Self {
+            product_id: product_id.into(),
+            amount: amount.into(),
+            auto_subscribe: None,
+            source_account: None,
+        }
+    }
+
+    /// Whether idle spot balances of the asset are subscribed daily, true by default.
+    pub fn set_auto_subscribe(mut self, auto_subscribe: bool) -> Self {
+        self.auto_subscribe = Some(auto_subscribe);
+        self
+    }
+
+    /// Wallet paying for the subscription, the spot wallet by default.
+    pub fn set_source_account(mut self, source_account: EarnAccount) -> Self {
+        self.source_account = Some(source_account);
+        self
+    }
+}
+
+/// Parameters of `POST /sapi/v1/simple-earn/locked/subscribe`.
+#[derive(Clone, Debug)]
+pub struct LockedSubscribeRequest {
+    pub project_id: String,
+    pub amount: Number,
+    pub auto_subscribe: Option<bool>,
+    pub source_account: Option<EarnAccount>,
+    pub redeem_to: Option<LockedRedeemTo>,
+}
+
+impl LockedSubscribeRequest {
+    pub fn new<S, F>(project_id: S, amount: F) -> Self
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        Self {
+            project_id: project_id.into(),
+            amount: amount.into(),
+            auto_subscribe: None,
+            source_account: None,
+            redeem_to: None,
+        }
+    }
+
+    /// Whether the position is locked again at the end of its duration, true by default.
+    pub fn set_auto_subscribe(mut self, auto_subscribe: bool) -> Self {
+        self.auto_subscribe = Some(auto_subscribe);
+        self
+    }
+
+    /// Wallet paying for the subscription, the spot wallet by default.
+    pub fn set_source_account(mut self, source_account: EarnAccount) -> Self {
+        self.source_account = Some(source_account);
+        self
+    }
+
+    /// Where the position is paid out when not subscribed again, the spot wallet by default.
+    pub fn set_redeem_to(mut self, redeem_to: LockedRedeemTo) -> Self {
+        self.redeem_to = Some(redeem_to);
+        self
+    }
+}
+
+/// Parameters of `POST /sapi/v1/simple-earn/flexible/redeem`.
+#[derive(Clone, Debug)]
+pub struct FlexibleRedeemRequest {
+    pub product_id: String,
+    pub amount: Option<Number>,
+    pub redeem_all: bool,
+    pub dest_account: Option<EarnAccount>,
+}
+
+impl FlexibleRedeemRequest {
+    pub fn new<S, F>(product_id: S, amount: F) -> Self
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        Self {
+            product_id: product_id.into(),
+            amount: Some(amount.into()),
+            redeem_all: false,
+            dest_account: None,
+        }
+    }
+
+    /// Redeem the whole position.
+    pub fn all<S: Into<String>>(product_id: S) -> Self {
+        Self {
+            product_id: product_id.into(),
+            amount: None,
+            redeem_all: true,
+            dest_account: None,
+        }
+    }
+
+    /// Wallet receiving the redemption, the spot wallet by default.
+    pub fn set_dest_account(mut self, dest_account: EarnAccount) -> Self {
+        self.dest_account = Some(dest_account);
+        self
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.redeem_all == self.amount.is_some() {
+            bail!("A flexible redemption needs either an amount or redeem_all");
+        }
+        if self.dest_account == Some(EarnAccount::All) {
+            bail!("A flexible redemption goes to either the spot or the funding wallet");
+        }
+        Ok(())
+    }
+}
+
+/// Parameters of the Simple Earn subscription, redemption and rewards
+/// histories, such as `GET /sapi/v1/simple-earn/flexible/history/rewardsRecord`.
+///
+/// Windows span at most 90 days. Pages start at 1 and hold up to 100
+/// records, 10 by default.
+#[derive(Clone, Debug, Default)]
+pub struct EarnHistoryRequest {
+    pub asset: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub current: Option<u32>,
+    pub size: Option<u32>,
+}
+
+impl EarnHistoryRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_asset<S: Into<String>>(mut self, asset: S) -> Self {
+        self.asset = Some(asset.into());
+        self
+    }
+
+    pub fn set_start_time(mut self, start_time: u64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn set_end_time(mut self, end_time: u64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn set_page(mut self, current: u32, size: u32) -> Self {
+        self.current = Some(current);
+        self.size = Some(size);
+        self
+    }
+}
+
 impl Savings {
     /// Get all coins available for deposit and withdrawal
     pub fn get_all_coins(&self) -> Result<Vec<CoinInfo>> {
@@ -49,8 +634,13 @@ impl Savings {
             .get_signed(API::Savings(Sapi::DepositAddress), Some(request))
     }
 
+    /// Move an asset between the spot and the futures wallets.
+    ///
+    /// The same transfer is `universal_transfer` with `UniversalTransferType::from(transfer_type)`,
+    /// which needs the "Permits Universal Transfer" API key permission.
+    #[deprecated(note = "Binance deprecated /sapi/v1/futures/transfer, use universal_transfer")]
     pub fn transfer_funds<S>(
-        &self, asset: S, amount: f64, transfer_type: SpotFuturesTransferType,
+        &self, asset: S, amount: Number, transfer_type: SpotFuturesTransferType,
     ) -> Result<TransactionId>
     where
         S: Into<String>,
@@ -63,4 +653,618 @@ impl Savings {
         self.client
             .post_signed(API::Savings(Sapi::SpotFuturesTransfer), request)
     }
+
+    /// Move an asset between any two wallets.
+    pub fn universal_transfer(&self, request: UniversalTransferRequest) -> Result<TransactionId> {
+        request.validate()?;
+        let request = build_signed_request(build_universal_transfer(request), self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::UniversalTransfer), request)
+    }
+
+    /// Universal transfers of one type, newest first.
+    pub fn universal_transfer_history(
+        &self, request: UniversalTransferHistoryRequest,
+    ) -> Result<Records<UniversalTransferRecord>> {
+        let request =
+            build_signed_request(build_universal_transfer_history(request), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::UniversalTransfer), Some(request))
+    }
+
+    /// Submit a withdrawal, returning its ID in the withdraw history.
+    pub fn withdraw(&self, request: WithdrawRequest) -> Result<WithdrawResponse> {
+        let request = build_signed_request(build_withdraw(request), self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::Withdraw), request)
+    }
+
+    /// Deposits inside a window of at most 90 days, newest first.
+    pub fn deposit_history(&self, request: DepositHistoryRequest) -> Result<Vec<DepositRecord>> {
+        let request = build_signed_request(build_deposit_history(request), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::DepositHistory), Some(request))
+    }
+
+    /// Withdrawals inside a window of at most 90 days, or 7 days for one
+    /// `withdraw_order_id`, newest first.
+    pub fn withdraw_history(&self, request: WithdrawHistoryRequest) -> Result<Vec<WithdrawRecord>> {
+        let request = build_signed_request(build_withdraw_history(request), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::WithdrawHistory), Some(request))
+    }
+
+    /// Every deposit from the request's end time, or now, back to its start
+    /// time, or 90 days back, 90 days at a time.
+    pub fn deposit_history_iter(
+        &self, request: DepositHistoryRequest,
+    ) -> WindowPages<'_, DepositRecord> {
+        let (start_time, end_time) = (request.start_time, request.end_time);
+        let limit = request.limit.unwrap_or(1000);
+        let fetch = move |page: &WindowRequest| {
+            self.deposit_history(DepositHistoryRequest {
+                start_time: Some(page.start_time),
+                end_time: Some(page.end_time),
+                offset: Some(page.offset),
+                limit: Some(page.limit),
+                ..request.clone()
+            })
+        };
+        WindowPages::new(fetch, start_time, end_time, WALLET_HISTORY_WINDOW, limit)
+    }
+
+    /// Every withdrawal from the request's end time, or now, back to its
+    /// start time, or 90 days back, 90 days at a time, or 7 days at a time
+    /// when filtered by `withdraw_order_id`.
+    pub fn withdraw_history_iter(
+        &self, request: WithdrawHistoryRequest,
+    ) -> WindowPages<'_, WithdrawRecord> {
+        let (start_time, end_time) = (request.start_time, request.end_time);
+        let limit = request.limit.unwrap_or(1000);
+        let window = if request.withdraw_order_id.is_some() {
+            WITHDRAW_ORDER_ID_WINDOW
+        } else {
+            WALLET_HISTORY_WINDOW
+        };
+        let fetch = move |page: &WindowRequest| {
+            self.withdraw_history(WithdrawHistoryRequest {
+                start_time: Some(page.start_time),
+                end_time: Some(page.end_time),
+                offset: Some(page.offset),
+                limit: Some(page.limit),
+                ..request.clone()
+            })
+        };
+        WindowPages::new(fetch, start_time, end_time, window, limit)
+    }
+
+    /// Flexible Simple Earn products.
+    pub fn flexible_products(
+        &self, request: EarnProductRequest,
+    ) -> Result<Records<FlexibleProduct>> {
+        let request = build_signed_request(build_earn_product(request), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::FlexibleProducts), Some(request))
+    }
+
+    /// Locked Simple Earn products.
+    pub fn locked_products(&self, request: EarnProductRequest) -> Result<Records<LockedProduct>> {
+        let request = build_signed_request(build_earn_product(request), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::LockedProducts), Some(request))
+    }
+
+    /// Every flexible product, 100 at a time unless the request sets a page size.
+    pub fn flexible_products_iter(
+        &self, request: EarnProductRequest,
+    ) -> NumberedPages<'_, FlexibleProduct> {
+        let size = request.size.unwrap_or(100);
+        let fetch = move |page: &NumberedRequest| {
+            self.flexible_products(request.clone().set_page(page.current, page.size))
+        };
+        NumberedPages::new(fetch, size)
+    }
+
+    /// Every locked product, 100 at a time unless the request sets a page size.
+    pub fn locked_products_iter(
+        &self, request: EarnProductRequest,
+    ) -> NumberedPages<'_, LockedProduct> {
+        let size = request.size.unwrap_or(100);
+        let fetch = move |page: &NumberedRequest| {
+            self.locked_products(request.clone().set_page(page.current, page.size))
+        };
+        NumberedPages::new(fetch, size)
+    }
+
+    /// Subscribe to a flexible product.
+    pub fn subscribe_flexible(&self, request: FlexibleSubscribeRequest) -> Result<EarnPurchase> {
+        let request = build_signed_request(build_flexible_subscribe(request), self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::FlexibleSubscribe), request)
+    }
+
+    /// Subscribe to a locked product, opening a new position.
+    pub fn subscribe_locked(&self, request: LockedSubscribeRequest) -> Result<EarnPurchase> {
+        let request = build_signed_request(build_locked_subscribe(request), self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::LockedSubscribe), request)
+    }
+
+    /// Redeem part or all of a flexible position.
+    pub fn redeem_flexible(&self, request: FlexibleRedeemRequest) -> Result<EarnRedemption> {
+        request.validate()?;
+        let request = build_signed_request(build_flexible_redeem(request), self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::FlexibleRedeem), request)
+    }
+
+    /// Redeem a locked position before the end of its duration.
+    pub fn redeem_locked(&self, position_id: u64) -> Result<EarnRedemption> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("positionId".into(), position_id.to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::LockedRedeem), request)
+    }
+
+    /// Flexible positions.
+    pub fn flexible_positions(
+        &self, request: FlexiblePositionRequest,
+    ) -> Result<Records<FlexiblePosition>> {
+        let request = build_signed_request(build_flexible_position(request), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::FlexiblePosition), Some(request))
+    }
+
+    /// Locked positions.
+    pub fn locked_positions(
+        &self, request: LockedPositionRequest,
+    ) -> Result<Records<LockedPosition>> {
+        let request = build_signed_request(build_locked_position(request), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::LockedPosition), Some(request))
+    }
+
+    /// Every flexible position, 100 at a time unless the request sets a page size.
+    pub fn flexible_positions_iter(
+        &self, request: FlexiblePositionRequest,
+    ) -> NumberedPages<'_, FlexiblePosition> {
+        let size = request.size.unwrap_or(100);
+        let fetch = move |page: &NumberedRequest| {
+            self.flexible_positions(request.clone().set_page(page.current, page.size))
+        };
+        NumberedPages::new(fetch, size)
+    }
+
+    /// Every locked position, 100 at a time unless the request sets a page size.
+    pub fn locked_positions_iter(
+        &self, request: LockedPositionRequest,
+    ) -> NumberedPages<'_, LockedPosition> {
+        let size = request.size.unwrap_or(100);
+        let fetch = move |page: &NumberedRequest| {
+            self.locked_positions(request.clone().set_page(page.current, page.size))
+        };
+        NumberedPages::new(fetch, size)
+    }
+
+    /// Flexible subscriptions, newest first.
+    pub fn flexible_subscription_history(
+        &self, request: EarnHistoryRequest,
+    ) -> Result<Records<FlexibleSubscriptionRecord>> {
+        let request = build_signed_request(build_earn_history(request), self.recv_window)?;
+        self.client.get_signed(
+            API::Savings(Sapi::FlexibleSubscriptionRecord),
+            Some(request),
+        )
+    }
+
+    /// Locked subscriptions, newest first.
+    pub fn locked_subscription_history(
+        &self, request: EarnHistoryRequest,
+    ) -> Result<Records<LockedSubscriptionRecord>> {
+        let request = build_signed_request(build_earn_history(request), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::LockedSubscriptionRecord), Some(request))
+    }
+
+    /// Flexible redemptions, newest first.
+    pub fn flexible_redemption_history(
+        &self, request: EarnHistoryRequest,
+    ) -> Result<Records<FlexibleRedemptionRecord>> {
+        let request = build_signed_request(build_earn_history(request), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::FlexibleRedemptionRecord), Some(request))
+    }
+
+    /// Locked redemptions, newest first.
+    pub fn locked_redemption_history(
+        &self, request: EarnHistoryRequest,
+    ) -> Result<Records<LockedRedemptionRecord>> {
+        let request = build_signed_request(build_earn_history(request), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::LockedRedemptionRecord), Some(request))
+    }
+
+    /// Flexible rewards of one type, newest first.
+    pub fn flexible_rewards_history(
+        &self, reward_type: EarnRewardType, request: EarnHistoryRequest,
+    ) -> Result<Records<FlexibleRewardRecord>> {
+        let mut parameters = build_earn_history(request);
+        parameters.insert("type".into(), reward_type.to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::FlexibleRewardsRecord), Some(request))
+    }
+
+    /// Locked rewards, newest first.
+    pub fn locked_rewards_history(
+        &self, request: EarnHistoryRequest,
+    ) -> Result<Records<LockedRewardRecord>> {
+        let request = build_signed_request(build_earn_history(request), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::LockedRewardsRecord), Some(request))
+    }
+
+    /// Every flexible subscription from the request's end time, or now, back to its start
+    /// time, or 90 days back, 90 days and 100 records at a time unless the
+    /// request sets a page size.
+    pub fn flexible_subscription_history_iter(
+        &self, request: EarnHistoryRequest,
+    ) -> NumberedPages<'_, FlexibleSubscriptionRecord> {
+        let (start_time, end_time) = (request.start_time, request.end_time);
+        let size = request.size.unwrap_or(100);
+        let fetch = move |page: &NumberedRequest| {
+            self.flexible_subscription_history(earn_history_page(&request, page))
+        };
+        NumberedPages::by_time(fetch, start_time, end_time, EARN_HISTORY_WINDOW, size)
+    }
+
+    /// Every locked subscription from the request's end time, or now, back to its start
+    /// time, or 90 days back, 90 days and 100 records at a time unless the
+    /// request sets a page size.
+    pub fn locked_subscription_history_iter(
+        &self, request: EarnHistoryRequest,
+    ) -> NumberedPages<'_, LockedSubscriptionRecord> {
+        let (start_time, end_time) = (request.start_time, request.end_time);
+        let size = request.size.unwrap_or(100);
+        let fetch = move |page: &NumberedRequest| {
+            self.locked_subscription_history(earn_history_page(&request, page))
+        };
+        NumberedPages::by_time(fetch, start_time, end_time, EARN_HISTORY_WINDOW, size)
+    }
+
+    /// Every flexible redemption from the request's end time, or now, back to its start
+    /// time, or 90 days back, 90 days and 100 records at a time unless the
+    /// request sets a page size.
+    pub fn flexible_redemption_history_iter(
+        &self, request: EarnHistoryRequest,
+    ) -> NumberedPages<'_, FlexibleRedemptionRecord> {
+        let (start_time, end_time) = (request.start_time, request.end_time);
+        let size = request.size.unwrap_or(100);
+        let fetch = move |page: &NumberedRequest| {
+            self.flexible_redemption_history(earn_history_page(&request, page))
+        };
+        NumberedPages::by_time(fetch, start_time, end_time, EARN_HISTORY_WINDOW, size)
+    }
+
+    /// Every locked redemption from the request's end time, or now, back to its start
+    /// time, or 90 days back, 90 days and 100 records at a time unless the
+    /// request sets a page size.
+    pub fn locked_redemption_history_iter(
+        &self, request: EarnHistoryRequest,
+    ) -> NumberedPages<'_, LockedRedemptionRecord> {
+        let (start_time, end_time) = (request.start_time, request.end_time);
+        let size = request.size.unwrap_or(100);
+        let fetch = move |page: &NumberedRequest| {
+            self.locked_redemption_history(earn_history_page(&request, page))
+        };
+        NumberedPages::by_time(fetch, start_time, end_time, EARN_HISTORY_WINDOW, size)
+    }
+
+    /// Every flexible reward of one type from the request's end time, or
+    /// now, back to its start time, or 90 days back, 90 days and 100 records
+    /// at a time unless the request sets a page size.
+    pub fn flexible_rewards_history_iter(
+        &self, reward_type: EarnRewardType, request: EarnHistoryRequest,
+    ) -> NumberedPages<'_, FlexibleRewardRecord> {
+        let (start_time, end_time) = (request.start_time, request.end_time);
+        let size = request.size.unwrap_or(100);
+        let fetch = move |page: &NumberedRequest| {
+            self.flexible_rewards_history(reward_type.clone(), earn_history_page(&request, page))
+        };
+        NumberedPages::by_time(fetch, start_time, end_time, EARN_HISTORY_WINDOW, size)
+    }
+
+    /// Every locked reward from the request's end time, or now, back to its start
+    /// time, or 90 days back, 90 days and 100 records at a time unless the
+    /// request sets a page size.
+    pub fn locked_rewards_history_iter(
+        &self, request: EarnHistoryRequest,
+    ) -> NumberedPages<'_, LockedRewardRecord> {
+        let (start_time, end_time) = (request.start_time, request.end_time);
+        let size = request.size.unwrap_or(100);
+        let fetch = move |page: &NumberedRequest| {
+            self.locked_rewards_history(earn_history_page(&request, page))
+        };
+        NumberedPages::by_time(fetch, start_time, end_time, EARN_HISTORY_WINDOW, size)
+    }
+}
+
+fn earn_history_page(request: &EarnHistoryRequest, page: &NumberedRequest) -> EarnHistoryRequest {
+    EarnHistoryRequest {
+        start_time: page.start_time,
+        end_time: page.end_time,
+        current: Some(page.current),
+        size: Some(page.size),
+        ..request.clone()
+    }
+}
+
+pub(crate) fn build_universal_transfer(
+    request: UniversalTransferRequest,
+) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+    parameters.insert("type".into(), request.transfer_type.to_string());
+    parameters.insert("asset".into(), request.asset);
+    parameters.insert("amount".into(), request.amount.to_string());
+
+    if let Some(from_symbol) = request.from_symbol {
+        parameters.insert("fromSymbol".into(), from_symbol);
+    }
+    if let Some(to_symbol) = request.to_symbol {
+        parameters.insert("toSymbol".into(), to_symbol);
+    }
+
+    parameters
+}
+
+pub(crate) fn build_universal_transfer_history(
+    request: UniversalTransferHistoryRequest,
+) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+    parameters.insert("type".into(), request.transfer_type.to_string());
+
+    if let Some(start_time) = request.start_time {
+        parameters.insert("startTime".into(), start_time.to_string());
+    }
+    if let Some(end_time) = request.end_time {
+        parameters.insert("endTime".into(), end_time.to_string());
+    }
+    if let Some(current) = request.current {
+        parameters.insert("current".into(), current.to_string());
+    }
+    if let Some(size) = request.size {
+        parameters.insert("size".into(), size.to_string());
+    }
+    if let Some(from_symbol) = request.from_symbol {
+        parameters.insert("fromSymbol".into(), from_symbol);
+    }
+    if let Some(to_symbol) = request.to_symbol {
+        parameters.insert("toSymbol".into(), to_symbol);
+    }
+
+    parameters
+}
+
+pub(crate) fn build_withdraw(request: WithdrawRequest) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+    parameters.insert("coin".into(), request.coin);
+    parameters.insert("address".into(), request.address);
+    parameters.insert("amount".into(), request.amount.to_string());
+
+    if let Some(network) = request.network {
+        parameters.insert("network".into(), network);
+    }
+    if let Some(address_tag) = request.address_tag {
+        parameters.insert("addressTag".into(), address_tag);
+    }
+    if let Some(withdraw_order_id) = request.withdraw_order_id {
+        parameters.insert("withdrawOrderId".into(), withdraw_order_id);
+    }
+    if let Some(transaction_fee_flag) = request.transaction_fee_flag {
+        parameters.insert(
+            "transactionFeeFlag".into(),
+            transaction_fee_flag.to_string(),
+        );
+    }
+    if let Some(name) = request.name {
+        parameters.insert("name".into(), name);
+    }
+    if let Some(wallet_type) = request.wallet_type {
+        parameters.insert("walletType".into(), wallet_type.to_string());
+    }
+
+    parameters
+}
+
+pub(crate) fn build_deposit_history(request: DepositHistoryRequest) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+    if let Some(coin) = request.coin {
+        parameters.insert("coin".into(), coin);
+    }
+    if let Some(status) = request.status {
+        parameters.insert("status".into(), status.to_string());
+    }
+    if let Some(tx_id) = request.tx_id {
+        parameters.insert("txId".into(), tx_id);
+    }
+    insert_window(
+        &mut parameters,
+        request.start_time,
+        request.end_time,
+        request.offset,
+        request.limit,
+    );
+
+    parameters
+}
+
+pub(crate) fn build_withdraw_history(request: WithdrawHistoryRequest) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+    if let Some(coin) = request.coin {
+        parameters.insert("coin".into(), coin);
+    }
+    if let Some(withdraw_order_id) = request.withdraw_order_id {
+        parameters.insert("withdrawOrderId".into(), withdraw_order_id);
+    }
+    if let Some(status) = request.status {
+        parameters.insert("status".into(), status.to_string());
+    }
+    insert_window(
+        &mut parameters,
+        request.start_time,
+        request.end_time,
+        request.offset,
+        request.limit,
+    );
+
+    parameters
+}
+
+fn insert_window(
+    parameters: &mut BTreeMap<String, String>, start_time: Option<u64>, end_time: Option<u64>,
+    offset: Option<u32>, limit: Option<u16>,
+) {
+    if let Some(start_time) = start_time {
+        parameters.insert("startTime".into(), start_time.to_string());
+    }
+    if let Some(end_time) = end_time {
+        parameters.insert("endTime".into(), end_time.to_string());
+    }
+    if let Some(offset) = offset {
+        parameters.insert("offset".into(), offset.to_string());
+    }
+    if let Some(limit) = limit {
+        parameters.insert("limit".into(), limit.to_string());
+    }
+}
+
+pub(crate) fn build_earn_product(request: EarnProductRequest) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+    if let Some(asset) = request.asset {
+        parameters.insert("asset".into(), asset);
+    }
+    insert_page(&mut parameters, request.current, request.size);
+
+    parameters
+}
+
+pub(crate) fn build_flexible_position(
+    request: FlexiblePositionRequest,
+) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+    if let Some(asset) = request.asset {
+        parameters.insert("asset".into(), asset);
+    }
+    if let Some(product_id) = request.product_id {
+        parameters.insert("productId".into(), product_id);
+    }
+    insert_page(&mut parameters, request.current, request.size);
+
+    parameters
+}
+
+pub(crate) fn build_locked_position(request: LockedPositionRequest) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+    if let Some(asset) = request.asset {
+        parameters.insert("asset".into(), asset);
+    }
+    if let Some(position_id) = request.position_id {
+        parameters.insert("positionId".into(), position_id.to_string());
+    }
+    if let Some(project_id) = request.project_id {
+        parameters.insert("projectId".into(), project_id);
+    }
+    insert_page(&mut parameters, request.current, request.size);
+
+    parameters
+}
+
+pub(crate) fn build_flexible_subscribe(
+    request: FlexibleSubscribeRequest,
+) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+    parameters.insert("productId".into(), request.product_id);
+    parameters.insert("amount".into(), request.amount.to_string());
+
+    if let Some(auto_subscribe) = request.auto_subscribe {
+        parameters.insert("autoSubscribe".into(), auto_subscribe.to_string());
+    }
+    if let Some(source_account) = request.source_account {
+        parameters.insert("sourceAccount".into(), source_account.to_string());
+    }
+
+    parameters
+}
+
+pub(crate) fn build_locked_subscribe(request: LockedSubscribeRequest) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+    parameters.insert("projectId".into(), request.project_id);
+    parameters.insert("amount".into(), request.amount.to_string());
+
+    if let Some(auto_subscribe) = request.auto_subscribe {
+        parameters.insert("autoSubscribe".into(), auto_subscribe.to_string());
+    }
+    if let Some(source_account) = request.source_account {
+        parameters.insert("sourceAccount".into(), source_account.to_string());
+    }
+    if let Some(redeem_to) = request.redeem_to {
+        parameters.insert("redeemTo".into(), redeem_to.to_string());
+    }
+
+    parameters
+}
+
+pub(crate) fn build_flexible_redeem(request: FlexibleRedeemRequest) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+    parameters.insert("productId".into(), request.product_id);
+
+    if request.redeem_all {
+        parameters.insert("redeemAll".into(), "true".into());
+    }
+    if let Some(amount) = request.amount {
+        parameters.insert("amount".into(), amount.to_string());
+    }
+    if let Some(dest_account) = request.dest_account {
+        parameters.insert("destAccount".into(), dest_account.to_string());
+    }
+
+    parameters
+}
+
+pub(crate) fn build_earn_history(request: EarnHistoryRequest) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+    if let Some(asset) = request.asset {
+        parameters.insert("asset".into(), asset);
+    }
+    if let Some(start_time) = request.start_time {
+        parameters.insert("startTime".into(), start_time.to_string());
+    }
+    if let Some(end_time) = request.end_time {
+        parameters.insert("endTime".into(), end_time.to_string());
+    }
+    insert_page(&mut parameters, request.current, request.size);
+
+    parameters
+}
+
+fn insert_page(parameters: &mut BTreeMap<String, String>, current: Option<u32>, size: Option<u32>) {
+    if let Some(current) = current {
+        parameters.insert("current".into(), current.to_string());
+    }
+    if let Some(size) = size {
+        parameters.insert("size".into(), size.to_string());
+    }
 }
@@ -0,0 +1,387 @@
+//! Sub-accounts of a master account, through `/sapi/*/sub-account/*`.
+//!
+//! Every request is signed with the master account's key, sub-accounts are
+//! identified by their email:
+//!
+//!```no_run
+//! use binance::api::Binance;
+//! use binance::subaccount::*;
+//!
+//! fn main() {
+//!     let sub_account: SubAccount = Binance::new(Some("api_key".into()), Some("secret_key".into()));
+//!
+//!     for info in sub_account.list(SubAccountListRequest::new()).unwrap() {
+//!         let balances = sub_account.spot_balances(info.email.as_str()).unwrap();
+//!         println!("{}: {:?}", info.email, balances);
+//!     }
+//!
+//!     // Fund a strategy's futures wallet from the master spot wallet
+//!     let request =
+//!         SubAccountTransferRequest::new(SubAccountType::Spot, SubAccountType::UsdtFuture, "USDT", 1000)
+//!             .set_to_email("strategy-1@example.com");
+//!     println!("{:?}", sub_account.transfer(request).unwrap());
+//! }
+//! ```
+use error_chain::bail;
+
+use crate::util::build_signed_request;
+pub use crate::model::{SubAccountFuturesType, SubAccountType};
+use crate::model::{
+    Number, SubAccountBalance, SubAccountBalances, SubAccountFuturesAccount,
+    SubAccountFuturesEnabled, SubAccountInfo, SubAccountMarginAccount, SubAccountMarginEnabled,
+    SubAccountTransferHistory, SubAccountTransferResponse, SubAccounts,
+};
+use crate::client::Client;
+use crate::errors::Result;
+use std::collections::BTreeMap;
+use crate::api::API;
+use crate::api::SubAccountSapi;
+
+#[derive(Clone)]
+pub struct SubAccount {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+/// Parameters of `GET /sapi/v1/sub-account/list`.
+///
+/// Pages start at 1 and hold up to 200 sub-accounts, 1 by default.
+#[derive(Clone, Debug, Default)]
+pub struct SubAccountListRequest {
+    pub email: Option<String>,
+    pub is_freeze: Option<bool>,
+    pub page: Option<u32>,
+    pub limit: Option<u16>,
+}
+
+impl SubAccountListRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_email<S: Into<String>>(mut self, email: S) -> Self {
+        self.email = Some(email.into());
+        self
+    }
+
+    /// Only the frozen, or only the active, sub-accounts.
+    pub fn set_is_freeze(mut self, is_freeze: bool) -> Self {
+        self.is_freeze = Some(is_freeze);
+        self
+    }
+
+    pub fn set_page(mut self, page: u32, limit: u16) -> Self {
+        self.page = Some(page);
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// Parameters of `POST /sapi/v1/sub-account/universalTransfer`.
+///
+/// Transfers come from and go to the master account unless an email is set,
+/// between two of its wallets or between two sub-accounts.
+#[derive(Clone, Debug)]
+pub struct SubAccountTransferRequest {
+    pub from_email: Option<String>,
+    pub to_email: Option<String>,
+    pub from_account_type: SubAccountType,
+    pub to_account_type: SubAccountType,
+    pub client_tran_id: Option<String>,
+    pub symbol: Option<String>,
+    pub asset: String,
+    pub amount: Number,
+}
+
+impl SubAccountTransferRequest {
+    pub fn new<S, F>(
+        from_account_type: SubAccountType, to_account_type: SubAccountType, asset: S, amount: F,
+    ) -> Self
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        Self {
+            from_email: None,
+            to_email: None,
+            from_account_type,
+            to_account_type,
+            client_tran_id: None,
+            symbol: None,
+            asset: asset.into(),
+            amount: amount.into(),
+        }
+    }
+
+    pub fn set_from_email<S: Into<String>>(mut self, from_email: S) -> Self {
+        self.from_email = Some(from_email.into());
+        self
+    }
+
+    pub fn set_to_email<S: Into<String>>(mut self, to_email: S) -> Self {
+        self.to_email = Some(to_email.into());
+        self
+    }
+
+    /// Client ID of the transfer, to find it in the transfer history.
+    pub fn set_client_tran_id<S: Into<String>>(mut self, client_tran_id: S) -> Self {
+        self.client_tran_id = Some(client_tran_id.into());
+        self
+    }
+
+    /// Symbol of the isolated margin account of either side.
+    pub fn set_symbol<S: Into<String>>(mut self, symbol: S) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    /// Check the accounts, without contacting the exchange.
+    pub fn validate(&self) -> Result<()> {
+        if self.from_email.is_none() && self.to_email.is_none() {
+            bail!("Sub-account transfers need a from or a to email");
+        }
+        let isolated = self.from_account_type == SubAccountType::IsolatedMargin ||
+            self.to_account_type == SubAccountType::IsolatedMargin;
+        if isolated != self.symbol.is_some() {
+            bail!("Sub-account transfers need a symbol only for isolated margin accounts");
+        }
+        Ok(())
+    }
+}
+
+/// Parameters of `GET /sapi/v1/sub-account/universalTransfer`.
+///
+/// Without a time window, Binance returns the last 30 days. Pages start at 1
+/// and hold up to 500 transfers, 500 by default.
+#[derive(Clone, Debug, Default)]
+pub struct SubAccountTransferHistoryRequest {
+    pub from_email: Option<String>,
+    pub to_email: Option<String>,
+    pub client_tran_id: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub page: Option<u32>,
+    pub limit: Option<u16>,
+}
+
+impl SubAccountTransferHistoryRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only the transfers from this sub-account, not together with `set_to_email`.
+    pub fn set_from_email<S: Into<String>>(mut self, from_email: S) -> Self {
+        self.from_email = Some(from_email.into());
+        self
+    }
+
+    /// Only the transfers to this sub-account, not together with `set_from_email`.
+    pub fn set_to_email<S: Into<String>>(mut self, to_email: S) -> Self {
+        self.to_email = Some(to_email.into());
+        self
+    }
+
+    pub fn set_client_tran_id<S: Into<String>>(mut self, client_tran_id: S) -> Self {
+        self.client_tran_id = Some(client_tran_id.into());
+        self
+    }
+
+    pub fn set_start_time(mut self, start_time: u64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn set_end_time(mut self, end_time: u64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn set_page(mut self, page: u32, limit: u16) -> Self {
+        self.page = Some(page);
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Check the filters, without contacting the exchange.
+    pub fn validate(&self) -> Result<()> {
+        if self.from_email.is_some() && self.to_email.is_some() {
+            bail!("The sub-account transfer history filters on a from or a to email, not both");
+        }
+        Ok(())
+    }
+}
+
+impl SubAccount {
+    /// Sub-accounts of the master account.
+    pub fn list(&self, request: SubAccountListRequest) -> Result<Vec<SubAccountInfo>> {
+        let request = build_signed_request(build_list(request), self.recv_window)?;
+        let list: SubAccounts = self
+            .client
+            .get_signed(API::SubAccount(SubAccountSapi::List), Some(request))?;
+        Ok(list.sub_accounts)
+    }
+
+    /// Spot balances of a sub-account.
+    pub fn spot_balances<S>(&self, email: S) -> Result<Vec<SubAccountBalance>>
+    where
+        S: Into<String>,
+    {
+        let request = build_signed_request(build_email(email.into()), self.recv_window)?;
+        let assets: SubAccountBalances = self
+            .client
+            .get_signed(API::SubAccount(SubAccountSapi::SpotAssets), Some(request))?;
+        Ok(assets.balances)
+    }
+
+    /// USD-M or COIN-M futures account of a sub-account.
+    pub fn futures_account<S>(
+        &self, email: S, futures_type: SubAccountFuturesType,
+    ) -> Result<SubAccountFuturesAccount>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = build_email(email.into());
+        parameters.insert("futuresType".into(), (futures_type as u8).to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client.get_signed(
+            API::SubAccount(SubAccountSapi::FuturesAccount),
+            Some(request),
+        )
+    }
+
+    /// Cross margin account of a sub-account.
+    pub fn margin_account<S>(&self, email: S) -> Result<SubAccountMarginAccount>
+    where
+        S: Into<String>,
+    {
+        let request = build_signed_request(build_email(email.into()), self.recv_window)?;
+        self.client.get_signed(
+            API::SubAccount(SubAccountSapi::MarginAccount),
+            Some(request),
+        )
+    }
+
+    /// Move an asset between the master and a sub-account, or between two sub-accounts.
+    pub fn transfer(
+        &self, request: SubAccountTransferRequest,
+    ) -> Result<SubAccountTransferResponse> {
+        request.validate()?;
+        let request = build_signed_request(build_transfer(request), self.recv_window)?;
+        self.client
+            .post_signed(API::SubAccount(SubAccountSapi::UniversalTransfer), request)
+    }
+
+    /// Transfers made with `transfer`, newest first.
+    pub fn transfer_history(
+        &self, request: SubAccountTransferHistoryRequest,
+    ) -> Result<SubAccountTransferHistory> {
+        request.validate()?;
+        let request = build_signed_request(build_transfer_history(request), self.recv_window)?;
+        self.client.get_signed(
+            API::SubAccount(SubAccountSapi::UniversalTransfer),
+            Some(request),
+        )
+    }
+
+    /// Open the futures account of a sub-account.
+    pub fn enable_futures<S>(&self, email: S) -> Result<SubAccountFuturesEnabled>
+    where
+        S: Into<String>,
+    {
+        let request = build_signed_request(build_email(email.into()), self.recv_window)?;
+        self.client
+            .post_signed(API::SubAccount(SubAccountSapi::EnableFutures), request)
+    }
+
+    /// Open the margin account of a sub-account.
+    pub fn enable_margin<S>(&self, email: S) -> Result<SubAccountMarginEnabled>
+    where
+        S: Into<String>,
+    {
+        let request = build_signed_request(build_email(email.into()), self.recv_window)?;
+        self.client
+            .post_signed(API::SubAccount(SubAccountSapi::EnableMargin), request)
+    }
+}
+
+pub(crate) fn build_email(email: String) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+    parameters.insert("email".into(), email);
+    parameters
+}
+
+pub(crate) fn build_list(request: SubAccountListRequest) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+    if let Some(email) = request.email {
+        parameters.insert("email".into(), email);
+    }
+    if let Some(is_freeze) = request.is_freeze {
+        parameters.insert("isFreeze".into(), is_freeze.to_string());
+    }
+    if let Some(page) = request.page {
+        parameters.insert("page".into(), page.to_string());
+    }
+    if let Some(limit) = request.limit {
+        parameters.insert("limit".into(), limit.to_string());
+    }
+
+    parameters
+}
+
+pub(crate) fn build_transfer(request: SubAccountTransferRequest) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+    parameters.insert(
+        "fromAccountType".into(),
+        request.from_account_type.to_string(),
+    );
+    parameters.insert("toAccountType".into(), request.to_account_type.to_string());
+    parameters.insert("asset".into(), request.asset);
+    parameters.insert("amount".into(), request.amount.to_string());
+
+    if let Some(from_email) = request.from_email {
+        parameters.insert("fromEmail".into(), from_email);
+    }
+    if let Some(to_email) = request.to_email {
+        parameters.insert("toEmail".into(), to_email);
+    }
+    if let Some(client_tran_id) = request.client_tran_id {
+        parameters.insert("clientTranId".into(), client_tran_id);
+    }
+    if let Some(symbol) = request.symbol {
+        parameters.insert("symbol".into(), symbol);
+    }
+
+    parameters
+}
+
+pub(crate) fn build_transfer_history(
+    request: SubAccountTransferHistoryRequest,
+) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+    if let Some(from_email) = request.from_email {
+        parameters.insert("fromEmail".into(), from_email);
+    }
+    if let Some(to_email) = request.to_email {
+        parameters.insert("toEmail".into(), to_email);
+    }
+    if let Some(client_tran_id) = request.client_tran_id {
+        parameters.insert("clientTranId".into(), client_tran_id);
+    }
+    if let Some(start_time) = request.start_time {
+        parameters.insert("startTime".into(), start_time.to_string());
+    }
+    if let Some(end_time) = request.end_time {
+        parameters.insert("endTime".into(), end_time.to_string());
+    }
+    if let Some(page) = request.page {
+        parameters.insert("page".into(), page.to_string());
+    }
+    if let Some(limit) = request.limit {
+        parameters.insert("limit".into(), limit.to_string());
+    }
+
+    parameters
+}
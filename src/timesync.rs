@@ -0,0 +1,119 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::errors::{ErrorKind, Result};
+use crate::util::get_timestamp;
+
+// "Timestamp for this request is outside of the recvWindow"
+const TIMESTAMP_OUTSIDE_RECV_WINDOW: i16 = -1021;
+
+#[derive(Debug, Default)]
+struct State {
+    offset: i64,
+    drift: i64,
+    round_trip: Option<Duration>,
+    synced_at: Option<Instant>,
+    last_sync: Option<u64>,
+}
+
+/// Keeps signed requests stamped with the Binance server time instead of the local clock.
+///
+/// The offset is measured against `/api/v3/time` (or `/fapi/v1/time` for futures),
+/// assuming the server read its clock halfway through the round trip. Clients refresh
+/// it before a signed request once `refresh_interval` has passed, and right after
+/// Binance rejects a timestamp with -1021.
+///
+/// ```no_run
+/// use binance::api::*;
+/// use binance::account::Account;
+/// use binance::config::Config;
+/// use binance::timesync::TimeSync;
+/// use std::time::Duration;
+///
+/// let config = Config::default().set_time_sync(TimeSync::new(Duration::from_secs(300)));
+/// let account: Account = Binance::new_with_config(None, None, &config);
+/// account.get_account().unwrap();
+///
+/// let time_sync = config.time_sync.unwrap();
+/// println!("offset {}ms, drift {}ms", time_sync.offset(), time_sync.drift());
+/// ```
+#[derive(Debug)]
+pub struct TimeSync {
+    refresh_interval: Duration,
+    state: Mutex<State>,
+}
+
+impl Default for TimeSync {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(600))
+    }
+}
+
+impl TimeSync {
+    pub fn new(refresh_interval: Duration) -> Self {
+        TimeSync {
+            refresh_interval,
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    /// Server time minus local time, in milliseconds.
+    pub fn offset(&self) -> i64 {
+        self.state.lock().unwrap().offset
+    }
+
+    /// How much the offset moved between the last two syncs, in milliseconds.
+    pub fn drift(&self) -> i64 {
+        self.state.lock().unwrap().drift
+    }
+
+    /// Round trip of the request used for the last sync.
+    pub fn round_trip(&self) -> Option<Duration> {
+        self.state.lock().unwrap().round_trip
+    }
+
+    /// Local time of the last sync in milliseconds since epoch.
+    pub fn last_sync(&self) -> Option<u64> {
+        self.state.lock().unwrap().last_sync
+    }
+
+    /// Current server time estimate in milliseconds since epoch.
+    pub fn now(&self) -> Result<u64> {
+        let local = get_timestamp(SystemTime::now())? as i64;
+        Ok((local + self.offset()) as u64)
+    }
+
+    pub(crate) fn needs_sync(&self) -> bool {
+        match self.state.lock().unwrap().synced_at {
+            Some(synced_at) => synced_at.elapsed() >= self.refresh_interval,
+            None => true,
+        }
+    }
+
+    // `sent` and `received` are the local clock around the request for `server_time`
+    pub(crate) fn record(&self, sent: u64, received: u64, server_time: u64) -> i64 {
+        let round_trip = received.saturating_sub(sent);
+        let offset = server_time as i64 - (sent + round_trip / 2) as i64;
+
+        let mut state = self.state.lock().unwrap();
+        if state.synced_at.is_some() {
+            state.drift = offset - state.offset;
+        }
+        state.offset = offset;
+        state.round_trip = Some(Duration::from_millis(round_trip));
+        state.synced_at = Some(Instant::now());
+        state.last_sync = Some(received);
+        offset
+    }
+
+    // A rejected timestamp means the offset is stale, whatever its age
+    pub(crate) fn check<T>(&self, result: &Result<T>) {
+        if let Err(e) = result {
+            if let ErrorKind::BinanceError(response) = e.kind() {
+                if response.code == TIMESTAMP_OUTSIDE_RECV_WINDOW {
+                    self.state.lock().unwrap().synced_at = None;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use std::fmt;
+
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode};
+
+use crate::errors::Result;
+
+/// A fully built request: signed, with headers, ready to go on the wire.
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: String,
+    pub headers: HeaderMap,
+    pub body: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
+/// Sends requests for the blocking `Client`.
+///
+/// `ReqwestTransport` is used unless another transport is set with
+/// `Config::set_transport`, e.g. an in-memory fake in tests:
+///
+/// ```
+/// use binance::api::*;
+/// use binance::config::Config;
+/// use binance::errors::Result;
+/// use binance::general::General;
+/// use binance::transport::{HttpRequest, HttpResponse, Transport};
+///
+/// #[derive(Debug)]
+/// struct Pong;
+///
+/// impl Transport for Pong {
+///     fn send(&self, _request: HttpRequest) -> Result<HttpResponse> {
+///         Ok(HttpResponse {
+///             status: reqwest::StatusCode::OK,
+///             headers: Default::default(),
+///             body: "{}".into(),
+///         })
+///     }
+/// }
+///
+/// let general: General = Binance::new_with_config(None, None, &Config::default().set_transport(Pong));
+/// assert_eq!(general.ping().unwrap(), "pong");
+/// ```
+pub trait Transport: fmt::Debug + Send + Sync {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse>;
+}
+
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    inner_client: reqwest::blocking::Client,
+}
+
+impl Default for ReqwestTransport {
+    fn default() -> Self {
+        ReqwestTransport {
+            inner_client: reqwest::blocking::Client::builder()
+                .pool_idle_timeout(None)
+                .build()
+                .unwrap(),
+        }
+    }
+}
+
+impl ReqwestTransport {
+    /// Use a preconfigured client, e.g. with a proxy or timeouts.
+    pub fn new(inner_client: reqwest::blocking::Client) -> Self {
+        ReqwestTransport { inner_client }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let mut builder = self
+            .inner_client
+            .request(request.method, request.url.as_str())
+            .headers(request.headers);
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+
+        let response = builder.send()?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.text()?;
+
+        Ok(HttpResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+}
+
+#[cfg(feature = "async")]
+pub use self::nonblocking::{AsyncTransport, ReqwestAsyncTransport};
+
+#[cfg(feature = "async")]
+mod nonblocking {
+    use super::{HttpRequest, HttpResponse};
+    use crate::errors::Result;
+    use std::fmt;
+    use std::future::Future;
+    use std::pin::Pin;
+
+    /// Sends requests for the async `Client`, see `Transport`.
+    pub trait AsyncTransport: fmt::Debug + Send + Sync {
+        fn send<'a>(
+            &'a self, request: HttpRequest,
+        ) -> Pin<Box<dyn Future<Output = Result<HttpResponse>> + Send + 'a>>;
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct ReqwestAsyncTransport {
+        inner_client: reqwest::Client,
+    }
+
+    impl Default for ReqwestAsyncTransport {
+        fn default() -> Self {
+            ReqwestAsyncTransport {
+                inner_client: reqwest::Client::builder()
+                    .pool_idle_timeout(None)
+                    .build()
+                    .unwrap(),
+            }
+        }
+    }
+
+    impl ReqwestAsyncTransport {
+        pub fn new(inner_client: reqwest::Client) -> Self {
+            ReqwestAsyncTransport { inner_client }
+        }
+    }
+
+    impl AsyncTransport for ReqwestAsyncTransport {
+        fn send<'a>(
+            &'a self, request: HttpRequest,
+        ) -> Pin<Box<dyn Future<Output = Result<HttpResponse>> + Send + 'a>> {
+            Box::pin(async move {
+                let mut builder = self
+                    .inner_client
+                    .request(request.method, request.url.as_str())
+                    .headers(request.headers);
+                if let Some(body) = request.body {
+                    builder = builder.body(body);
+                }
+
+                let response = builder.send().await?;
+                let status = response.status();
+                let headers = response.headers().clone();
+                let body = response.text().await?;
+
+                Ok(HttpResponse {
+                    status,
+                    headers,
+                    body,
+                })
+            })
+        }
+    }
+}
@@ -7,13 +7,21 @@ use serde_json::Value;
 pub fn build_request(parameters: BTreeMap<String, String>) -> String {
     let mut request = String::new();
     for (key, value) in parameters {
-        let param = format!("{}={}&", key, value);
+        let param = format!("{}={}&", encode_param(&key), encode_param(&value));
         request.push_str(param.as_ref());
     }
     request.pop();
     request
 }
 
+/// Percent-encode a parameter key or value for a query string or form body.
+pub fn encode_param(value: &str) -> String {
+    // form_urlencoded writes spaces as '+', %20 cannot be mistaken for a literal '+'
+    url::form_urlencoded::byte_serialize(value.as_bytes())
+        .collect::<String>()
+        .replace('+', "%20")
+}
+
 pub fn build_signed_request(
     parameters: BTreeMap<String, String>, recv_window: u64,
 ) -> Result<String> {
@@ -41,7 +49,7 @@ pub fn to_f64(v: &Value) -> f64 {
     v.as_str().unwrap().parse().unwrap()
 }
 
-fn get_timestamp(start: SystemTime) -> Result<u64> {
+pub(crate) fn get_timestamp(start: SystemTime) -> Result<u64> {
     let since_epoch = start.duration_since(UNIX_EPOCH)?;
     Ok(since_epoch.as_secs() * 1000 + u64::from(since_epoch.subsec_nanos()) / 1_000_000)
 }
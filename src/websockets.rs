@@ -1,8 +1,9 @@
+use crate::cassette::{Cassette, Recorder};
 use crate::errors::Result;
 use crate::config::Config;
 use crate::model::{
     AccountUpdateEvent, AggrTradesEvent, BalanceUpdateEvent, BookTickerEvent, DayTickerEvent,
-    DepthOrderBookEvent, KlineEvent, OrderBook, OrderTradeEvent, TradeEvent,
+    DepthOrderBookEvent, KlineEvent, ListStatusEvent, OrderBook, OrderTradeEvent, TradeEvent,
 };
 use error_chain::bail;
 use url::Url;
@@ -41,6 +42,7 @@ pub enum WebsocketEvent {
     AccountUpdate(AccountUpdateEvent),
     BalanceUpdate(BalanceUpdateEvent),
     OrderTrade(OrderTradeEvent),
+    ListStatus(ListStatusEvent),
     AggrTrades(AggrTradesEvent),
     Trade(TradeEvent),
     OrderBook(OrderBook),
@@ -54,6 +56,7 @@ pub enum WebsocketEvent {
 pub struct WebSockets<'a> {
     pub socket: Option<(WebSocket<MaybeTlsStream<TcpStream>>, Response)>,
     handler: Box<dyn FnMut(WebsocketEvent) -> Result<()> + 'a>,
+    recorder: Option<Recorder>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -65,6 +68,7 @@ enum Events {
     BookTickerEvent(BookTickerEvent),
     AccountUpdateEvent(AccountUpdateEvent),
     OrderTradeEvent(OrderTradeEvent),
+    ListStatusEvent(ListStatusEvent),
     AggrTradesEvent(AggrTradesEvent),
     TradeEvent(TradeEvent),
     KlineEvent(KlineEvent),
@@ -80,9 +84,23 @@ impl<'a> WebSockets<'a> {
         WebSockets {
             socket: None,
             handler: Box::new(handler),
+            recorder: None,
         }
     }
 
+    /// Record every text frame received by `event_loop` in the recorder's cassette.
+    pub fn set_recorder(&mut self, recorder: Recorder) {
+        self.recorder = Some(recorder);
+    }
+
+    /// Feed the frames of `cassette` to the handler, without a connection.
+    pub fn replay(&mut self, cassette: &Cassette) -> Result<()> {
+        for frame in &cassette.frames {
+            self.handle_msg(frame)?;
+        }
+        Ok(())
+    }
+
     pub fn connect(&mut self, subscription: &str) -> Result<()> {
         self.connect_wss(&WebsocketAPI::Default.params(subscription))
     }
@@ -133,6 +151,7 @@ impl<'a> WebSockets<'a> {
                 Events::BalanceUpdateEvent(v) => WebsocketEvent::BalanceUpdate(v),
                 Events::AccountUpdateEvent(v) => WebsocketEvent::AccountUpdate(v),
                 Events::OrderTradeEvent(v) => WebsocketEvent::OrderTrade(v),
+                Events::ListStatusEvent(v) => WebsocketEvent::ListStatus(v),
                 Events::AggrTradesEvent(v) => WebsocketEvent::AggrTrades(v),
                 Events::TradeEvent(v) => WebsocketEvent::Trade(v),
                 Events::DayTickerEvent(v) => WebsocketEvent::DayTicker(v),
@@ -151,6 +170,9 @@ impl<'a> WebSockets<'a> {
                 let message = socket.0.read_message()?;
                 match message {
                     Message::Text(msg) => {
+                        if let Some(recorder) = &self.recorder {
+                            recorder.record_frame(&msg);
+                        }
                         if let Err(e) = self.handle_msg(&msg) {
                             bail!(format!("Error on handling stream message: {}", e));
                         }
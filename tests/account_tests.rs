@@ -1,7 +1,9 @@
 use binance::api::*;
 use binance::config::*;
 use binance::account::*;
+use binance::errors::{BinanceErrorCode, ErrorCategory, ErrorKind};
 use binance::model::*;
+use binance::websockets::*;
 
 #[cfg(test)]
 mod tests {
@@ -99,15 +101,15 @@ mod tests {
         assert_eq!(open_order.order_id, 1);
         assert_eq!(open_order.order_list_id, -1);
         assert_eq!(open_order.client_order_id, "myOrder1");
-        assert!(approx_eq!(f64, open_order.price, 0.1, ulps = 2));
+        assert_eq!(open_order.price, "0.1".parse::<Number>().unwrap());
         assert_eq!(open_order.orig_qty, "1.0");
         assert_eq!(open_order.executed_qty, "0.0");
         assert_eq!(open_order.cummulative_quote_qty, "0.0");
-        assert_eq!(open_order.status, "NEW");
-        assert_eq!(open_order.time_in_force, "GTC"); //Migrate to TimeInForce enum
-        assert_eq!(open_order.type_name, "LIMIT");
-        assert_eq!(open_order.side, "BUY");
-        assert!(approx_eq!(f64, open_order.stop_price, 0.0, ulps = 2));
+        assert_eq!(open_order.status, OrderStatus::New);
+        assert_eq!(open_order.time_in_force, TimeInForce::GTC);
+        assert_eq!(open_order.type_name, OrderType::Limit);
+        assert_eq!(open_order.side, OrderSide::Buy);
+        assert_eq!(open_order.stop_price, "0.0".parse::<Number>().unwrap());
         assert_eq!(open_order.iceberg_qty, "0.0");
         assert_eq!(open_order.time, 1499827319559);
         assert_eq!(open_order.update_time, 1499827319559);
@@ -139,15 +141,15 @@ mod tests {
         assert_eq!(open_order.order_id, 1);
         assert_eq!(open_order.order_list_id, -1);
         assert_eq!(open_order.client_order_id, "myOrder1");
-        assert!(approx_eq!(f64, open_order.price, 0.1, ulps = 2));
+        assert_eq!(open_order.price, "0.1".parse::<Number>().unwrap());
         assert_eq!(open_order.orig_qty, "1.0");
         assert_eq!(open_order.executed_qty, "0.0");
         assert_eq!(open_order.cummulative_quote_qty, "0.0");
-        assert_eq!(open_order.status, "NEW");
-        assert_eq!(open_order.time_in_force, "GTC"); //Migrate to TimeInForce enum
-        assert_eq!(open_order.type_name, "LIMIT");
-        assert_eq!(open_order.side, "BUY");
-        assert!(approx_eq!(f64, open_order.stop_price, 0.0, ulps = 2));
+        assert_eq!(open_order.status, OrderStatus::New);
+        assert_eq!(open_order.time_in_force, TimeInForce::GTC);
+        assert_eq!(open_order.type_name, OrderType::Limit);
+        assert_eq!(open_order.side, OrderSide::Buy);
+        assert_eq!(open_order.stop_price, "0.0".parse::<Number>().unwrap());
         assert_eq!(open_order.iceberg_qty, "0.0");
         assert_eq!(open_order.time, 1499827319559);
         assert_eq!(open_order.update_time, 1499827319559);
@@ -224,15 +226,15 @@ mod tests {
         assert_eq!(order_status.order_id, 1);
         assert_eq!(order_status.order_list_id, -1);
         assert_eq!(order_status.client_order_id, "myOrder1");
-        assert!(approx_eq!(f64, order_status.price, 0.1, ulps = 2));
+        assert_eq!(order_status.price, "0.1".parse::<Number>().unwrap());
         assert_eq!(order_status.orig_qty, "1.0");
         assert_eq!(order_status.executed_qty, "0.0");
         assert_eq!(order_status.cummulative_quote_qty, "0.0");
-        assert_eq!(order_status.status, "NEW");
-        assert_eq!(order_status.time_in_force, "GTC"); //Migrate to TimeInForce enum
-        assert_eq!(order_status.type_name, "LIMIT");
-        assert_eq!(order_status.side, "BUY");
-        assert!(approx_eq!(f64, order_status.stop_price, 0.0, ulps = 2));
+        assert_eq!(order_status.status, OrderStatus::New);
+        assert_eq!(order_status.time_in_force, TimeInForce::GTC);
+        assert_eq!(order_status.type_name, OrderType::Limit);
+        assert_eq!(order_status.side, OrderSide::Buy);
+        assert_eq!(order_status.stop_price, "0.0".parse::<Number>().unwrap());
         assert_eq!(order_status.iceberg_qty, "0.0");
         assert_eq!(order_status.time, 1499827319559);
         assert_eq!(order_status.update_time, 1499827319559);
@@ -241,6 +243,42 @@ mod tests {
     }
 
     #[test]
+    fn order_status_with_client_id() {
+        let mock_order_status = mock("GET", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "origClientOrderId=myStrategy-42&recvWindow=1234&symbol=LTCBTC&timestamp=\\d+"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/account/order_status_client_id.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order_status = account
+            .order_status_with_client_id("LTCBTC", "myStrategy-42".into())
+            .unwrap();
+
+        mock_order_status.assert();
+
+        assert_eq!(order_status.client_order_id, "myStrategy-42");
+        assert_eq!(order_status.status, OrderStatus::ExpiredInMatch);
+        assert_eq!(order_status.working_time, Some(1499827319559));
+        assert_eq!(
+            order_status.self_trade_prevention_mode,
+            Some(SelfTradePreventionMode::ExpireMaker)
+        );
+        assert_eq!(order_status.strategy_id, Some(42));
+        assert_eq!(order_status.strategy_type, Some(1000000));
+        assert_eq!(order_status.prevented_match_id, Some(0));
+        assert_eq!(order_status.prevented_quantity.unwrap(), "1.0");
+    }
+
+    #[test]
+    #[allow(deprecated)]
     fn test_order_status() {
         let mock_test_order_status = mock("GET", "/api/v3/order/test")
             .with_header("content-type", "application/json;charset=UTF-8")
@@ -273,7 +311,9 @@ mod tests {
             .set_recv_window(1234);
         let account: Account = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
-        let transaction: Transaction = account.limit_buy("LTCBTC", 1, 0.1).unwrap();
+        let transaction: Transaction = account
+            .limit_buy("LTCBTC", 1, "0.1".parse::<Number>().unwrap())
+            .unwrap();
 
         mock_limit_buy.assert();
 
@@ -282,19 +322,17 @@ mod tests {
         assert_eq!(transaction.order_list_id.unwrap(), -1);
         assert_eq!(transaction.client_order_id, "6gCrw2kRUAF9CvJDGP16IP");
         assert_eq!(transaction.transact_time, 1507725176595);
-        assert!(approx_eq!(f64, transaction.price, 0.1, ulps = 2));
-        assert!(approx_eq!(f64, transaction.orig_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(f64, transaction.executed_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(
-            f64,
+        assert_eq!(transaction.price, "0.1".parse::<Number>().unwrap());
+        assert_eq!(transaction.orig_qty, "1.0".parse::<Number>().unwrap());
+        assert_eq!(transaction.executed_qty, "1.0".parse::<Number>().unwrap());
+        assert_eq!(
             transaction.cummulative_quote_qty,
-            0.0,
-            ulps = 2
-        ));
-        assert_eq!(transaction.status, "NEW");
-        assert_eq!(transaction.time_in_force, "GTC"); //Migrate to TimeInForce enum
-        assert_eq!(transaction.type_name, "LIMIT");
-        assert_eq!(transaction.side, "BUY");
+            "0.0".parse::<Number>().unwrap()
+        );
+        assert_eq!(transaction.status, OrderStatus::New);
+        assert_eq!(transaction.time_in_force, TimeInForce::GTC);
+        assert_eq!(transaction.type_name, OrderType::Limit);
+        assert_eq!(transaction.side, OrderSide::Buy);
     }
 
     #[test]
@@ -310,7 +348,9 @@ mod tests {
             .set_recv_window(1234);
         let account: Account = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
-        account.test_limit_buy("LTCBTC", 1, 0.1).unwrap();
+        account
+            .test_limit_buy("LTCBTC", 1, "0.1".parse::<Number>().unwrap())
+            .unwrap();
 
         mock_test_limit_buy.assert();
     }
@@ -328,7 +368,9 @@ mod tests {
             .set_recv_window(1234);
         let account: Account = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
-        let transaction: Transaction = account.limit_sell("LTCBTC", 1, 0.1).unwrap();
+        let transaction: Transaction = account
+            .limit_sell("LTCBTC", 1, "0.1".parse::<Number>().unwrap())
+            .unwrap();
 
         mock_limit_sell.assert();
 
@@ -337,19 +379,17 @@ mod tests {
         assert_eq!(transaction.order_list_id.unwrap(), -1);
         assert_eq!(transaction.client_order_id, "6gCrw2kRUAF9CvJDGP16IP");
         assert_eq!(transaction.transact_time, 1507725176595);
-        assert!(approx_eq!(f64, transaction.price, 0.1, ulps = 2));
-        assert!(approx_eq!(f64, transaction.orig_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(f64, transaction.executed_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(
-            f64,
+        assert_eq!(transaction.price, "0.1".parse::<Number>().unwrap());
+        assert_eq!(transaction.orig_qty, "1.0".parse::<Number>().unwrap());
+        assert_eq!(transaction.executed_qty, "1.0".parse::<Number>().unwrap());
+        assert_eq!(
             transaction.cummulative_quote_qty,
-            0.0,
-            ulps = 2
-        ));
-        assert_eq!(transaction.status, "NEW");
-        assert_eq!(transaction.time_in_force, "GTC"); //Migrate to TimeInForce enum
-        assert_eq!(transaction.type_name, "LIMIT");
-        assert_eq!(transaction.side, "SELL");
+            "0.0".parse::<Number>().unwrap()
+        );
+        assert_eq!(transaction.status, OrderStatus::New);
+        assert_eq!(transaction.time_in_force, TimeInForce::GTC);
+        assert_eq!(transaction.type_name, OrderType::Limit);
+        assert_eq!(transaction.side, OrderSide::Sell);
     }
 
     #[test]
@@ -365,7 +405,9 @@ mod tests {
             .set_recv_window(1234);
         let account: Account = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
-        account.test_limit_sell("LTCBTC", 1, 0.1).unwrap();
+        account
+            .test_limit_sell("LTCBTC", 1, "0.1".parse::<Number>().unwrap())
+            .unwrap();
 
         mock_test_limit_sell.assert();
     }
@@ -395,19 +437,17 @@ mod tests {
         assert_eq!(transaction.order_list_id.unwrap(), -1);
         assert_eq!(transaction.client_order_id, "6gCrw2kRUAF9CvJDGP16IP");
         assert_eq!(transaction.transact_time, 1507725176595);
-        assert!(approx_eq!(f64, transaction.price, 0.1, ulps = 2));
-        assert!(approx_eq!(f64, transaction.orig_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(f64, transaction.executed_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(
-            f64,
+        assert_eq!(transaction.price, "0.1".parse::<Number>().unwrap());
+        assert_eq!(transaction.orig_qty, "1.0".parse::<Number>().unwrap());
+        assert_eq!(transaction.executed_qty, "1.0".parse::<Number>().unwrap());
+        assert_eq!(
             transaction.cummulative_quote_qty,
-            0.0,
-            ulps = 2
-        ));
-        assert_eq!(transaction.status, "NEW");
-        assert_eq!(transaction.time_in_force, "GTC"); //Migrate to TimeInForce enum
-        assert_eq!(transaction.type_name, "MARKET");
-        assert_eq!(transaction.side, "BUY");
+            "0.0".parse::<Number>().unwrap()
+        );
+        assert_eq!(transaction.status, OrderStatus::New);
+        assert_eq!(transaction.time_in_force, TimeInForce::GTC);
+        assert_eq!(transaction.type_name, OrderType::Market);
+        assert_eq!(transaction.side, OrderSide::Buy);
     }
 
     #[test]
@@ -444,7 +484,8 @@ mod tests {
             .set_recv_window(1234);
         let account: Account = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
-        match account.market_buy_using_quote_quantity("BNBBTC", 0.002) {
+        match account.market_buy_using_quote_quantity("BNBBTC", "0.002".parse::<Number>().unwrap())
+        {
             Ok(answer) => {
                 assert!(answer.order_id == 1);
             }
@@ -468,7 +509,7 @@ mod tests {
         let account: Account = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
         account
-            .test_market_buy_using_quote_quantity("BNBBTC", 0.002)
+            .test_market_buy_using_quote_quantity("BNBBTC", "0.002".parse::<Number>().unwrap())
             .unwrap();
 
         mock_test_market_buy_using_quote_quantity.assert();
@@ -499,19 +540,17 @@ mod tests {
         assert_eq!(transaction.order_list_id.unwrap(), -1);
         assert_eq!(transaction.client_order_id, "6gCrw2kRUAF9CvJDGP16IP");
         assert_eq!(transaction.transact_time, 1507725176595);
-        assert!(approx_eq!(f64, transaction.price, 0.1, ulps = 2));
-        assert!(approx_eq!(f64, transaction.orig_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(f64, transaction.executed_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(
-            f64,
+        assert_eq!(transaction.price, "0.1".parse::<Number>().unwrap());
+        assert_eq!(transaction.orig_qty, "1.0".parse::<Number>().unwrap());
+        assert_eq!(transaction.executed_qty, "1.0".parse::<Number>().unwrap());
+        assert_eq!(
             transaction.cummulative_quote_qty,
-            0.0,
-            ulps = 2
-        ));
-        assert_eq!(transaction.status, "NEW");
-        assert_eq!(transaction.time_in_force, "GTC"); //Migrate to TimeInForce enum
-        assert_eq!(transaction.type_name, "MARKET");
-        assert_eq!(transaction.side, "SELL");
+            "0.0".parse::<Number>().unwrap()
+        );
+        assert_eq!(transaction.status, OrderStatus::New);
+        assert_eq!(transaction.time_in_force, TimeInForce::GTC);
+        assert_eq!(transaction.type_name, OrderType::Market);
+        assert_eq!(transaction.side, OrderSide::Sell);
     }
 
     #[test]
@@ -548,7 +587,8 @@ mod tests {
             .set_recv_window(1234);
         let account: Account = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
-        match account.market_sell_using_quote_quantity("BNBBTC", 0.002) {
+        match account.market_sell_using_quote_quantity("BNBBTC", "0.002".parse::<Number>().unwrap())
+        {
             Ok(answer) => {
                 assert!(answer.order_id == 1);
             }
@@ -572,7 +612,7 @@ mod tests {
         let account: Account = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
         account
-            .test_market_sell_using_quote_quantity("BNBBTC", 0.002)
+            .test_market_sell_using_quote_quantity("BNBBTC", "0.002".parse::<Number>().unwrap())
             .unwrap();
 
         mock_test_market_sell_using_quote_quantity.assert();
@@ -592,7 +632,13 @@ mod tests {
         let account: Account = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
         let transaction: Transaction = account
-            .stop_limit_buy_order("LTCBTC", 1, 0.1, 0.09, TimeInForce::GTC)
+            .stop_limit_buy_order(
+                "LTCBTC",
+                1,
+                "0.1".parse::<Number>().unwrap(),
+                "0.09".parse::<Number>().unwrap(),
+                TimeInForce::GTC,
+            )
             .unwrap();
 
         mock_stop_limit_buy_order.assert();
@@ -602,20 +648,18 @@ mod tests {
         assert_eq!(transaction.order_list_id.unwrap(), -1);
         assert_eq!(transaction.client_order_id, "6gCrw2kRUAF9CvJDGP16IP");
         assert_eq!(transaction.transact_time, 1507725176595);
-        assert!(approx_eq!(f64, transaction.price, 0.1, ulps = 2));
-        assert!(approx_eq!(f64, transaction.orig_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(f64, transaction.executed_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(
-            f64,
+        assert_eq!(transaction.price, "0.1".parse::<Number>().unwrap());
+        assert_eq!(transaction.orig_qty, "1.0".parse::<Number>().unwrap());
+        assert_eq!(transaction.executed_qty, "1.0".parse::<Number>().unwrap());
+        assert_eq!(
             transaction.cummulative_quote_qty,
-            0.0,
-            ulps = 2
-        ));
-        assert!(approx_eq!(f64, transaction.stop_price, 0.09, ulps = 2));
-        assert_eq!(transaction.status, "NEW");
-        assert_eq!(transaction.time_in_force, "GTC"); //Migrate to TimeInForce enum
-        assert_eq!(transaction.type_name, "STOP_LOSS_LIMIT");
-        assert_eq!(transaction.side, "BUY");
+            "0.0".parse::<Number>().unwrap()
+        );
+        assert_eq!(transaction.stop_price, "0.09".parse::<Number>().unwrap());
+        assert_eq!(transaction.status, OrderStatus::New);
+        assert_eq!(transaction.time_in_force, TimeInForce::GTC);
+        assert_eq!(transaction.type_name, OrderType::StopLossLimit);
+        assert_eq!(transaction.side, OrderSide::Buy);
     }
 
     #[test]
@@ -632,7 +676,13 @@ mod tests {
         let account: Account = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
         account
-            .test_stop_limit_buy_order("LTCBTC", 1, 0.1, 0.09, TimeInForce::GTC)
+            .test_stop_limit_buy_order(
+                "LTCBTC",
+                1,
+                "0.1".parse::<Number>().unwrap(),
+                "0.09".parse::<Number>().unwrap(),
+                TimeInForce::GTC,
+            )
             .unwrap();
 
         mock_test_stop_limit_buy_order.assert();
@@ -652,7 +702,13 @@ mod tests {
         let account: Account = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
         let transaction: Transaction = account
-            .stop_limit_sell_order("LTCBTC", 1, 0.1, 0.09, TimeInForce::GTC)
+            .stop_limit_sell_order(
+                "LTCBTC",
+                1,
+                "0.1".parse::<Number>().unwrap(),
+                "0.09".parse::<Number>().unwrap(),
+                TimeInForce::GTC,
+            )
             .unwrap();
 
         mock_stop_limit_sell_order.assert();
@@ -662,20 +718,18 @@ mod tests {
         assert_eq!(transaction.order_list_id.unwrap(), -1);
         assert_eq!(transaction.client_order_id, "6gCrw2kRUAF9CvJDGP16IP");
         assert_eq!(transaction.transact_time, 1507725176595);
-        assert!(approx_eq!(f64, transaction.price, 0.1, ulps = 2));
-        assert!(approx_eq!(f64, transaction.orig_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(f64, transaction.executed_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(
-            f64,
+        assert_eq!(transaction.price, "0.1".parse::<Number>().unwrap());
+        assert_eq!(transaction.orig_qty, "1.0".parse::<Number>().unwrap());
+        assert_eq!(transaction.executed_qty, "1.0".parse::<Number>().unwrap());
+        assert_eq!(
             transaction.cummulative_quote_qty,
-            0.0,
-            ulps = 2
-        ));
-        assert!(approx_eq!(f64, transaction.stop_price, 0.09, ulps = 2));
-        assert_eq!(transaction.status, "NEW");
-        assert_eq!(transaction.time_in_force, "GTC"); //Migrate to TimeInForce enum
-        assert_eq!(transaction.type_name, "STOP_LOSS_LIMIT");
-        assert_eq!(transaction.side, "SELL");
+            "0.0".parse::<Number>().unwrap()
+        );
+        assert_eq!(transaction.stop_price, "0.09".parse::<Number>().unwrap());
+        assert_eq!(transaction.status, OrderStatus::New);
+        assert_eq!(transaction.time_in_force, TimeInForce::GTC);
+        assert_eq!(transaction.type_name, OrderType::StopLossLimit);
+        assert_eq!(transaction.side, OrderSide::Sell);
     }
 
     #[test]
@@ -692,7 +746,13 @@ mod tests {
         let account: Account = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
         account
-            .test_stop_limit_sell_order("LTCBTC", 1, 0.1, 0.09, TimeInForce::GTC)
+            .test_stop_limit_sell_order(
+                "LTCBTC",
+                1,
+                "0.1".parse::<Number>().unwrap(),
+                "0.09".parse::<Number>().unwrap(),
+                TimeInForce::GTC,
+            )
             .unwrap();
 
         mock_test_stop_limit_sell_order.assert();
@@ -715,7 +775,7 @@ mod tests {
             .custom_order(
                 "LTCBTC",
                 1,
-                0.1,
+                "0.1".parse::<Number>().unwrap(),
                 None,
                 OrderSide::Buy,
                 OrderType::Market,
@@ -731,20 +791,18 @@ mod tests {
         assert_eq!(transaction.order_list_id.unwrap(), -1);
         assert_eq!(transaction.client_order_id, "6gCrw2kRUAF9CvJDGP16IP");
         assert_eq!(transaction.transact_time, 1507725176595);
-        assert!(approx_eq!(f64, transaction.price, 0.1, ulps = 2));
-        assert!(approx_eq!(f64, transaction.orig_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(f64, transaction.executed_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(
-            f64,
+        assert_eq!(transaction.price, "0.1".parse::<Number>().unwrap());
+        assert_eq!(transaction.orig_qty, "1.0".parse::<Number>().unwrap());
+        assert_eq!(transaction.executed_qty, "1.0".parse::<Number>().unwrap());
+        assert_eq!(
             transaction.cummulative_quote_qty,
-            0.0,
-            ulps = 2
-        ));
-        assert!(approx_eq!(f64, transaction.stop_price, 0.09, ulps = 2));
-        assert_eq!(transaction.status, "NEW");
-        assert_eq!(transaction.time_in_force, "GTC"); //Migrate to TimeInForce enum
-        assert_eq!(transaction.type_name, "STOP_LOSS_LIMIT");
-        assert_eq!(transaction.side, "SELL");
+            "0.0".parse::<Number>().unwrap()
+        );
+        assert_eq!(transaction.stop_price, "0.09".parse::<Number>().unwrap());
+        assert_eq!(transaction.status, OrderStatus::New);
+        assert_eq!(transaction.time_in_force, TimeInForce::GTC);
+        assert_eq!(transaction.type_name, OrderType::StopLossLimit);
+        assert_eq!(transaction.side, OrderSide::Sell);
     }
 
     #[test]
@@ -764,7 +822,7 @@ mod tests {
             .test_custom_order(
                 "LTCBTC",
                 1,
-                0.1,
+                "0.1".parse::<Number>().unwrap(),
                 None,
                 OrderSide::Buy,
                 OrderType::Market,
@@ -801,6 +859,31 @@ mod tests {
         assert_eq!(cancelled_order.client_order_id.unwrap(), "cancelMyOrder1");
     }
 
+    #[test]
+    fn cancel_order_with_client_id() {
+        let mock_cancel_order = mock("DELETE", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "origClientOrderId=myOrder1&recvWindow=1234&symbol=LTCBTC&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/cancel_order.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let cancelled_order = account
+            .cancel_order_with_client_id("LTCBTC", "myOrder1".into())
+            .unwrap();
+
+        mock_cancel_order.assert();
+
+        assert_eq!(cancelled_order.orig_client_order_id.unwrap(), "myOrder1");
+        assert_eq!(cancelled_order.order_id.unwrap(), 4);
+    }
+
     #[test]
     fn test_cancel_order() {
         let mock_test_cancel_order = mock("DELETE", "/api/v3/order/test")
@@ -844,9 +927,10 @@ mod tests {
 
         let history: TradeHistory = histories[0].clone();
 
+        assert_eq!(history.symbol, "BNBBTC");
         assert_eq!(history.id, 28457);
-        assert!(approx_eq!(f64, history.price, 4.00000100, ulps = 2));
-        assert!(approx_eq!(f64, history.qty, 12.00000000, ulps = 2));
+        assert_eq!(history.price, "4.00000100".parse::<Number>().unwrap());
+        assert_eq!(history.qty, "12.00000000".parse::<Number>().unwrap());
         assert_eq!(history.commission, "10.10000000");
         assert_eq!(history.commission_asset, "BNB");
         assert_eq!(history.time, 1499865549590);
@@ -854,4 +938,635 @@ mod tests {
         assert!(!history.is_maker);
         assert!(history.is_best_match);
     }
+
+    #[test]
+    fn place_order() {
+        let mock_place_order = mock("POST", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("icebergQty=0.2&newOrderRespType=FULL&price=0.1&quantity=1&recvWindow=1234&selfTradePreventionMode=EXPIRE_TAKER&side=SELL&stopPrice=0.09&strategyId=7&symbol=LTCBTC&timeInForce=GTC&timestamp=\\d+&type=STOP_LOSS_LIMIT".into()))
+            .with_body_from_file("tests/mocks/account/stop_limit_sell.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = SpotOrderRequest::new("LTCBTC", OrderSide::Sell, OrderType::StopLossLimit)
+            .set_quantity(1)
+            .set_price("0.1".parse::<Number>().unwrap())
+            .set_stop_price("0.09".parse::<Number>().unwrap())
+            .set_time_in_force(TimeInForce::GTC)
+            .set_iceberg_qty("0.2".parse::<Number>().unwrap())
+            .set_strategy_id(7)
+            .set_new_order_resp_type(NewOrderRespType::FULL)
+            .set_self_trade_prevention_mode(SelfTradePreventionMode::ExpireTaker);
+        let transaction = account.place_order(order).unwrap();
+
+        mock_place_order.assert();
+
+        assert_eq!(transaction.order_id, 1);
+        assert_eq!(transaction.type_name, OrderType::StopLossLimit);
+    }
+
+    #[test]
+    fn place_order_ack() {
+        let mock_place_order = mock("POST", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("newClientOrderId=6gCrw2kRUAF9CvJDGP16IP&newOrderRespType=ACK&quoteOrderQty=10&recvWindow=1234&side=BUY&symbol=LTCBTC&timestamp=\\d+&type=MARKET".into()))
+            .with_body_from_file("tests/mocks/account/place_order_ack.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = SpotOrderRequest::new("LTCBTC", OrderSide::Buy, OrderType::Market)
+            .set_quote_order_qty(10)
+            .set_new_client_order_id("6gCrw2kRUAF9CvJDGP16IP");
+        let ack = account.place_order_ack(order.clone()).unwrap();
+
+        mock_place_order.assert();
+
+        assert_eq!(ack.order_id, 28);
+        assert_eq!(ack.client_order_id, "6gCrw2kRUAF9CvJDGP16IP");
+        assert!(account
+            .place_order(order.set_new_order_resp_type(NewOrderRespType::ACK))
+            .is_err());
+    }
+
+    #[test]
+    fn test_place_order() {
+        let mock_test_place_order = mock("POST", "/api/v3/order/test")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "price=0.1&quantity=1&recvWindow=1234&side=BUY&symbol=LTCBTC&timestamp=\\d+&type=LIMIT_MAKER".into(),
+            ))
+            .with_body("{}")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = SpotOrderRequest::new("LTCBTC", OrderSide::Buy, OrderType::LimitMaker)
+            .set_quantity(1)
+            .set_price("0.1".parse::<Number>().unwrap());
+        account.test_place_order(order).unwrap();
+
+        mock_test_place_order.assert();
+    }
+
+    #[test]
+    fn validate_spot_order() {
+        let limit = SpotOrderRequest::new("LTCBTC", OrderSide::Buy, OrderType::Limit)
+            .set_quantity(1)
+            .set_price("0.1".parse::<Number>().unwrap());
+        assert!(limit.validate().is_err());
+        assert!(limit
+            .clone()
+            .set_time_in_force(TimeInForce::GTC)
+            .validate()
+            .is_ok());
+        assert!(limit
+            .set_time_in_force(TimeInForce::IOC)
+            .set_iceberg_qty("0.5".parse::<Number>().unwrap())
+            .validate()
+            .is_err());
+
+        let market = SpotOrderRequest::new("LTCBTC", OrderSide::Sell, OrderType::Market);
+        assert!(market.validate().is_err());
+        assert!(market.clone().set_quantity(1).validate().is_ok());
+        assert!(market
+            .clone()
+            .set_quantity(1)
+            .set_price("0.1".parse::<Number>().unwrap())
+            .validate()
+            .is_err());
+        assert!(market
+            .set_quantity(1)
+            .set_quote_order_qty(1)
+            .validate()
+            .is_err());
+
+        let stop =
+            SpotOrderRequest::new("LTCBTC", OrderSide::Sell, OrderType::StopLoss).set_quantity(1);
+        assert!(stop.validate().is_err());
+        assert!(stop.clone().set_trailing_delta(100).validate().is_ok());
+        assert!(stop
+            .set_stop_price("0.09".parse::<Number>().unwrap())
+            .validate()
+            .is_ok());
+
+        let futures_only = SpotOrderRequest::new("LTCBTC", OrderSide::Sell, OrderType::StopMarket)
+            .set_quantity(1)
+            .set_stop_price("0.09".parse::<Number>().unwrap());
+        assert!(futures_only.validate().is_err());
+    }
+
+    #[test]
+    fn place_oco() {
+        let mock_place_oco = mock("POST", "/api/v3/order/oco")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("listClientOrderId=JYVpp3F0f5CAG15DhtrqLp&price=0.12&quantity=0.624363&recvWindow=1234&side=SELL&stopLimitPrice=0.085&stopLimitTimeInForce=GTC&stopPrice=0.09&symbol=LTCBTC&timestamp=\\d+".into()))
+            .with_body_from_file("tests/mocks/account/oco.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = OcoOrderRequest::new(
+            "LTCBTC",
+            OrderSide::Sell,
+            "0.624363".parse::<Number>().unwrap(),
+            "0.12".parse::<Number>().unwrap(),
+        )
+        .set_stop_price("0.09".parse::<Number>().unwrap())
+        .set_stop_limit("0.085".parse::<Number>().unwrap(), TimeInForce::GTC)
+        .set_list_client_order_id("JYVpp3F0f5CAG15DhtrqLp");
+        let order_list = account.place_oco(order).unwrap();
+
+        mock_place_oco.assert();
+
+        assert_eq!(order_list.order_list_id, 0);
+        assert_eq!(order_list.contingency_type, ContingencyType::OCO);
+        assert_eq!(order_list.list_status_type, ListStatusType::ExecStarted);
+        assert_eq!(order_list.list_order_status, ListOrderStatus::Executing);
+        assert_eq!(order_list.orders.len(), 2);
+        assert_eq!(order_list.order_reports.len(), 2);
+        assert_eq!(order_list.order_reports[0].type_name, OrderType::StopLoss);
+        assert_eq!(
+            order_list.order_reports[0].stop_price,
+            "0.09".parse::<Number>().unwrap()
+        );
+        assert_eq!(
+            order_list.order_reports[1].price,
+            "0.12".parse::<Number>().unwrap()
+        );
+    }
+
+    #[test]
+    fn place_order_list_oco() {
+        let mock_place_oco = mock("POST", "/api/v3/orderList/oco")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("abovePrice=0.12&aboveType=LIMIT_MAKER&belowStopPrice=0.09&belowTrailingDelta=100&belowType=STOP_LOSS&quantity=0.624363&recvWindow=1234&side=SELL&symbol=LTCBTC&timestamp=\\d+".into()))
+            .with_body_from_file("tests/mocks/account/oco.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = OrderListOcoRequest::new(
+            "LTCBTC",
+            OrderSide::Sell,
+            "0.624363".parse::<Number>().unwrap(),
+            OcoLeg::new(OrderType::LimitMaker).set_price("0.12".parse::<Number>().unwrap()),
+            OcoLeg::new(OrderType::StopLoss)
+                .set_stop_price("0.09".parse::<Number>().unwrap())
+                .set_trailing_delta(100),
+        );
+        let order_list = account.place_order_list_oco(order.clone()).unwrap();
+
+        mock_place_oco.assert();
+
+        assert_eq!(order_list.orders[1].order_id, 3);
+
+        // A LIMIT leg is not allowed, and a LIMIT_MAKER leg needs a price
+        let mut invalid = order.clone();
+        invalid.above.order_type = OrderType::Limit;
+        assert!(account.place_order_list_oco(invalid).is_err());
+        let mut invalid = order;
+        invalid.above.price = None;
+        assert!(invalid.validate().is_err());
+    }
+
+    #[test]
+    fn cancel_replace_order() {
+        let mock_cancel_replace = mock("POST", "/api/v3/order/cancelReplace")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("cancelOrderId=9&cancelReplaceMode=STOP_ON_FAILURE&cancelRestrictions=ONLY_NEW&price=0.02&quantity=0.04&recvWindow=1234&side=BUY&symbol=BTCUSDT&timeInForce=GTC&timestamp=\\d+&type=LIMIT".into()))
+            .with_body_from_file("tests/mocks/account/cancel_replace.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = SpotOrderRequest::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+            .set_quantity("0.04".parse::<Number>().unwrap())
+            .set_price("0.02".parse::<Number>().unwrap())
+            .set_time_in_force(TimeInForce::GTC);
+        let request = CancelReplaceRequest::new(order, CancelReplaceMode::StopOnFailure)
+            .set_cancel_order_id(9)
+            .set_cancel_restrictions(CancelRestrictions::OnlyNew);
+        let response = account.cancel_replace_order(request.clone()).unwrap();
+
+        mock_cancel_replace.assert();
+
+        assert!(response.is_success());
+        assert!(response.leg_error().is_none());
+        let canceled = response.cancel_response.unwrap();
+        assert_eq!(canceled.ok().unwrap().status, OrderStatus::Canceled);
+        let placed = response.new_order_response.unwrap();
+        assert_eq!(placed.ok().unwrap().order_id, 10);
+
+        // The order to cancel must be identified exactly once
+        let both = request.set_cancel_orig_client_order_id("DnLo3vTAQcjha43lAZhZ0y");
+        assert!(both.validate().is_err());
+    }
+
+    #[test]
+    fn cancel_replace_order_failures() {
+        let mock_partial_failure = mock("POST", "/api/v3/order/cancelReplace")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_status(409)
+            .match_query(Matcher::Regex(
+                "cancelOrigClientOrderId=DnLo3vTAQcjha43lAZhZ0y&cancelReplaceMode=ALLOW_FAILURE"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/account/cancel_replace_partial.json")
+            .create();
+        let mock_failure = mock("POST", "/api/v3/order/cancelReplace")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_status(400)
+            .match_query(Matcher::Regex(
+                "cancelOrderId=404&cancelReplaceMode=STOP_ON_FAILURE".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/cancel_replace_failed.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = SpotOrderRequest::new("BTCUSDT", OrderSide::Buy, OrderType::LimitMaker)
+            .set_quantity("0.04".parse::<Number>().unwrap())
+            .set_price("0.02".parse::<Number>().unwrap());
+
+        // The cancel went through, the new order was rejected
+        let request = CancelReplaceRequest::new(order.clone(), CancelReplaceMode::AllowFailure)
+            .set_cancel_orig_client_order_id("DnLo3vTAQcjha43lAZhZ0y");
+        let error = account.cancel_replace_order(request).unwrap_err();
+        mock_partial_failure.assert();
+        match error.kind() {
+            ErrorKind::CancelReplaceFailed(response, legs) => {
+                assert_eq!(response.code, -2021);
+                assert_eq!(legs.cancel_result, CancelReplaceResult::Success);
+                assert_eq!(legs.new_order_result, CancelReplaceResult::Failure);
+                assert_eq!(legs.leg_error().unwrap().code, -2010);
+            }
+            _ => panic!("Unexpected error: {:?}", error),
+        }
+        assert_eq!(error.category(), ErrorCategory::OrderRejected);
+        assert_eq!(
+            error.error_code(),
+            Some(BinanceErrorCode::CancelReplacePartiallyFailed)
+        );
+
+        // The cancel failed, the new order was not attempted
+        let request = CancelReplaceRequest::new(order, CancelReplaceMode::StopOnFailure)
+            .set_cancel_order_id(404);
+        let error = account.cancel_replace_order(request).unwrap_err();
+        mock_failure.assert();
+        match error.kind() {
+            ErrorKind::CancelReplaceFailed(_, legs) => {
+                assert_eq!(legs.new_order_result, CancelReplaceResult::NotAttempted);
+                assert!(legs.new_order_response.is_none());
+            }
+            _ => panic!("Unexpected error: {:?}", error),
+        }
+        assert_eq!(error.category(), ErrorCategory::UnknownOrder);
+    }
+
+    #[test]
+    fn amend_order_keep_priority() {
+        let mock_amend = mock("PUT", "/api/v3/order/amend/keepPriority")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "newClientOrderId=PFaq6hIHxqFENGfdtn4J6Q&newQty=5&orderId=33&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/order_amend.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let request = OrderAmendRequest::new("BTCUSDT", 33, 5)
+            .set_new_client_order_id("PFaq6hIHxqFENGfdtn4J6Q");
+        let amendment = account.amend_order_keep_priority(request).unwrap();
+
+        mock_amend.assert();
+
+        assert_eq!(amendment.execution_id, 75);
+        assert!(amendment.list_status.is_none());
+        let order = amendment.amended_order;
+        assert_eq!(order.order_id, 33);
+        assert_eq!(order.qty, "5.0".parse::<Number>().unwrap());
+        assert_eq!(order.working_time, Some(1741926410242));
+        assert_eq!(
+            order.self_trade_prevention_mode,
+            Some(SelfTradePreventionMode::None)
+        );
+    }
+
+    #[test]
+    fn amend_order_with_client_id() {
+        let mock_amend = mock("PUT", "/api/v3/order/amend/keepPriority")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "newQty=5&origClientOrderId=5xrgbMyg6z36NzBn2pbT8H&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/order_amend.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let request = OrderAmendRequest::with_client_id("BTCUSDT", "5xrgbMyg6z36NzBn2pbT8H", 5);
+        let amendment = account.amend_order_keep_priority(request).unwrap();
+
+        mock_amend.assert();
+
+        assert_eq!(
+            amendment.amended_order.orig_client_order_id,
+            "5xrgbMyg6z36NzBn2pbT8H"
+        );
+    }
+
+    #[test]
+    fn amend_order_validation() {
+        let mock_amend = mock("PUT", "/api/v3/order/amend/keepPriority")
+            .match_query(Matcher::Any)
+            .expect(0)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let unidentified = OrderAmendRequest {
+            order_id: None,
+            ..OrderAmendRequest::new("BTCUSDT", 12, 5)
+        };
+        assert!(account.amend_order_keep_priority(unidentified).is_err());
+        let empty = OrderAmendRequest::new("BTCUSDT", 12, 0);
+        assert!(account.amend_order_keep_priority(empty).is_err());
+
+        mock_amend.assert();
+    }
+
+    #[test]
+    fn cancel_order_list() {
+        let mock_cancel_order_list = mock("DELETE", "/api/v3/orderList")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "orderListId=27&recvWindow=1234&symbol=LTCBTC&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/order_list.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order_list = account.cancel_order_list("LTCBTC", 27).unwrap();
+
+        mock_cancel_order_list.assert();
+
+        assert_eq!(order_list.order_list_id, 27);
+        assert!(order_list.order_reports.is_empty());
+    }
+
+    #[test]
+    fn order_list_status() {
+        let mock_order_list_status = mock("GET", "/api/v3/orderList")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "origClientOrderId=h2USkA5YQpaXHPIrkd96xE&recvWindow=1234&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/order_list.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order_list = account
+            .order_list_status_with_client_id("h2USkA5YQpaXHPIrkd96xE".into())
+            .unwrap();
+
+        mock_order_list_status.assert();
+
+        assert_eq!(order_list.list_client_order_id, "h2USkA5YQpaXHPIrkd96xE");
+        assert_eq!(order_list.symbol, "LTCBTC");
+    }
+
+    #[test]
+    fn get_all_orders() {
+        let mock_all_orders = mock("GET", "/api/v3/allOrders")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "endTime=1499903999999&limit=500&recvWindow=1234&startTime=1499817600000&symbol=BNBBTC&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/all_orders.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let request = OrderHistoryRequest::new("BNBBTC")
+            .set_start_time(1499817600000)
+            .set_end_time(1499903999999)
+            .set_limit(500);
+        let orders = account.get_all_orders(request).unwrap();
+
+        mock_all_orders.assert();
+
+        assert_eq!(orders.len(), 2);
+        assert_eq!(orders[0].order_id, 1);
+        assert_eq!(orders[0].status, OrderStatus::Filled);
+        assert_eq!(orders[1].status, OrderStatus::Canceled);
+        assert_eq!(orders[0].cummulative_quote_qty, "0.1");
+    }
+
+    #[test]
+    fn all_orders_iter_pages_by_id() {
+        let mock_first_page = mock("GET", "/api/v3/allOrders")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "limit=2&orderId=1&recvWindow=1234&symbol=BNBBTC&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/all_orders.json")
+            .create();
+        let mock_next_page = mock("GET", "/api/v3/allOrders")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "limit=2&orderId=3&recvWindow=1234&symbol=BNBBTC&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/all_orders_next.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let request = OrderHistoryRequest::new("BNBBTC")
+            .set_order_id(1)
+            .set_limit(2);
+        let orders: Vec<Order> = account
+            .all_orders_iter(request)
+            .collect::<binance::errors::Result<_>>()
+            .unwrap();
+
+        mock_first_page.assert();
+        mock_next_page.assert();
+
+        let ids: Vec<u64> = orders.iter().map(|order| order.order_id).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn my_trades_iter_pages_by_time() {
+        let mock_first_day = mock("GET", "/api/v3/myTrades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "endTime=1499903999999&limit=1000&recvWindow=1234&startTime=1499817600000&symbol=BNBBTC&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/trade_history.json")
+            .create();
+        let mock_second_day = mock("GET", "/api/v3/myTrades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "endTime=1499950000000&limit=1000&recvWindow=1234&startTime=1499904000000&symbol=BNBBTC&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/trade_history.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let request = TradeHistoryRequest::new("BNBBTC")
+            .set_start_time(1499817600000)
+            .set_end_time(1499950000000);
+        let trades: Vec<TradeHistory> = account
+            .my_trades_iter(request)
+            .collect::<binance::errors::Result<_>>()
+            .unwrap();
+
+        mock_first_day.assert();
+        mock_second_day.assert();
+
+        // The second window returns the same trade again, it is only yielded once
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].order_id, 100234);
+        assert_eq!(trades[0].order_list_id, -1);
+        assert_eq!(trades[0].quote_qty, "48.000012".parse::<Number>().unwrap());
+    }
+
+    #[test]
+    fn my_trades_iter_id_over_start_time() {
+        let mock_from_id = mock("GET", "/api/v3/myTrades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^fromId=28457&limit=1000&recvWindow=1234&symbol=BNBBTC&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/trade_history.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        // The trade ID wins, the start time after the trade is not sent
+        let request = TradeHistoryRequest::new("BNBBTC")
+            .set_from_id(28457)
+            .set_start_time(1499900000000)
+            .set_end_time(1499950000000);
+        let trades: Vec<TradeHistory> = account
+            .my_trades_iter(request)
+            .collect::<binance::errors::Result<_>>()
+            .unwrap();
+
+        mock_from_id.assert();
+
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].id, 28457);
+    }
+
+    #[test]
+    fn get_all_order_lists() {
+        let mock_all_order_lists = mock("GET", "/api/v3/allOrderList")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "fromId=28&limit=2&recvWindow=1234&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/all_order_lists.json")
+            .create();
+        let mock_open_order_lists = mock("GET", "/api/v3/openOrderList")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("recvWindow=1234&timestamp=\\d+".into()))
+            .with_body("[]")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order_lists = account
+            .get_all_order_lists(Some(28), None, None, 2)
+            .unwrap();
+        let open_order_lists = account.get_open_order_lists().unwrap();
+
+        mock_all_order_lists.assert();
+        mock_open_order_lists.assert();
+
+        assert_eq!(order_lists.len(), 2);
+        assert_eq!(order_lists[1].list_status_type, ListStatusType::AllDone);
+        assert!(open_order_lists.is_empty());
+    }
+
+    #[test]
+    fn list_status_event() {
+        let msg = r#"{"e":"listStatus","E":1564035303637,"s":"ETHBTC","g":2,"c":"OCO","l":"EXEC_STARTED","L":"EXECUTING","r":"NONE","C":"F4QN4G8DlFATFlIUQ0cjdD","T":1564035303625,"O":[{"s":"ETHBTC","i":17,"c":"AJYsMjErWJesZvqlJCTUgL"},{"s":"ETHBTC","i":18,"c":"bfYPSQdLoqAJeNrOr9adzq"}]}"#;
+
+        let mut events = Vec::new();
+        let mut web_socket = WebSockets::new(|event: WebsocketEvent| {
+            events.push(event);
+            Ok(())
+        });
+        web_socket.test_handle_msg(msg).unwrap();
+        drop(web_socket);
+
+        match &events[..] {
+            [WebsocketEvent::ListStatus(event)] => {
+                assert_eq!(event.order_list_id, 2);
+                assert_eq!(event.contingency_type, ContingencyType::OCO);
+                assert_eq!(event.list_order_status, ListOrderStatus::Executing);
+                assert_eq!(event.orders[1].order_id, 18);
+            }
+            _ => panic!("Unexpected events"),
+        }
+    }
 }
@@ -0,0 +1,106 @@
+use binance::api::*;
+use binance::config::*;
+use binance::account::*;
+use binance::market::*;
+use binance::cassette::*;
+use binance::model::*;
+use binance::transport::ReqwestTransport;
+use binance::websockets::*;
+use std::time::Duration;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+
+    #[test]
+    fn record_and_replay_rest() {
+        let mock_get_account = mock("GET", "/api/v3/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_header("x-mbx-used-weight-1m", "10")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/get_account.json")
+            .create();
+
+        let recorder = Recorder::new(ReqwestTransport::default());
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234)
+            .set_transport(recorder.clone());
+        let account: Account =
+            Binance::new_with_config(Some("api-key".into()), Some("secret".into()), &config);
+        let recorded = account.get_account().unwrap();
+        mock_get_account.assert();
+
+        let path = std::env::temp_dir().join("binance_record_and_replay_rest.json");
+        recorder.save(&path).unwrap();
+        let saved = std::fs::read_to_string(&path).unwrap();
+        assert!(saved.contains("signature=REDACTED"));
+        assert!(!saved.contains("api-key"));
+
+        let cassette = Cassette::load(&path).unwrap();
+        assert_eq!(cassette.interactions.len(), 1);
+        assert_eq!(cassette.interactions[0].method, "GET");
+        assert!(cassette.interactions[0]
+            .url
+            .starts_with("/api/v3/account?recvWindow=1234&timestamp="));
+        std::fs::remove_file(&path).unwrap();
+
+        // Nothing listens on this host
+        let config = Config::default()
+            .set_rest_api_endpoint("http://127.0.0.1:1")
+            .set_recv_window(1234)
+            .set_transport(Replayer::new(cassette));
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let replayed = account.get_account().unwrap();
+
+        assert_eq!(replayed.balances.len(), recorded.balances.len());
+        assert_eq!(
+            account
+                .client
+                .rate_limiter()
+                .used_weight(Duration::from_secs(60)),
+            Some(10)
+        );
+        // Every interaction is served once
+        assert!(account.get_account().is_err());
+    }
+
+    #[test]
+    fn replay_requires_matching_request() {
+        let config = Config::default()
+            .set_transport(Replayer::load("tests/mocks/cassettes/trades.json").unwrap());
+        let market: Market = Binance::new_with_config(None, None, &config);
+
+        let err = market.get_price("LTCBTC").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "No recorded response for GET /api/v3/ticker/price?symbol=LTCBTC"
+        );
+
+        let price = market.get_price("BNBBTC").unwrap();
+        assert_eq!(price.price, "0.001".parse::<Number>().unwrap());
+    }
+
+    #[test]
+    fn replay_websocket_frames() {
+        let cassette = Cassette::load("tests/mocks/cassettes/trades.json").unwrap();
+        let mut trades: Vec<TradeEvent> = Vec::new();
+
+        let mut web_socket = WebSockets::new(|event: WebsocketEvent| {
+            if let WebsocketEvent::Trade(trade) = event {
+                trades.push(trade);
+            }
+            Ok(())
+        });
+        web_socket.replay(&cassette).unwrap();
+        drop(web_socket);
+
+        assert_eq!(trades.len(), 2);
+        assert_eq!(trades[0].trade_id, 12345);
+        assert_eq!(trades[1].trade_id, 12346);
+        assert_eq!(trades[1].qty, "50");
+    }
+}
@@ -0,0 +1,87 @@
+#![cfg(feature = "decimal")]
+
+use binance::api::*;
+use binance::config::*;
+use binance::account::*;
+use binance::market::*;
+use binance::model::*;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+
+    #[test]
+    fn prices_are_exact() {
+        let mock_get_price = mock("GET", "/api/v3/ticker/price")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Exact("symbol=LTCBTC".into()))
+            .with_body_from_file("tests/mocks/market/get_price.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(mockito::server_url());
+        let market: Market = Binance::new_with_config(None, None, &config);
+        let price = market.get_price("LTCBTC").unwrap();
+        mock_get_price.assert();
+
+        assert_eq!(price.price, Decimal::new(4000002, 6));
+        // Formatted back as Binance sent it
+        assert_eq!(price.price.to_string(), "4.00000200");
+    }
+
+    #[test]
+    fn order_parameters_are_exact() {
+        let mock_limit_buy = mock("POST", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "price=0.30000000&quantity=0.00012345&recvWindow=1234&side=BUY&symbol=LTCBTC&timeInForce=GTC&timestamp=\\d+&type=LIMIT".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/limit_buy.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let transaction = account
+            .limit_buy(
+                "LTCBTC",
+                Decimal::from_str("0.00012345").unwrap(),
+                Decimal::from_str("0.30000000").unwrap(),
+            )
+            .unwrap();
+        mock_limit_buy.assert();
+
+        assert_eq!(transaction.price, Decimal::new(1, 1));
+        assert_eq!(transaction.orig_qty, Decimal::ONE);
+    }
+
+    #[test]
+    fn json_numbers_and_strings() {
+        let price: SymbolPrice =
+            serde_json::from_str(r#"{"symbol":"LTCBTC","price":0.1}"#).unwrap();
+        assert_eq!(price.price, Decimal::new(1, 1));
+
+        let price: SymbolPrice =
+            serde_json::from_str(r#"{"symbol":"LTCBTC","price":"0.00000001"}"#).unwrap();
+        assert_eq!(price.price, Decimal::new(1, 8));
+        assert_eq!(
+            serde_json::to_string(&price).unwrap(),
+            r#"{"symbol":"LTCBTC","price":"0.00000001"}"#
+        );
+
+        // Not the binary expansion of the nearest f64
+        let price: SymbolPrice =
+            serde_json::from_str(r#"{"symbol":"LTCBTC","price":12345.67890123}"#).unwrap();
+        assert_eq!(price.price, Decimal::from_str("12345.67890123").unwrap());
+        assert_eq!(price.price.to_string(), "12345.67890123");
+    }
+
+    #[test]
+    fn infinity_is_an_error() {
+        let price = serde_json::from_str::<SymbolPrice>(r#"{"symbol":"LTCBTC","price":"INF"}"#);
+        assert!(price.is_err());
+    }
+}
@@ -0,0 +1,122 @@
+use binance::api::*;
+use binance::config::*;
+use binance::model::Number;
+use binance::account::*;
+use binance::general::*;
+use binance::errors::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+
+    #[test]
+    fn order_rejected_insufficient_balance() {
+        let body =
+            r#"{"code":-2010,"msg":"Account has insufficient balance for requested action."}"#;
+        let mock_limit_buy = mock("POST", "/api/v3/order")
+            .with_status(400)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Any)
+            .with_body(body)
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(mockito::server_url());
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let err = account
+            .limit_buy("LTCBTC", 1, "0.1".parse::<Number>().unwrap())
+            .unwrap_err();
+        mock_limit_buy.assert();
+
+        assert_eq!(err.error_code(), Some(BinanceErrorCode::NewOrderRejected));
+        assert_eq!(err.category(), ErrorCategory::InsufficientBalance);
+        assert_eq!(err.retry_after(), None);
+        match err.kind() {
+            ErrorKind::BinanceError(response) => {
+                assert_eq!(response.code, -2010);
+                assert_eq!(response.status, 400);
+                assert_eq!(response.body, body);
+            }
+            _ => panic!("Unexpected error: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn order_rejected_filter_failure() {
+        let mock_limit_sell = mock("POST", "/api/v3/order")
+            .with_status(400)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Any)
+            .with_body(r#"{"code":-1013,"msg":"Filter failure: PRICE_FILTER"}"#)
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(mockito::server_url());
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let err = account
+            .limit_sell("LTCBTC", 1, "0.1".parse::<Number>().unwrap())
+            .unwrap_err();
+        mock_limit_sell.assert();
+
+        assert_eq!(err.error_code(), Some(BinanceErrorCode::InvalidMessage));
+        assert_eq!(err.category(), ErrorCategory::FilterFailure);
+    }
+
+    #[test]
+    fn unauthorized() {
+        let mock_get_account = mock("GET", "/api/v3/account")
+            .with_status(401)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Any)
+            .with_body(r#"{"code":-2015,"msg":"Invalid API-key, IP, or permissions for action."}"#)
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(mockito::server_url());
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let err = account.get_account().unwrap_err();
+        mock_get_account.assert();
+
+        assert_eq!(err.error_code(), Some(BinanceErrorCode::RejectedMbxKey));
+        assert_eq!(err.category(), ErrorCategory::Unauthorized);
+    }
+
+    #[test]
+    fn server_error_without_binance_body() {
+        let mock_ping = mock("GET", "/api/v3/ping")
+            .with_status(503)
+            .with_body("<html>Service Unavailable</html>")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(mockito::server_url());
+        let general: General = Binance::new_with_config(None, None, &config);
+        let err = general.ping().unwrap_err();
+        mock_ping.assert();
+
+        assert_eq!(err.error_code(), None);
+        assert_eq!(err.category(), ErrorCategory::ServerError);
+        match err.kind() {
+            ErrorKind::HttpError(response) => {
+                assert_eq!(response.status, 503);
+                assert_eq!(response.body, "<html>Service Unavailable</html>");
+            }
+            _ => panic!("Unexpected error: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn error_codes() {
+        assert_eq!(
+            BinanceErrorCode::from(-1021),
+            BinanceErrorCode::InvalidTimestamp
+        );
+        assert_eq!(
+            BinanceErrorCode::from(-2011),
+            BinanceErrorCode::CancelRejected
+        );
+        assert_eq!(
+            BinanceErrorCode::from(-9999),
+            BinanceErrorCode::Unknown(-9999)
+        );
+        assert_eq!(BinanceErrorCode::NewOrderRejected.code(), -2010);
+        assert_eq!(BinanceErrorCode::Unknown(-9999).code(), -9999);
+    }
+}
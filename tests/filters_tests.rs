@@ -0,0 +1,134 @@
+use binance::account::*;
+use binance::errors::ErrorKind;
+use binance::filters::*;
+use binance::model::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn n(value: &str) -> Number {
+        value.parse().unwrap()
+    }
+
+    fn ethbtc() -> Symbol {
+        let info: ExchangeInformation = serde_json::from_str(
+            &std::fs::read_to_string("tests/mocks/general/exchange_info.json").unwrap(),
+        )
+        .unwrap();
+        info.symbols.into_iter().next().unwrap()
+    }
+
+    #[test]
+    fn valid_order_passes() {
+        let order = SpotOrderRequest::new("ETHBTC", OrderSide::Buy, OrderType::Limit)
+            .set_quantity(n("1.5"))
+            .set_price(n("0.0301"))
+            .set_time_in_force(TimeInForce::GTC);
+
+        assert!(ethbtc().check_order(&OrderCheck::from(&order)).is_empty());
+        assert!(order.check_filters(&ethbtc()).is_ok());
+    }
+
+    #[test]
+    fn violations_are_reported() {
+        let order = SpotOrderRequest::new("ETHBTC", OrderSide::Buy, OrderType::Limit)
+            .set_quantity(n("0.0015"))
+            .set_price(n("0.0000015"))
+            .set_time_in_force(TimeInForce::GTC)
+            .set_iceberg_qty(n("0.0001"));
+
+        let violations = ethbtc().check_order(&OrderCheck::from(&order));
+        assert_eq!(
+            violations,
+            vec![
+                FilterViolation::PriceNotOnTick {
+                    price: n("0.0000015"),
+                    tick_size: n("0.000001"),
+                },
+                FilterViolation::QuantityNotOnStep {
+                    qty: n("0.0015"),
+                    step_size: n("0.001"),
+                },
+                FilterViolation::NotionalTooLow {
+                    notional: n("0.0015") * n("0.0000015"),
+                    min_notional: n("0.0001"),
+                },
+                FilterViolation::TooManyIcebergParts {
+                    parts: n("15"),
+                    limit: 10,
+                },
+            ]
+        );
+
+        let error = order.check_filters(&ethbtc()).unwrap_err();
+        match error.kind() {
+            ErrorKind::FilterViolations(found) => assert_eq!(*found, violations),
+            _ => panic!("Unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn market_orders_use_reference_price() {
+        let mut symbol = ethbtc();
+        symbol.status = "BREAK".into();
+        let order = SpotOrderRequest::new("ETHBTC", OrderSide::Sell, OrderType::Market)
+            .set_quantity(n("0.002"));
+        let check = OrderCheck::from(&order);
+
+        assert_eq!(
+            symbol.check_order(&check),
+            vec![FilterViolation::SymbolNotTrading {
+                status: "BREAK".into()
+            }]
+        );
+        assert_eq!(
+            symbol.check_order(&check.set_reference_price(n("0.03")))[1],
+            FilterViolation::NotionalTooLow {
+                notional: n("0.002") * n("0.03"),
+                min_notional: n("0.0001"),
+            }
+        );
+
+        let limit = SpotOrderRequest::new("ETHBTC", OrderSide::Sell, OrderType::Limit)
+            .set_quantity(n("1"))
+            .set_price(n("0.2"))
+            .set_time_in_force(TimeInForce::GTC);
+        assert_eq!(
+            ethbtc().check_order(&OrderCheck::from(&limit).set_reference_price(n("0.03"))),
+            vec![FilterViolation::PriceOutsideBand {
+                price: n("0.2"),
+                min_price: n("0.03") * n("0.2"),
+                max_price: n("0.03") * n("5"),
+            }]
+        );
+    }
+
+    #[test]
+    fn rounding() {
+        let symbol = ethbtc();
+
+        assert_eq!(
+            symbol.round_price(n("0.0301239"), &OrderSide::Buy),
+            n("0.030123")
+        );
+        assert_eq!(
+            symbol.round_price(n("0.0301231"), &OrderSide::Sell),
+            n("0.030124")
+        );
+        assert_eq!(symbol.round_price(n("0.3"), &OrderSide::Sell), n("0.3"));
+        assert_eq!(
+            symbol.round_quantity(n("1.2349"), &OrderType::Limit),
+            n("1.234")
+        );
+        assert_eq!(
+            symbol.round_quantity(n("0.3"), &OrderType::Market),
+            n("0.3")
+        );
+        // 0.000003 / 0.000001 is 2.9999999999999996 in f64
+        assert_eq!(
+            symbol.round_price(n("0.000003"), &OrderSide::Buy),
+            n("0.000003")
+        );
+    }
+}
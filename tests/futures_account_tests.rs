@@ -1,12 +1,12 @@
 use binance::api::*;
 use binance::config::*;
+use binance::model::Number;
 use binance::futures::account::*;
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use mockito::{mock, Matcher};
-    use float_cmp::*;
     use binance::account::OrderSide;
     use binance::futures::model::Transaction;
 
@@ -31,12 +31,10 @@ mod tests {
 
         assert_eq!(response.leverage, 2);
         assert_eq!(response.symbol, "LTCUSDT");
-        assert!(approx_eq!(
-            f64,
+        assert_eq!(
             response.max_notional_value,
-            9223372036854776000.0,
-            ulps = 2
-        ));
+            "9223372036854776000.0".parse::<Number>().unwrap()
+        );
     }
 
     #[test]
@@ -92,15 +90,17 @@ mod tests {
             .set_recv_window(1234);
         let account: FuturesAccount = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
-        let transaction: Transaction = account.stop_market_close_buy("SRMUSDT", 10.5).unwrap();
+        let transaction: Transaction = account
+            .stop_market_close_buy("SRMUSDT", "10.5".parse::<Number>().unwrap())
+            .unwrap();
 
         mock_stop_market_close_sell.assert();
 
         assert_eq!(transaction.symbol, "SRMUSDT");
-        assert_eq!(transaction.side, "BUY");
-        assert_eq!(transaction.orig_type, "STOP_MARKET");
+        assert_eq!(transaction.side, OrderSide::Buy);
+        assert_eq!(transaction.orig_type, OrderType::StopMarket);
         assert!(transaction.close_position);
-        assert!(approx_eq!(f64, transaction.stop_price, 10.5, ulps = 2));
+        assert_eq!(transaction.stop_price, "10.5".parse::<Number>().unwrap());
     }
 
     #[test]
@@ -116,15 +116,17 @@ mod tests {
             .set_recv_window(1234);
         let account: FuturesAccount = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
-        let transaction: Transaction = account.stop_market_close_sell("SRMUSDT", 7.4).unwrap();
+        let transaction: Transaction = account
+            .stop_market_close_sell("SRMUSDT", "7.4".parse::<Number>().unwrap())
+            .unwrap();
 
         mock_stop_market_close_sell.assert();
 
         assert_eq!(transaction.symbol, "SRMUSDT");
-        assert_eq!(transaction.side, "SELL");
-        assert_eq!(transaction.orig_type, "STOP_MARKET");
+        assert_eq!(transaction.side, OrderSide::Sell);
+        assert_eq!(transaction.orig_type, OrderType::StopMarket);
         assert!(transaction.close_position);
-        assert!(approx_eq!(f64, transaction.stop_price, 7.4, ulps = 2));
+        assert_eq!(transaction.stop_price, "7.4".parse::<Number>().unwrap());
     }
 
     #[test]
@@ -149,22 +151,23 @@ mod tests {
             qty: None,
             reduce_only: None,
             price: None,
-            stop_price: Some(7.4),
+            stop_price: Some("7.4".parse::<Number>().unwrap()),
             close_position: Some(true),
             activation_price: None,
             callback_rate: None,
             working_type: None,
             price_protect: None,
+            client_order_id: None,
         };
         let transaction: Transaction = account.custom_order(custom_order).unwrap();
 
         mock_custom_order.assert();
 
         assert_eq!(transaction.symbol, "SRMUSDT");
-        assert_eq!(transaction.side, "SELL");
-        assert_eq!(transaction.orig_type, "STOP_MARKET");
+        assert_eq!(transaction.side, OrderSide::Sell);
+        assert_eq!(transaction.orig_type, OrderType::StopMarket);
         assert!(transaction.close_position);
-        assert!(approx_eq!(f64, transaction.stop_price, 7.4, ulps = 2));
+        assert_eq!(transaction.stop_price, "7.4".parse::<Number>().unwrap());
     }
 
     #[test]
@@ -0,0 +1,374 @@
+use binance::account::{
+    OrderHistoryRequest, OrderSide, OrderType, SpotOrderRequest, TradeHistoryRequest,
+};
+use binance::api::*;
+use binance::config::*;
+use binance::margin::*;
+use binance::model::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+
+    #[test]
+    fn get_account() {
+        let mock_get_account = mock("GET", "/sapi/v1/margin/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/account.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let account = margin.get_account().unwrap();
+
+        mock_get_account.assert();
+
+        assert!(account.borrow_enabled);
+        assert_eq!(
+            account.margin_level,
+            "11.64405625".parse::<Number>().unwrap()
+        );
+        assert_eq!(account.transfer_out_enabled, Some(true));
+        assert_eq!(account.user_assets.len(), 2);
+        let bnb = &account.user_assets[1];
+        assert_eq!(bnb.asset, "BNB");
+        assert_eq!(bnb.borrowed, "201.66666672".parse::<Number>().unwrap());
+        assert_eq!(bnb.net_asset, "2144.83333328".parse::<Number>().unwrap());
+    }
+
+    #[test]
+    fn get_isolated_account() {
+        let mock_isolated_account = mock("GET", "/sapi/v1/margin/isolated/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&symbols=BTCUSDT%2CETHUSDT&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/isolated_account.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let account = margin
+            .get_isolated_account(&["BTCUSDT", "ETHUSDT"])
+            .unwrap();
+
+        mock_isolated_account.assert();
+
+        assert!(account.total_asset_of_btc.is_none());
+        let pair = &account.assets[0];
+        assert_eq!(pair.symbol, "BTCUSDT");
+        assert_eq!(pair.margin_level_status, "EXCESSIVE");
+        assert_eq!(pair.quote_asset.asset, "USDT");
+        assert_eq!(pair.quote_asset.borrowed, "50".parse::<Number>().unwrap());
+        assert!(pair.base_asset.repay_enabled);
+    }
+
+    #[test]
+    fn borrow_and_repay() {
+        let mock_borrow = mock("POST", "/sapi/v1/margin/borrow-repay")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^amount=50&asset=USDT&isIsolated=TRUE&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&type=BORROW".into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/transaction_id.json")
+            .create();
+        let mock_repay = mock("POST", "/sapi/v1/margin/borrow-repay")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^amount=2&asset=BNB&isIsolated=FALSE&recvWindow=1234&timestamp=\\d+&type=REPAY"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/transaction_id.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let borrowed = margin.borrow("USDT", 50, Some("BTCUSDT".into())).unwrap();
+        let repaid = margin.repay("BNB", 2, None).unwrap();
+
+        mock_borrow.assert();
+        mock_repay.assert();
+
+        assert_eq!(borrowed.tran_id, 100000001);
+        assert_eq!(repaid.tran_id, 100000001);
+    }
+
+    #[test]
+    fn borrow_repay_history() {
+        let mock_history = mock("GET", "/sapi/v1/margin/borrow-repay")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^asset=USDT&current=1&isolatedSymbol=BTCUSDT&recvWindow=1234&size=10&startTime=1555056000000&timestamp=\\d+&type=BORROW".into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/borrow_history.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let request = MarginHistoryRequest::new()
+            .set_asset("USDT")
+            .set_isolated_symbol("BTCUSDT")
+            .set_start_time(1555056000000)
+            .set_page(1, 10);
+        let records = margin
+            .borrow_repay_history(BorrowRepayType::Borrow, request)
+            .unwrap();
+
+        mock_history.assert();
+
+        assert_eq!(records.total, 1);
+        let record = &records.rows[0];
+        assert_eq!(record.isolated_symbol.as_deref(), Some("BTCUSDT"));
+        assert_eq!(record.status, LoanStatus::Confirmed);
+        assert_eq!(record.principal, "50".parse::<Number>().unwrap());
+        assert_eq!(record.tx_id, 12807067523);
+    }
+
+    #[test]
+    fn interest_history() {
+        let mock_history = mock("GET", "/sapi/v1/margin/interestHistory")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^asset=USDT&recvWindow=1234&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/interest_history.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let records = margin
+            .interest_history(MarginHistoryRequest::new().set_asset("USDT"))
+            .unwrap();
+
+        mock_history.assert();
+
+        assert_eq!(records.total, 14);
+        assert_eq!(records.rows.len(), 2);
+        assert_eq!(records.rows[0].interest_accrued_time, 1672160400000);
+        assert_eq!(records.rows[0].interest_type, "ON_BORROW");
+        assert_eq!(records.rows[0].isolated_symbol.as_deref(), Some("BNBUSDT"));
+        assert!(records.rows[1].raw_asset.is_none());
+    }
+
+    #[test]
+    fn max_borrowable() {
+        let mock_max_borrowable = mock("GET", "/sapi/v1/margin/maxBorrowable")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^asset=BTC&isolatedSymbol=BTCUSDT&recvWindow=1234&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/max_borrowable.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let max = margin
+            .max_borrowable("BTC", Some("BTCUSDT".into()))
+            .unwrap();
+
+        mock_max_borrowable.assert();
+
+        assert_eq!(max.amount, "1.69248805".parse::<Number>().unwrap());
+        assert_eq!(max.borrow_limit, "60".parse::<Number>().unwrap());
+    }
+
+    #[test]
+    fn place_isolated_order() {
+        let mock_place_order = mock("POST", "/sapi/v1/margin/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^isIsolated=TRUE&quoteOrderQty=100&recvWindow=1234&side=BUY&sideEffectType=MARGIN_BUY&symbol=BTCUSDT&timestamp=\\d+&type=MARKET".into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/order.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = SpotOrderRequest::new("BTCUSDT", OrderSide::Buy, OrderType::Market)
+            .set_quote_order_qty(100);
+        let order =
+            MarginOrderRequest::isolated(order).set_side_effect_type(SideEffectType::MarginBuy);
+        let transaction = margin.place_order(order.clone()).unwrap();
+
+        mock_place_order.assert();
+
+        assert!(transaction.is_isolated);
+        assert_eq!(transaction.transaction.order_id, 28);
+        assert_eq!(transaction.transaction.status, OrderStatus::Filled);
+        assert_eq!(
+            transaction.margin_buy_borrow_amount,
+            Some("50".parse::<Number>().unwrap())
+        );
+        assert_eq!(transaction.margin_buy_borrow_asset.as_deref(), Some("USDT"));
+
+        // Margin orders are answered with the order details
+        let ack = MarginOrderRequest {
+            order: order.order.set_new_order_resp_type(NewOrderRespType::ACK),
+            ..order
+        };
+        assert!(ack.validate().is_err());
+    }
+
+    #[test]
+    fn cancel_isolated_order() {
+        let mock_cancel_order = mock("DELETE", "/sapi/v1/margin/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^isIsolated=TRUE&orderId=4&recvWindow=1234&symbol=LTCBTC&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/cancel_order.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let canceled = margin.cancel_order("LTCBTC", 4, true).unwrap();
+
+        mock_cancel_order.assert();
+
+        assert_eq!(canceled.order_id, Some(4));
+    }
+
+    #[test]
+    fn order_status_and_open_orders() {
+        let mock_order_status = mock("GET", "/sapi/v1/margin/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^isIsolated=TRUE&orderId=213205622&recvWindow=1234&symbol=BNBBTC&timestamp=\\d+"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/order_status.json")
+            .create();
+        let mock_open_orders = mock("GET", "/sapi/v1/margin/openOrders")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^recvWindow=1234&symbol=BNBBTC&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/open_orders.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = margin.order_status("BNBBTC", 213205622, true).unwrap();
+        let open_orders = margin.get_open_orders("BNBBTC", false).unwrap();
+
+        mock_order_status.assert();
+        mock_open_orders.assert();
+
+        assert!(order.is_isolated);
+        assert_eq!(order.status, OrderStatus::New);
+        assert_eq!(order.price, "0.0049363".parse::<Number>().unwrap());
+        assert_eq!(open_orders.len(), 1);
+        assert_eq!(open_orders[0].order_id, 211842552);
+        assert!(!open_orders[0].is_isolated);
+    }
+
+    #[test]
+    fn all_orders_and_trades() {
+        let mock_all_orders = mock("GET", "/sapi/v1/margin/allOrders")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^limit=2&recvWindow=1234&symbol=BNBBTC&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/all_orders.json")
+            .create();
+        let mock_my_trades = mock("GET", "/sapi/v1/margin/myTrades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^fromId=34&recvWindow=1234&symbol=BNBBTC&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/my_trades.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let orders = margin
+            .get_all_orders(OrderHistoryRequest::new("BNBBTC").set_limit(2), false)
+            .unwrap();
+        let trades = margin
+            .my_trades(TradeHistoryRequest::new("BNBBTC").set_from_id(34), false)
+            .unwrap();
+
+        mock_all_orders.assert();
+        mock_my_trades.assert();
+
+        assert_eq!(orders.len(), 2);
+        assert_eq!(orders[0].order_id, 41295);
+        assert_eq!(orders[0].stop_price, "0.18".parse::<Number>().unwrap());
+        assert!(!orders[0].is_isolated);
+        assert_eq!(trades[0].id, 34);
+        assert_eq!(trades[0].qty, "3".parse::<Number>().unwrap());
+        assert!(!trades[0].is_isolated);
+    }
+
+    #[test]
+    fn transfers() {
+        let mock_transfer = mock("POST", "/sapi/v1/margin/transfer")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^amount=3&asset=BNB&recvWindow=1234&timestamp=\\d+&type=2".into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/transaction_id.json")
+            .create();
+        let mock_isolated_transfer = mock("POST", "/sapi/v1/margin/isolated/transfer")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^amount=100&asset=USDT&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&transFrom=SPOT&transTo=ISOLATED_MARGIN".into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/transaction_id.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        margin
+            .transfer("BNB", 3, MarginTransferType::MarginToSpot)
+            .unwrap();
+        margin
+            .isolated_transfer(
+                "USDT",
+                "BTCUSDT",
+                100,
+                IsolatedTransferDirection::SpotToIsolated,
+            )
+            .unwrap();
+
+        mock_transfer.assert();
+        mock_isolated_transfer.assert();
+    }
+}
@@ -7,7 +7,6 @@ use binance::model::*;
 mod tests {
     use super::*;
     use mockito::{mock, Matcher};
-    use float_cmp::*;
 
     #[test]
     fn get_depth() {
@@ -24,7 +23,13 @@ mod tests {
         mock_get_depth.assert();
 
         assert_eq!(order_book.last_update_id, 1027024);
-        assert_eq!(order_book.bids[0], Bids::new(4.00000000, 431.00000000));
+        assert_eq!(
+            order_book.bids[0],
+            Bids::new(
+                "4.00000000".parse::<Number>().unwrap(),
+                "431.00000000".parse::<Number>().unwrap(),
+            )
+        );
     }
 
     #[test]
@@ -42,7 +47,13 @@ mod tests {
         mock_get_custom_depth.assert();
 
         assert_eq!(order_book.last_update_id, 1027024);
-        assert_eq!(order_book.bids[0], Bids::new(4.00000000, 431.00000000));
+        assert_eq!(
+            order_book.bids[0],
+            Bids::new(
+                "4.00000000".parse::<Number>().unwrap(),
+                "431.00000000".parse::<Number>().unwrap(),
+            )
+        );
     }
 
     #[test]
@@ -63,10 +74,10 @@ mod tests {
                 assert!(!symbols.is_empty());
                 let first_symbol = symbols[0].clone();
                 assert_eq!(first_symbol.symbol, "LTCBTC");
-                assert!(approx_eq!(f64, first_symbol.price, 4.00000200, ulps = 2));
+                assert_eq!(first_symbol.price, "4.00000200".parse::<Number>().unwrap());
                 let second_symbol = symbols[1].clone();
                 assert_eq!(second_symbol.symbol, "ETHBTC");
-                assert!(approx_eq!(f64, second_symbol.price, 0.07946600, ulps = 2));
+                assert_eq!(second_symbol.price, "0.07946600".parse::<Number>().unwrap());
             }
         }
     }
@@ -86,7 +97,7 @@ mod tests {
         mock_get_price.assert();
 
         assert_eq!(symbol.symbol, "LTCBTC");
-        assert!(approx_eq!(f64, symbol.price, 4.00000200, ulps = 2));
+        assert_eq!(symbol.price, "4.00000200".parse::<Number>().unwrap());
     }
 
     #[test]
@@ -104,7 +115,7 @@ mod tests {
         mock_get_average_price.assert();
 
         assert_eq!(symbol.mins, 5);
-        assert!(approx_eq!(f64, symbol.price, 9.35751834, ulps = 2));
+        assert_eq!(symbol.price, "9.35751834".parse::<Number>().unwrap());
     }
 
     #[test]
@@ -125,46 +136,40 @@ mod tests {
                 assert!(!tickers.is_empty());
                 let first_ticker = tickers[0].clone();
                 assert_eq!(first_ticker.symbol, "LTCBTC");
-                assert!(approx_eq!(
-                    f64,
+                assert_eq!(
                     first_ticker.bid_price,
-                    4.00000000,
-                    ulps = 2
-                ));
-                assert!(approx_eq!(
-                    f64,
+                    "4.00000000".parse::<Number>().unwrap()
+                );
+                assert_eq!(
                     first_ticker.bid_qty,
-                    431.00000000,
-                    ulps = 2
-                ));
-                assert!(approx_eq!(
-                    f64,
+                    "431.00000000".parse::<Number>().unwrap()
+                );
+                assert_eq!(
                     first_ticker.ask_price,
-                    4.00000200,
-                    ulps = 2
-                ));
-                assert!(approx_eq!(f64, first_ticker.ask_qty, 9.00000000, ulps = 2));
+                    "4.00000200".parse::<Number>().unwrap()
+                );
+                assert_eq!(
+                    first_ticker.ask_qty,
+                    "9.00000000".parse::<Number>().unwrap()
+                );
                 let second_ticker = tickers[1].clone();
                 assert_eq!(second_ticker.symbol, "ETHBTC");
-                assert!(approx_eq!(
-                    f64,
+                assert_eq!(
                     second_ticker.bid_price,
-                    0.07946700,
-                    ulps = 2
-                ));
-                assert!(approx_eq!(f64, second_ticker.bid_qty, 9.00000000, ulps = 2));
-                assert!(approx_eq!(
-                    f64,
+                    "0.07946700".parse::<Number>().unwrap()
+                );
+                assert_eq!(
+                    second_ticker.bid_qty,
+                    "9.00000000".parse::<Number>().unwrap()
+                );
+                assert_eq!(
                     second_ticker.ask_price,
-                    100000.00000000,
-                    ulps = 2
-                ));
-                assert!(approx_eq!(
-                    f64,
+                    "100000.00000000".parse::<Number>().unwrap()
+                );
+                assert_eq!(
                     second_ticker.ask_qty,
-                    1000.00000000,
-                    ulps = 2
-                ));
+                    "1000.00000000".parse::<Number>().unwrap()
+                );
             }
         }
     }
@@ -184,10 +189,19 @@ mod tests {
         mock_get_book_ticker.assert();
 
         assert_eq!(book_ticker.symbol, "LTCBTC");
-        assert!(approx_eq!(f64, book_ticker.bid_price, 4.00000000, ulps = 2));
-        assert!(approx_eq!(f64, book_ticker.bid_qty, 431.00000000, ulps = 2));
-        assert!(approx_eq!(f64, book_ticker.ask_price, 4.00000200, ulps = 2));
-        assert!(approx_eq!(f64, book_ticker.ask_qty, 9.00000000, ulps = 2));
+        assert_eq!(
+            book_ticker.bid_price,
+            "4.00000000".parse::<Number>().unwrap()
+        );
+        assert_eq!(
+            book_ticker.bid_qty,
+            "431.00000000".parse::<Number>().unwrap()
+        );
+        assert_eq!(
+            book_ticker.ask_price,
+            "4.00000200".parse::<Number>().unwrap()
+        );
+        assert_eq!(book_ticker.ask_qty, "9.00000000".parse::<Number>().unwrap());
     }
 
     #[test]
@@ -208,34 +222,38 @@ mod tests {
         assert_eq!(price_stats.price_change, "-94.99999800");
         assert_eq!(price_stats.price_change_percent, "-95.960");
         assert_eq!(price_stats.weighted_avg_price, "0.29628482");
-        assert!(approx_eq!(
-            f64,
+        assert_eq!(
             price_stats.prev_close_price,
-            0.10002000,
-            ulps = 2
-        ));
-        assert!(approx_eq!(
-            f64,
+            "0.10002000".parse::<Number>().unwrap()
+        );
+        assert_eq!(
             price_stats.last_price,
-            4.00000200,
-            ulps = 2
-        ));
-        assert!(approx_eq!(f64, price_stats.bid_price, 4.00000000, ulps = 2));
-        assert!(approx_eq!(f64, price_stats.ask_price, 4.00000200, ulps = 2));
-        assert!(approx_eq!(
-            f64,
+            "4.00000200".parse::<Number>().unwrap()
+        );
+        assert_eq!(
+            price_stats.bid_price,
+            "4.00000000".parse::<Number>().unwrap()
+        );
+        assert_eq!(
+            price_stats.ask_price,
+            "4.00000200".parse::<Number>().unwrap()
+        );
+        assert_eq!(
             price_stats.open_price,
-            99.00000000,
-            ulps = 2
-        ));
-        assert!(approx_eq!(
-            f64,
+            "99.00000000".parse::<Number>().unwrap()
+        );
+        assert_eq!(
             price_stats.high_price,
-            100.00000000,
-            ulps = 2
-        ));
-        assert!(approx_eq!(f64, price_stats.low_price, 0.10000000, ulps = 2));
-        assert!(approx_eq!(f64, price_stats.volume, 8913.30000000, ulps = 2));
+            "100.00000000".parse::<Number>().unwrap()
+        );
+        assert_eq!(
+            price_stats.low_price,
+            "0.10000000".parse::<Number>().unwrap()
+        );
+        assert_eq!(
+            price_stats.volume,
+            "8913.30000000".parse::<Number>().unwrap()
+        );
         assert_eq!(price_stats.open_time, 1499783499040);
         assert_eq!(price_stats.close_time, 1499869899040);
         assert_eq!(price_stats.first_id, 28385);
@@ -264,34 +282,38 @@ mod tests {
         assert_eq!(price_stats.price_change, "-94.99999800");
         assert_eq!(price_stats.price_change_percent, "-95.960");
         assert_eq!(price_stats.weighted_avg_price, "0.29628482");
-        assert!(approx_eq!(
-            f64,
+        assert_eq!(
             price_stats.prev_close_price,
-            0.10002000,
-            ulps = 2
-        ));
-        assert!(approx_eq!(
-            f64,
+            "0.10002000".parse::<Number>().unwrap()
+        );
+        assert_eq!(
             price_stats.last_price,
-            4.00000200,
-            ulps = 2
-        ));
-        assert!(approx_eq!(f64, price_stats.bid_price, 4.00000000, ulps = 2));
-        assert!(approx_eq!(f64, price_stats.ask_price, 4.00000200, ulps = 2));
-        assert!(approx_eq!(
-            f64,
+            "4.00000200".parse::<Number>().unwrap()
+        );
+        assert_eq!(
+            price_stats.bid_price,
+            "4.00000000".parse::<Number>().unwrap()
+        );
+        assert_eq!(
+            price_stats.ask_price,
+            "4.00000200".parse::<Number>().unwrap()
+        );
+        assert_eq!(
             price_stats.open_price,
-            99.00000000,
-            ulps = 2
-        ));
-        assert!(approx_eq!(
-            f64,
+            "99.00000000".parse::<Number>().unwrap()
+        );
+        assert_eq!(
             price_stats.high_price,
-            100.00000000,
-            ulps = 2
-        ));
-        assert!(approx_eq!(f64, price_stats.low_price, 0.10000000, ulps = 2));
-        assert!(approx_eq!(f64, price_stats.volume, 8913.30000000, ulps = 2));
+            "100.00000000".parse::<Number>().unwrap()
+        );
+        assert_eq!(
+            price_stats.low_price,
+            "0.10000000".parse::<Number>().unwrap()
+        );
+        assert_eq!(
+            price_stats.volume,
+            "8913.30000000".parse::<Number>().unwrap()
+        );
         assert_eq!(price_stats.open_time, 1499783499040);
         assert_eq!(price_stats.close_time, 1499869899040);
         assert_eq!(price_stats.first_id, 28385);
@@ -332,4 +354,78 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn get_trades() {
+        let mock_get_trades = mock("GET", "/api/v3/trades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("limit=2&symbol=BNBBTC".into()))
+            .with_body_from_file("tests/mocks/market/get_trades.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(mockito::server_url());
+        let market: Market = Binance::new_with_config(None, None, &config);
+
+        let trades = market.get_trades("BNBBTC", 2).unwrap();
+        mock_get_trades.assert();
+
+        assert_eq!(trades.len(), 2);
+        assert_eq!(trades[0].id, 28457);
+        assert_eq!(trades[0].price, "4.00000100".parse::<Number>().unwrap());
+        assert_eq!(trades[0].quote_qty, "48.000012".parse::<Number>().unwrap());
+        assert_eq!(trades[1].time, 1499865549591);
+        assert!(trades[1].is_buyer_maker);
+    }
+
+    #[test]
+    fn get_historical_trades() {
+        let mock_get_historical_trades = mock("GET", "/api/v3/historicalTrades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_header("x-mbx-apikey", "api_key")
+            .match_query(Matcher::Regex(
+                "^fromId=28455&limit=2&symbol=LTCBTC$".into(),
+            ))
+            .with_body_from_file("tests/mocks/market/get_historical_trades.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(mockito::server_url());
+        let market: Market = Binance::new_with_config(Some("api_key".into()), None, &config);
+
+        let trades = market.get_historical_trades("LTCBTC", 28455, 2).unwrap();
+        mock_get_historical_trades.assert();
+
+        assert_eq!(trades.len(), 2);
+        assert_eq!(trades[0].id, 28455);
+    }
+
+    #[test]
+    fn historical_trades_backward() {
+        let mock_recent_trades = mock("GET", "/api/v3/historicalTrades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^limit=2&symbol=ETHBTC$".into()))
+            .with_body_from_file("tests/mocks/market/get_trades.json")
+            .create();
+        let mock_older_trades = mock("GET", "/api/v3/historicalTrades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^fromId=28455&limit=2&symbol=ETHBTC$".into(),
+            ))
+            .with_body_from_file("tests/mocks/market/get_historical_trades.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(mockito::server_url());
+        let market: Market = Binance::new_with_config(Some("api_key".into()), None, &config);
+
+        // Newest first, down to the first trade after 1499865549584
+        let ids: Vec<u64> = market
+            .historical_trades_backward("ETHBTC", None, 2)
+            .map(|trade| trade.unwrap())
+            .take_while(|trade| trade.time > 1499865549584)
+            .map(|trade| trade.id)
+            .collect();
+        mock_recent_trades.assert();
+        mock_older_trades.assert();
+
+        assert_eq!(ids, vec![28458, 28457, 28456]);
+    }
 }
@@ -0,0 +1,244 @@
+#![cfg(feature = "async")]
+
+use binance::api::*;
+use binance::config::*;
+use binance::nonblocking::account::*;
+use binance::nonblocking::futures::market::FuturesMarket;
+use binance::nonblocking::general::*;
+use binance::nonblocking::margin::Margin;
+use binance::nonblocking::market::*;
+use binance::model::*;
+use binance::cassette::{Recorder, Replayer};
+use binance::transport::ReqwestAsyncTransport;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+
+    #[tokio::test]
+    async fn ping() {
+        let mock_ping = mock("GET", "/api/v3/ping")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body("{}")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(mockito::server_url());
+        let general: General = Binance::new_with_config(None, None, &config);
+
+        let pong = general.ping().await.unwrap();
+        mock_ping.assert();
+
+        assert_eq!(pong, "pong");
+    }
+
+    #[tokio::test]
+    async fn get_server_time() {
+        let mock_server_time = mock("GET", "/api/v3/time")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/general/server_time.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(mockito::server_url());
+        let general: General = Binance::new_with_config(None, None, &config);
+
+        let server_time = general.get_server_time().await.unwrap();
+        mock_server_time.assert();
+
+        assert_eq!(server_time.server_time, 1499827319559);
+    }
+
+    #[tokio::test]
+    async fn get_price() {
+        let mock_get_price = mock("GET", "/api/v3/ticker/price")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("symbol=LTCBTC".into()))
+            .with_body_from_file("tests/mocks/market/get_price.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(mockito::server_url());
+        let market: Market = Binance::new_with_config(None, None, &config);
+
+        let symbol = market.get_price("LTCBTC").await.unwrap();
+        mock_get_price.assert();
+
+        assert_eq!(symbol.symbol, "LTCBTC");
+        assert_eq!(symbol.price, "4.00000200".parse::<Number>().unwrap());
+    }
+
+    #[tokio::test]
+    async fn get_historical_trades() {
+        let mock_get_historical_trades = mock("GET", "/api/v3/historicalTrades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_header("x-mbx-apikey", "api_key")
+            .match_query(Matcher::Regex("fromId=28455&limit=2&symbol=LTCBTC".into()))
+            .with_body_from_file("tests/mocks/market/get_historical_trades.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(mockito::server_url());
+        let market: Market = Binance::new_with_config(Some("api_key".into()), None, &config);
+
+        let trades = market
+            .get_historical_trades("LTCBTC", 28455, 2)
+            .await
+            .unwrap();
+        mock_get_historical_trades.assert();
+
+        assert_eq!(trades.len(), 2);
+        assert_eq!(trades[1].id, 28456);
+    }
+
+    #[tokio::test]
+    async fn get_margin_account() {
+        let mock_get_account = mock("GET", "/sapi/v1/margin/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/account.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+
+        let account = margin.get_account().await.unwrap();
+        mock_get_account.assert();
+
+        assert!(account.trade_enabled);
+        assert_eq!(account.user_assets[0].asset, "BTC");
+    }
+
+    #[tokio::test]
+    async fn get_balance() {
+        let mock_get_account = mock("GET", "/api/v3/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/get_account.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let balance = account.get_balance("BTC").await.unwrap();
+
+        mock_get_account.assert();
+
+        assert_eq!(balance.asset, "BTC");
+        assert_eq!(balance.free, "4723846.89208129");
+        assert_eq!(balance.locked, "0.00000000");
+    }
+
+    #[tokio::test]
+    async fn limit_buy() {
+        let mock_limit_buy = mock("POST", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("price=0.1&quantity=1&recvWindow=1234&side=BUY&symbol=LTCBTC&timeInForce=GTC&timestamp=\\d+&type=LIMIT".into()))
+            .with_body_from_file("tests/mocks/account/limit_buy.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let transaction: Transaction = account
+            .limit_buy("LTCBTC", 1, "0.1".parse::<Number>().unwrap())
+            .await
+            .unwrap();
+
+        mock_limit_buy.assert();
+
+        assert_eq!(transaction.symbol, "LTCBTC");
+        assert_eq!(transaction.order_id, 1);
+        assert_eq!(transaction.client_order_id, "6gCrw2kRUAF9CvJDGP16IP");
+        assert_eq!(transaction.price, "0.1".parse::<Number>().unwrap());
+        assert_eq!(transaction.side, OrderSide::Buy);
+    }
+
+    #[tokio::test]
+    async fn cancel_order_error() {
+        let mock_cancel_order = mock("DELETE", "/api/v3/order")
+            .with_status(400)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "orderId=1&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+".into(),
+            ))
+            .with_body(r#"{"code":-2011,"msg":"Unknown order sent."}"#)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let err = account.cancel_order("BTCUSDT", 1).await.unwrap_err();
+
+        mock_cancel_order.assert();
+
+        match err.kind() {
+            binance::errors::ErrorKind::BinanceError(response) => {
+                assert_eq!(response.code, -2011);
+                assert_eq!(response.msg, "Unknown order sent.");
+            }
+            _ => panic!("Unexpected error: {:?}", err),
+        }
+    }
+
+    #[tokio::test]
+    async fn open_interest_statistics() {
+        let mock_open_interest_statistics = mock("GET", "/futures/data/openInterestHist")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("limit=10&period=5m&symbol=BTCUSDT".into()))
+            .with_body_from_file("tests/mocks/futures/market/open_interest_statistics.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(mockito::server_url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+
+        let open_interest_hists = market
+            .open_interest_statistics("BTCUSDT", "5m", 10, None, None)
+            .await
+            .unwrap();
+        mock_open_interest_statistics.assert();
+
+        assert_eq!(open_interest_hists.len(), 2);
+        assert_eq!(open_interest_hists[0].timestamp, 1583127900000);
+    }
+
+    #[tokio::test]
+    async fn record_and_replay() {
+        let mock_get_balance = mock("GET", "/api/v3/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=4321&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/get_account.json")
+            .create();
+
+        let recorder = Recorder::new_async(ReqwestAsyncTransport::default());
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(4321)
+            .set_async_transport(recorder.clone());
+        let account: Account = Binance::new_with_config(None, Some("secret".into()), &config);
+        let recorded = account.get_balance("BTC").await.unwrap();
+        mock_get_balance.assert();
+
+        let cassette = recorder.cassette();
+        assert_eq!(cassette.interactions.len(), 1);
+        assert!(cassette.interactions[0].url.contains("signature=REDACTED"));
+
+        // Nothing listens on this host
+        let config = Config::default()
+            .set_rest_api_endpoint("http://127.0.0.1:1")
+            .set_recv_window(4321)
+            .set_async_transport(Replayer::new(cassette));
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let replayed = account.get_balance("BTC").await.unwrap();
+
+        assert_eq!(replayed.free, recorded.free);
+    }
+}
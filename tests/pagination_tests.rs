@@ -0,0 +1,258 @@
+use binance::model::*;
+use binance::pagination::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trade(id: u64, time: u64) -> Trade {
+        Trade {
+            id,
+            price: "0.1".parse::<Number>().unwrap(),
+            qty: "1".parse::<Number>().unwrap(),
+            quote_qty: "0.1".parse::<Number>().unwrap(),
+            time,
+            is_buyer_maker: false,
+            is_best_match: true,
+        }
+    }
+
+    // Trades 0 to 9, one per second
+    fn history() -> Vec<Trade> {
+        (0..10).map(|id| trade(id, 1000 * id)).collect()
+    }
+
+    fn ids(pages: Pages<'_, Trade>) -> Vec<u64> {
+        pages.map(|trade| trade.unwrap().id).collect()
+    }
+
+    #[test]
+    fn forward_by_id() {
+        let mut requests = vec![];
+        let pages = Pages::by_id(
+            |page: &PageRequest| {
+                requests.push(page.from_id);
+                let from_id = page.from_id.unwrap();
+                Ok(history()
+                    .into_iter()
+                    .filter(|trade| trade.id >= from_id)
+                    .take(page.limit.into())
+                    .collect())
+            },
+            3,
+            Some(7500),
+            2,
+        );
+
+        assert_eq!(ids(pages), vec![3, 4, 5, 6, 7]);
+        assert_eq!(requests, vec![Some(3), Some(5), Some(7)]);
+    }
+
+    #[test]
+    fn backward_by_id() {
+        let mut requests = vec![];
+        let pages = Pages::backward(
+            |page: &PageRequest| {
+                requests.push(page.from_id);
+                // The most recent trades when no ID is given
+                let from_id = page.from_id.unwrap_or(7);
+                Ok(history()
+                    .into_iter()
+                    .filter(|trade| trade.id >= from_id)
+                    .take(page.limit.into())
+                    .collect())
+            },
+            None,
+            3,
+        );
+
+        assert_eq!(ids(pages), vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 0]);
+        assert_eq!(requests, vec![None, Some(4), Some(1), Some(0)]);
+    }
+
+    #[test]
+    fn by_time_windows() {
+        let mut requests = vec![];
+        let pages = Pages::by_time(
+            |page: &PageRequest| {
+                requests.push((page.start_time.unwrap(), page.end_time.unwrap()));
+                let (start, end) = (page.start_time.unwrap(), page.end_time.unwrap());
+                Ok(history()
+                    .into_iter()
+                    .filter(|trade| trade.time >= start && trade.time <= end)
+                    .take(page.limit.into())
+                    .collect())
+            },
+            500,
+            Some(6000),
+            4000,
+            2,
+        );
+
+        assert_eq!(ids(pages), vec![1, 2, 3, 4, 5, 6]);
+        // A full page continues from its last time, as more trades may share it
+        assert_eq!(
+            requests,
+            vec![
+                (500, 4499),
+                (2000, 5999),
+                (3000, 6000),
+                (4000, 6000),
+                (5000, 6000),
+                (6000, 6000)
+            ]
+        );
+    }
+
+    #[test]
+    fn by_id_after_a_full_page_in_one_millisecond() {
+        // Trades 2 to 5 share the same millisecond
+        let times = [0, 1000, 2000, 2000, 2000, 2000, 3000, 4000];
+        let history: Vec<Trade> = times
+            .iter()
+            .enumerate()
+            .map(|(id, &time)| trade(id as u64, time))
+            .collect();
+        let mut requests = vec![];
+        let pages = Pages::by_time(
+            |page: &PageRequest| {
+                requests.push((page.from_id, page.start_time, page.end_time));
+                Ok(history
+                    .iter()
+                    .filter(|trade| match page.from_id {
+                        Some(from_id) => trade.id >= from_id,
+                        None => {
+                            trade.time >= page.start_time.unwrap() &&
+                                trade.time <= page.end_time.unwrap()
+                        }
+                    })
+                    .take(page.limit.into())
+                    .cloned()
+                    .collect())
+            },
+            2000,
+            Some(3500),
+            4000,
+            2,
+        );
+
+        assert_eq!(ids(pages), vec![2, 3, 4, 5, 6]);
+        assert_eq!(
+            requests,
+            vec![
+                (None, Some(2000), Some(3500)),
+                (Some(4), None, None),
+                (Some(6), None, None),
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_an_empty_window() {
+        let _ = Pages::by_time(|_: &PageRequest| Ok(history()), 0, Some(6000), 0, 2);
+    }
+
+    #[test]
+    fn windows_by_offset() {
+        let mut requests = vec![];
+        let pages = WindowPages::new(
+            |page: &WindowRequest| {
+                requests.push((page.start_time, page.end_time, page.offset));
+                // Newest first, like the wallet histories
+                let mut window: Vec<Trade> = history()
+                    .into_iter()
+                    .filter(|trade| trade.time >= page.start_time && trade.time <= page.end_time)
+                    .collect();
+                window.reverse();
+                Ok(window
+                    .into_iter()
+                    .skip(page.offset as usize)
+                    .take(page.limit.into())
+                    .collect())
+            },
+            Some(1500),
+            Some(7000),
+            4000,
+            2,
+        );
+
+        let ids: Vec<u64> = pages.map(|trade| trade.unwrap().id).collect();
+        assert_eq!(ids, vec![7, 6, 5, 4, 3, 2]);
+        assert_eq!(
+            requests,
+            vec![
+                (3001, 7000, 0),
+                (3001, 7000, 2),
+                (3001, 7000, 4),
+                (1500, 3000, 0),
+                (1500, 3000, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn numbered_pages_until_total() {
+        let mut requests = vec![];
+        let pages = NumberedPages::new(
+            |page: &NumberedRequest| {
+                requests.push(page.current);
+                let rows = history()
+                    .into_iter()
+                    .skip(((page.current - 1) * page.size) as usize)
+                    .take(page.size as usize)
+                    .collect();
+                Ok(Records { rows, total: 10 })
+            },
+            5,
+        );
+
+        let ids: Vec<u64> = pages.map(|trade| trade.unwrap().id).collect();
+        assert_eq!(ids, (0..10).collect::<Vec<u64>>());
+        // The second page is full but reaches the total
+        assert_eq!(requests, vec![1, 2]);
+    }
+
+    #[test]
+    fn numbered_pages_by_time_windows() {
+        let mut requests = vec![];
+        let pages = NumberedPages::by_time(
+            |page: &NumberedRequest| {
+                let (start, end) = (page.start_time.unwrap(), page.end_time.unwrap());
+                requests.push((start, end, page.current));
+                let window: Vec<Trade> = history()
+                    .into_iter()
+                    .filter(|trade| trade.time >= start && trade.time <= end)
+                    .collect();
+                let total = window.len() as u64;
+                let rows = window
+                    .into_iter()
+                    .skip(((page.current - 1) * page.size) as usize)
+                    .take(page.size as usize)
+                    .collect();
+                Ok(Records { rows, total })
+            },
+            Some(1500),
+            Some(7000),
+            4000,
+            2,
+        );
+
+        let ids: Vec<u64> = pages.map(|trade| trade.unwrap().id).collect();
+        assert_eq!(ids, vec![4, 5, 6, 7, 2, 3]);
+        // Each window is numbered from page 1 again
+        assert_eq!(
+            requests,
+            vec![(3001, 7000, 1), (3001, 7000, 2), (1500, 3000, 1)]
+        );
+    }
+
+    #[test]
+    fn stops_after_error() {
+        let mut pages =
+            Pages::<Trade>::by_id(|_: &PageRequest| Err("Unavailable".into()), 0, None, 10);
+
+        assert!(pages.next().unwrap().is_err());
+        assert!(pages.next().is_none());
+    }
+}
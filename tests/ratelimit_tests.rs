@@ -0,0 +1,203 @@
+use binance::api::*;
+use binance::config::*;
+use binance::model::Number;
+use binance::account::*;
+use binance::general::*;
+use binance::ratelimit::*;
+use std::time::Duration;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+
+    #[test]
+    fn used_weight_from_headers() {
+        let mock_server_time = mock("GET", "/api/v3/time")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_header("x-mbx-used-weight", "42")
+            .with_header("x-mbx-used-weight-1m", "42")
+            .with_body_from_file("tests/mocks/general/server_time.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(mockito::server_url());
+        let general: General = Binance::new_with_config(None, None, &config);
+
+        general.get_server_time().unwrap();
+        mock_server_time.assert();
+
+        let used_weight = config.rate_limiter.used_weight(Duration::from_secs(60));
+        assert_eq!(used_weight, Some(42));
+        assert_eq!(
+            general
+                .client
+                .rate_limiter()
+                .used_weight(Duration::from_secs(60)),
+            Some(42)
+        );
+        assert_eq!(
+            config.rate_limiter.order_count(Duration::from_secs(10)),
+            None
+        );
+    }
+
+    #[test]
+    fn budgets_from_exchange_info() {
+        let mock_exchange_info = mock("GET", "/api/v3/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/general/exchange_info.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(mockito::server_url());
+        let general: General = Binance::new_with_config(None, None, &config);
+
+        let exchange_info = general.exchange_info().unwrap();
+        mock_exchange_info.assert();
+
+        let limiter =
+            RateLimiter::from_rate_limits(&exchange_info.rate_limits, RateLimitBehavior::Wait);
+        let limits: Vec<_> = limiter
+            .usage()
+            .into_iter()
+            .map(|usage| (usage.rate_limit_type, usage.interval, usage.limit))
+            .collect();
+
+        assert_eq!(
+            limits,
+            vec![
+                (
+                    RateLimitType::RequestWeight,
+                    Duration::from_secs(60),
+                    Some(1200)
+                ),
+                (RateLimitType::Orders, Duration::from_secs(10), Some(100)),
+                (
+                    RateLimitType::Orders,
+                    Duration::from_secs(86400),
+                    Some(200000)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn fail_fast_when_budget_exhausted() {
+        let mock_limit_buy = mock("POST", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_header("x-mbx-order-count-10s", "1")
+            .with_header("x-mbx-order-count-1d", "5")
+            .match_query(Matcher::Any)
+            .with_body_from_file("tests/mocks/account/limit_buy.json")
+            .expect(1)
+            .create();
+
+        let limiter = RateLimiter::new(RateLimitBehavior::FailFast).set_budget(
+            RateLimitType::Orders,
+            Duration::from_secs(86400),
+            5,
+        );
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_rate_limiter(limiter);
+        let account: Account = Binance::new_with_config(None, None, &config);
+
+        account
+            .limit_buy("LTCBTC", 1, "0.1".parse::<Number>().unwrap())
+            .unwrap();
+        let err = account
+            .limit_buy("LTCBTC", 1, "0.1".parse::<Number>().unwrap())
+            .unwrap_err();
+        mock_limit_buy.assert();
+
+        match err.kind() {
+            binance::errors::ErrorKind::RateLimitExceeded(exceeded) => {
+                assert_eq!(exceeded.rate_limit_type, RateLimitType::Orders);
+                assert_eq!(exceeded.limit, 5);
+                assert_eq!(exceeded.used, 5);
+                assert!(exceeded.retry_after <= Duration::from_secs(86400));
+            }
+            _ => panic!("Unexpected error: {:?}", err),
+        }
+        assert_eq!(
+            config.rate_limiter.order_count(Duration::from_secs(10)),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn fail_when_request_exceeds_whole_budget() {
+        let mock_exchange_info = mock("GET", "/api/v3/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/general/exchange_info.json")
+            .expect(0)
+            .create();
+
+        // Waiting for the next minute would never make room for a weight of 20
+        let limiter = RateLimiter::new(RateLimitBehavior::Wait).set_budget(
+            RateLimitType::RequestWeight,
+            Duration::from_secs(60),
+            10,
+        );
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_rate_limiter(limiter);
+        let general: General = Binance::new_with_config(None, None, &config);
+
+        let err = general.exchange_info().unwrap_err();
+        mock_exchange_info.assert();
+
+        match err.kind() {
+            binance::errors::ErrorKind::RateLimitExceeded(exceeded) => {
+                assert_eq!(exceeded.rate_limit_type, RateLimitType::RequestWeight);
+                assert_eq!(exceeded.cost, 20);
+                assert_eq!(exceeded.used, 0);
+                assert!(!exceeded.fits_budget());
+            }
+            _ => panic!("Unexpected error: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn empty_budget_intervals() {
+        let mock_exchange_info = mock("GET", "/api/v3/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/general/exchange_info.json")
+            .create();
+
+        // Clamped to 1 ms instead of dividing by zero
+        let limiter = RateLimiter::new(RateLimitBehavior::FailFast)
+            .set_budget(RateLimitType::RequestWeight, Duration::ZERO, 1000)
+            .set_budget(RateLimitType::Orders, Duration::from_micros(500), 10);
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_rate_limiter(limiter);
+        let general: General = Binance::new_with_config(None, None, &config);
+
+        let exchange_info = general.exchange_info().unwrap();
+        mock_exchange_info.assert();
+
+        let intervals: Vec<_> = config
+            .rate_limiter
+            .usage()
+            .into_iter()
+            .filter(|usage| usage.limit.is_some())
+            .map(|usage| (usage.rate_limit_type, usage.interval))
+            .collect();
+        assert_eq!(
+            intervals,
+            vec![
+                (RateLimitType::RequestWeight, Duration::from_millis(1)),
+                (RateLimitType::Orders, Duration::from_millis(1)),
+            ]
+        );
+
+        let mut rate_limits = exchange_info.rate_limits;
+        rate_limits[0].interval_num = 0;
+        let limiter = RateLimiter::from_rate_limits(&rate_limits, RateLimitBehavior::Wait);
+        assert!(limiter
+            .usage()
+            .iter()
+            .all(|usage| usage.interval > Duration::ZERO));
+        assert_eq!(limiter.usage().len(), 2);
+    }
+}
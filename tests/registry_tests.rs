@@ -0,0 +1,109 @@
+use binance::api::*;
+use binance::config::*;
+use binance::filters::*;
+use binance::general::*;
+use binance::model::*;
+use binance::registry::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::mock;
+    use std::time::Duration;
+
+    fn symbols() -> Vec<Symbol> {
+        let info: ExchangeInformation = serde_json::from_str(
+            &std::fs::read_to_string("tests/mocks/general/exchange_info.json").unwrap(),
+        )
+        .unwrap();
+        info.symbols
+    }
+
+    #[test]
+    fn lookups() {
+        let registry = SymbolRegistry::new(symbols());
+
+        assert_eq!(registry.len(), 3);
+        assert_eq!(registry.get("ETHBTC").unwrap().base_asset, "ETH");
+        assert_eq!(registry.get("ethbtc").unwrap().symbol, "ETHBTC");
+        assert!(registry.get("ETHUSDT").is_none());
+
+        let names: Vec<String> = registry
+            .by_quote("BTC")
+            .iter()
+            .map(|symbol| symbol.symbol.clone())
+            .collect();
+        assert_eq!(names, vec!["BNBBTC", "ETHBTC", "LTCBTC"]);
+        assert_eq!(registry.by_base("LTC").len(), 1);
+        assert!(registry.by_base("BTC").is_empty());
+
+        let symbol = registry.get("ETHBTC").unwrap();
+        let lot_size = symbol.lot_size().unwrap();
+        assert_eq!(lot_size.step, "0.001".parse::<Number>().unwrap());
+        assert_eq!(
+            symbol.min_notional(),
+            Some("0.0001".parse::<Number>().unwrap())
+        );
+        assert_eq!(symbol.price_precision(), Some(6));
+        assert_eq!(symbol.quantity_precision(), Some(3));
+    }
+
+    #[test]
+    fn update_reports_changes() {
+        let registry = SymbolRegistry::new(symbols());
+
+        let mut next = symbols();
+        next.retain(|symbol| symbol.symbol != "BNBBTC");
+        next[0].status = "HALT".into();
+        next[1].filters.pop();
+        let mut listed = next[1].clone();
+        listed.symbol = "LTCETH".into();
+        listed.quote_asset = "ETH".into();
+        next.push(listed);
+
+        let diff = registry.update(next);
+        assert_eq!(diff.listed, vec!["LTCETH"]);
+        assert_eq!(diff.delisted, vec!["BNBBTC"]);
+        assert_eq!(
+            diff.status_changed,
+            vec![StatusChange {
+                symbol: "ETHBTC".into(),
+                from: "TRADING".into(),
+                to: "HALT".into(),
+            }]
+        );
+        assert_eq!(diff.filters_changed, vec!["LTCBTC"]);
+        assert_eq!(registry.by_quote("ETH").len(), 1);
+        assert!(registry.get("BNBBTC").is_none());
+
+        assert!(registry
+            .update(symbols())
+            .delisted
+            .contains(&"LTCETH".to_string()));
+        assert!(registry.update(symbols()).is_empty());
+        assert!(registry.refresh().is_err());
+    }
+
+    #[test]
+    fn refresh_from_exchange_info() {
+        let mock_exchange_info = mock("GET", "/api/v3/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/general/exchange_info.json")
+            .expect(2)
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(mockito::server_url());
+        let general: General = Binance::new_with_config(None, None, &config);
+        let registry = SymbolRegistry::spot(general).unwrap();
+
+        assert!(!registry.is_stale());
+        assert!(registry.refresh_if_stale().unwrap().is_none());
+
+        let registry = registry.set_max_age(Duration::from_secs(0));
+        let diff = registry.refresh_if_stale().unwrap().unwrap();
+        mock_exchange_info.assert();
+
+        assert!(diff.is_empty());
+        assert!(registry.contains("LTCBTC"));
+    }
+}
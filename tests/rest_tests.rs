@@ -0,0 +1,91 @@
+use binance::api::*;
+use binance::config::*;
+use binance::errors::*;
+use binance::futures::rest::*;
+use binance::model::*;
+use binance::rest::*;
+use std::collections::BTreeMap;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+
+    #[test]
+    fn get_typed() {
+        let mock_get_price = mock("GET", "/api/v3/ticker/price")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Exact("symbol=LTCBTC".into()))
+            .with_body_from_file("tests/mocks/market/get_price.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(mockito::server_url());
+        let rest: Rest = Binance::new_with_config(None, None, &config);
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), "LTCBTC".into());
+        let price: SymbolPrice = rest.get("/api/v3/ticker/price", parameters).unwrap();
+        mock_get_price.assert();
+
+        assert_eq!(price.symbol, "LTCBTC");
+        assert_eq!(price.price, "4.000002".parse::<Number>().unwrap());
+    }
+
+    #[test]
+    fn get_signed_value() {
+        let mock_dividends = mock("GET", "/sapi/v1/asset/assetDividend")
+            .match_header("x-mbx-apikey", "api_key")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "asset=BNB&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body(r#"{"rows":[{"amount":"10.00000000","asset":"BNB"}],"total":1}"#)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let rest: Rest =
+            Binance::new_with_config(Some("api_key".into()), Some("secret".into()), &config);
+        let mut parameters = BTreeMap::new();
+        parameters.insert("asset".into(), "BNB".into());
+        let dividends: serde_json::Value = rest
+            .get_signed("/sapi/v1/asset/assetDividend", parameters)
+            .unwrap();
+        mock_dividends.assert();
+
+        assert_eq!(dividends["total"], 1);
+        assert_eq!(dividends["rows"][0]["asset"], "BNB");
+    }
+
+    #[test]
+    fn futures_post_signed_error() {
+        let mock_leverage = mock("POST", "/fapi/v1/leverage")
+            .with_status(400)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "leverage=200&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body(r#"{"code":-4028,"msg":"Leverage 200 is not valid"}"#)
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let rest: FuturesRest = Binance::new_with_config(None, None, &config);
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), "BTCUSDT".into());
+        parameters.insert("leverage".into(), "200".into());
+        let err = rest
+            .post_signed::<serde_json::Value>("/fapi/v1/leverage", parameters)
+            .unwrap_err();
+        mock_leverage.assert();
+
+        match err.kind() {
+            ErrorKind::BinanceError(response) => {
+                assert_eq!(response.code, -4028);
+                assert_eq!(response.status, 400);
+            }
+            _ => panic!("Unexpected error: {:?}", err),
+        }
+    }
+}
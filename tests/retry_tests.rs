@@ -0,0 +1,159 @@
+use binance::api::*;
+use binance::config::*;
+use binance::model::Number;
+use binance::account::*;
+use binance::general::*;
+use binance::errors::{ErrorCategory, ErrorKind};
+use binance::retry::*;
+use std::time::Duration;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+
+    fn retry_policy() -> RetryPolicy {
+        RetryPolicy::new(3).set_backoff(Duration::from_millis(1), Duration::from_millis(10))
+    }
+
+    #[test]
+    fn retries_get_until_success() {
+        let mock_unavailable = mock("GET", "/api/v3/time")
+            .with_status(503)
+            .expect(2)
+            .create();
+        let mock_server_time = mock("GET", "/api/v3/time")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/general/server_time.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_retry_policy(retry_policy());
+        let general: General = Binance::new_with_config(None, None, &config);
+
+        let server_time = general.get_server_time().unwrap();
+        mock_unavailable.assert();
+        mock_server_time.assert();
+
+        assert_eq!(server_time.server_time, 1499827319559);
+    }
+
+    #[test]
+    fn order_post_is_not_retried() {
+        let mock_limit_buy = mock("POST", "/api/v3/order")
+            .with_status(503)
+            .match_query(Matcher::Any)
+            .expect(1)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_retry_policy(retry_policy());
+        let account: Account = Binance::new_with_config(None, None, &config);
+
+        assert!(account
+            .limit_buy("LTCBTC", 1, "0.1".parse::<Number>().unwrap())
+            .is_err());
+        mock_limit_buy.assert();
+    }
+
+    #[test]
+    fn order_post_retried_when_allowed() {
+        let mock_unavailable = mock("POST", "/api/v3/order")
+            .with_status(503)
+            .match_query(Matcher::Any)
+            .expect(1)
+            .create();
+        let mock_limit_buy = mock("POST", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Any)
+            .with_body_from_file("tests/mocks/account/limit_buy.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_retry_policy(retry_policy().set_retry_non_idempotent(true));
+        let account: Account = Binance::new_with_config(None, None, &config);
+
+        let transaction = account
+            .limit_buy("LTCBTC", 1, "0.1".parse::<Number>().unwrap())
+            .unwrap();
+        mock_unavailable.assert();
+        mock_limit_buy.assert();
+
+        assert_eq!(transaction.order_id, 1);
+    }
+
+    #[test]
+    fn too_many_requests_keeps_retry_after() {
+        let mock_ping = mock("GET", "/api/v3/ping")
+            .with_status(429)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_header("retry-after", "30")
+            .with_body(r#"{"code":-1003,"msg":"Too much request weight used; current limit is 6000 request weight per 1 MINUTE."}"#)
+            .expect(1)
+            .create();
+
+        // Retry-After is longer than the policy is willing to wait
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_retry_policy(retry_policy());
+        let general: General = Binance::new_with_config(None, None, &config);
+
+        let err = general.ping().unwrap_err();
+        mock_ping.assert();
+
+        assert_eq!(err.category(), ErrorCategory::RateLimited);
+        assert_eq!(err.retry_after(), Some(Duration::from_secs(30)));
+        match err.kind() {
+            ErrorKind::BinanceError(response) => {
+                assert_eq!(response.status, 429);
+                assert_eq!(response.retry_after, Some(Duration::from_secs(30)));
+            }
+            _ => panic!("Unexpected error: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn no_requests_while_banned() {
+        let mock_exchange_info = mock("GET", "/api/v3/exchangeInfo")
+            .with_status(418)
+            .with_header("retry-after", "60")
+            .expect(1)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_retry_policy(retry_policy());
+        let general: General = Binance::new_with_config(None, None, &config);
+
+        let err = general.exchange_info().unwrap_err();
+        let until = match err.kind() {
+            ErrorKind::IpBanned(until) => *until,
+            _ => panic!("Unexpected error: {:?}", err),
+        };
+        assert_eq!(config.rate_limiter.banned_until(), Some(until));
+
+        let err = general.exchange_info().unwrap_err();
+        mock_exchange_info.assert();
+
+        match err.kind() {
+            ErrorKind::IpBanned(banned_until) => assert_eq!(*banned_until, until),
+            _ => panic!("Unexpected error: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn backoff_grows_with_jitter() {
+        let policy =
+            RetryPolicy::new(5).set_backoff(Duration::from_millis(100), Duration::from_millis(250));
+
+        let first = policy.backoff(1);
+        assert!(first >= Duration::from_millis(50) && first <= Duration::from_millis(100));
+        let second = policy.backoff(2);
+        assert!(second >= Duration::from_millis(100) && second <= Duration::from_millis(200));
+        let capped = policy.backoff(10);
+        assert!(capped >= Duration::from_millis(125) && capped <= Duration::from_millis(250));
+    }
+}
@@ -0,0 +1,609 @@
+use binance::api::*;
+use binance::config::*;
+use binance::model::*;
+use binance::savings::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+
+    #[test]
+    fn withdraw() {
+        let mock_withdraw = mock("POST", "/sapi/v1/capital/withdraw/apply")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^address=0x94df8b352de7f46f64b01d3666bf6e936e44ce60&amount=9&coin=USDT&network=ETH&recvWindow=1234&timestamp=\\d+&walletType=1&withdrawOrderId=treasury-0042".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/withdraw.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let request = WithdrawRequest::new("USDT", "0x94df8b352de7f46f64b01d3666bf6e936e44ce60", 9)
+            .set_network("ETH")
+            .set_withdraw_order_id("treasury-0042")
+            .set_wallet_type(WalletType::Funding);
+        let response = savings.withdraw(request).unwrap();
+
+        mock_withdraw.assert();
+
+        assert_eq!(response.id, "7213fea8e94b4a5593d507237e5a555b");
+    }
+
+    #[test]
+    fn deposit_history() {
+        let mock_deposit_history = mock("GET", "/sapi/v1/capital/deposit/hisrec")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^coin=BNB&recvWindow=1234&status=1&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/deposit_history.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let request = DepositHistoryRequest::new()
+            .set_coin("BNB")
+            .set_status(DepositStatus::Success);
+        let deposits = savings.deposit_history(request).unwrap();
+
+        mock_deposit_history.assert();
+
+        assert_eq!(deposits.len(), 2);
+        assert_eq!(deposits[0].status, DepositStatus::Success);
+        assert_eq!(deposits[0].amount, "0.001".parse::<Number>().unwrap());
+        assert_eq!(deposits[0].complete_time, Some(1661493146000));
+        assert_eq!(deposits[0].wallet_type, WalletType::Spot);
+        // Statuses added later by Binance are kept
+        assert_eq!(deposits[1].status, DepositStatus::Unknown(9));
+        assert_eq!(deposits[1].status.code(), 9);
+        assert!(deposits[1].complete_time.is_none());
+    }
+
+    #[test]
+    fn withdraw_history() {
+        let mock_withdraw_history = mock("GET", "/sapi/v1/capital/withdraw/history")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^recvWindow=1234&timestamp=\\d+&withdrawOrderId=treasury-0042".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/withdraw_history.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let request = WithdrawHistoryRequest::new().set_withdraw_order_id("treasury-0042");
+        let withdrawals = savings.withdraw_history(request).unwrap();
+
+        mock_withdraw_history.assert();
+
+        let withdrawal = &withdrawals[0];
+        assert_eq!(withdrawal.status, WithdrawStatus::Completed);
+        assert_eq!(
+            withdrawal.transaction_fee,
+            "0.004".parse::<Number>().unwrap()
+        );
+        assert_eq!(
+            withdrawal.withdraw_order_id.as_deref(),
+            Some("treasury-0042")
+        );
+        assert_eq!(withdrawal.confirm_no, Some(3));
+        assert_eq!(withdrawal.wallet_type, WalletType::Funding);
+    }
+
+    #[test]
+    fn withdraw_history_iter_windows() {
+        const DAY: u64 = 24 * 60 * 60 * 1000;
+        let end_time = 1570000000000;
+        let start_time = end_time - 100 * DAY;
+
+        let mock_recent_window = mock("GET", "/sapi/v1/capital/withdraw/history")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(format!(
+                "^coin=ETH&endTime={}&limit=1&offset=0&recvWindow=1234&startTime={}&",
+                end_time,
+                end_time - 90 * DAY + 1
+            )))
+            .with_body_from_file("tests/mocks/savings/withdraw_history.json")
+            .create();
+        let mock_recent_window_next = mock("GET", "/sapi/v1/capital/withdraw/history")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^coin=ETH&endTime=\\d+&limit=1&offset=1&".into(),
+            ))
+            .with_body("[]")
+            .create();
+        let mock_oldest_window = mock("GET", "/sapi/v1/capital/withdraw/history")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(format!(
+                "^coin=ETH&endTime={}&limit=1&offset=0&recvWindow=1234&startTime={}&",
+                end_time - 90 * DAY,
+                start_time
+            )))
+            .with_body("[]")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let request = WithdrawHistoryRequest::new()
+            .set_coin("ETH")
+            .set_start_time(start_time)
+            .set_end_time(end_time)
+            .set_limit(1);
+        let withdrawals: Vec<WithdrawRecord> = savings
+            .withdraw_history_iter(request)
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        mock_recent_window.assert();
+        mock_recent_window_next.assert();
+        mock_oldest_window.assert();
+
+        assert_eq!(withdrawals.len(), 1);
+        assert_eq!(withdrawals[0].id, "b6ae22b3aa844210a7041aee7589627c");
+    }
+
+    #[test]
+    fn withdraw_history_iter_by_withdraw_order_id() {
+        const DAY: u64 = 24 * 60 * 60 * 1000;
+        let end_time = 1570000000000;
+        let start_time = end_time - 10 * DAY;
+
+        let mock_recent_window = mock("GET", "/sapi/v1/capital/withdraw/history")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(format!(
+                "^endTime={}&limit=1&offset=0&recvWindow=1234&startTime={}&timestamp=\\d+&withdrawOrderId=WITHDRAWtest123",
+                end_time,
+                end_time - 7 * DAY + 1
+            )))
+            .with_body_from_file("tests/mocks/savings/withdraw_history.json")
+            .create();
+        let mock_recent_window_next = mock("GET", "/sapi/v1/capital/withdraw/history")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^endTime=\\d+&limit=1&offset=1&".into()))
+            .with_body("[]")
+            .create();
+        let mock_oldest_window = mock("GET", "/sapi/v1/capital/withdraw/history")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(format!(
+                "^endTime={}&limit=1&offset=0&recvWindow=1234&startTime={}&",
+                end_time - 7 * DAY,
+                start_time
+            )))
+            .with_body("[]")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let request = WithdrawHistoryRequest::new()
+            .set_withdraw_order_id("WITHDRAWtest123")
+            .set_start_time(start_time)
+            .set_end_time(end_time)
+            .set_limit(1);
+        let withdrawals: Vec<WithdrawRecord> = savings
+            .withdraw_history_iter(request)
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        mock_recent_window.assert();
+        mock_recent_window_next.assert();
+        mock_oldest_window.assert();
+
+        assert_eq!(withdrawals.len(), 1);
+    }
+
+    #[test]
+    fn universal_transfer() {
+        let mock_isolated_transfer = mock("POST", "/sapi/v1/asset/transfer")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^amount=10&asset=USDT&recvWindow=1234&timestamp=\\d+&toSymbol=BTCUSDT&type=MARGIN_ISOLATEDMARGIN".into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/transaction_id.json")
+            .create();
+        let mock_futures_transfer = mock("POST", "/sapi/v1/asset/transfer")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^amount=5&asset=BNB&recvWindow=1234&timestamp=\\d+&type=UMFUTURE_MAIN".into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/transaction_id.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let request =
+            UniversalTransferRequest::new(UniversalTransferType::MarginIsolatedMargin, "USDT", 10)
+                .set_to_symbol("BTCUSDT");
+        let transfer = savings.universal_transfer(request).unwrap();
+        // The legacy spot-futures directions map to universal transfer types
+        let request =
+            UniversalTransferRequest::new(SpotFuturesTransferType::UsdtFuturesToSpot, "BNB", 5);
+        savings.universal_transfer(request).unwrap();
+
+        mock_isolated_transfer.assert();
+        mock_futures_transfer.assert();
+
+        assert_eq!(transfer.tran_id, 100000001);
+
+        // Isolated margin accounts are identified by their symbol
+        let missing_symbol =
+            UniversalTransferRequest::new(UniversalTransferType::IsolatedMarginMargin, "USDT", 10);
+        assert!(missing_symbol.validate().is_err());
+        let extra_symbol =
+            UniversalTransferRequest::new(UniversalTransferType::MainMargin, "USDT", 10)
+                .set_to_symbol("BTCUSDT");
+        assert!(extra_symbol.validate().is_err());
+    }
+
+    #[test]
+    fn universal_transfer_history() {
+        let mock_history = mock("GET", "/sapi/v1/asset/transfer")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^current=2&recvWindow=1234&size=100&timestamp=\\d+&type=MAIN_UMFUTURE".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/universal_transfer_history.json")
+            .create();
+        let mock_empty_history = mock("GET", "/sapi/v1/asset/transfer")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^recvWindow=1234&timestamp=\\d+&type=FUNDING_OPTION".into(),
+            ))
+            .with_body("{\"total\":0}")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let request = UniversalTransferHistoryRequest::new(UniversalTransferType::MainUmFuture)
+            .set_page(2, 100);
+        let history = savings.universal_transfer_history(request).unwrap();
+        let empty = savings
+            .universal_transfer_history(UniversalTransferHistoryRequest::new(
+                UniversalTransferType::FundingOption,
+            ))
+            .unwrap();
+
+        mock_history.assert();
+        mock_empty_history.assert();
+
+        assert_eq!(history.total, 2);
+        assert_eq!(
+            history.rows[0].transfer_type,
+            UniversalTransferType::MainUmFuture
+        );
+        assert_eq!(history.rows[0].status, TransferStatus::Confirmed);
+        assert_eq!(history.rows[1].amount, "2".parse::<Number>().unwrap());
+        assert_eq!(empty.total, 0);
+        assert!(empty.rows.is_empty());
+    }
+
+    #[test]
+    fn earn_products() {
+        let mock_flexible = mock("GET", "/sapi/v1/simple-earn/flexible/list")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^asset=BTC&current=1&recvWindow=1234&size=10&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/flexible_products.json")
+            .create();
+        let mock_locked = mock("GET", "/sapi/v1/simple-earn/locked/list")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^asset=AXS&recvWindow=1234&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/locked_products.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let flexible = savings
+            .flexible_products(EarnProductRequest::new().set_asset("BTC").set_page(1, 10))
+            .unwrap();
+        let locked = savings
+            .locked_products(EarnProductRequest::new().set_asset("AXS"))
+            .unwrap();
+
+        mock_flexible.assert();
+        mock_locked.assert();
+
+        assert_eq!(flexible.total, 1);
+        let product = &flexible.rows[0];
+        assert_eq!(product.product_id, "BTC001");
+        assert_eq!(product.status, EarnProductStatus::Purchasing);
+        assert_eq!(
+            product.min_purchase_amount,
+            "0.01".parse::<Number>().unwrap()
+        );
+
+        let product = &locked.rows[0];
+        assert_eq!(product.project_id, "Axs*90");
+        assert_eq!(product.detail.duration, 90);
+        assert_eq!(product.detail.status, EarnProductStatus::Created);
+        assert_eq!(
+            product.detail.extra_reward_apr,
+            Some("0.23".parse::<Number>().unwrap())
+        );
+        assert_eq!(product.quota.minimum, "0.001".parse::<Number>().unwrap());
+    }
+
+    #[test]
+    fn flexible_products_iter_pages() {
+        let mock_first_page = mock("GET", "/sapi/v1/simple-earn/flexible/list")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^asset=ETH&current=1&recvWindow=1234&size=1&".into(),
+            ))
+            .with_body(
+                "{\"rows\":[{\"asset\":\"ETH\",\"latestAnnualPercentageRate\":\"0.01\",\"canPurchase\":true,\"canRedeem\":true,\"isSoldOut\":false,\"hot\":false,\"minPurchaseAmount\":\"0.0001\",\"productId\":\"ETH001\",\"subscriptionStartTime\":1646182276000,\"status\":\"PURCHASING\"}],\"total\":2}",
+            )
+            .create();
+        let mock_last_page = mock("GET", "/sapi/v1/simple-earn/flexible/list")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^asset=ETH&current=2&recvWindow=1234&size=1&".into(),
+            ))
+            .with_body("{\"rows\":[],\"total\":2}")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let request = EarnProductRequest::new().set_asset("ETH").set_page(1, 1);
+        let products: Vec<FlexibleProduct> = savings
+            .flexible_products_iter(request)
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        mock_first_page.assert();
+        mock_last_page.assert();
+
+        assert_eq!(products.len(), 1);
+        assert_eq!(products[0].product_id, "ETH001");
+    }
+
+    #[test]
+    fn earn_subscribe_and_redeem() {
+        let mock_subscribe_flexible = mock("POST", "/sapi/v1/simple-earn/flexible/subscribe")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^amount=250&autoSubscribe=false&productId=USDT001&recvWindow=1234&sourceAccount=FUND&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/flexible_subscribe.json")
+            .create();
+        let mock_subscribe_locked = mock("POST", "/sapi/v1/simple-earn/locked/subscribe")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^amount=3&projectId=Axs\\*90&recvWindow=1234&redeemTo=FLEXIBLE&timestamp=\\d+"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/locked_subscribe.json")
+            .create();
+        let mock_redeem_flexible = mock("POST", "/sapi/v1/simple-earn/flexible/redeem")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^productId=USDT001&recvWindow=1234&redeemAll=true&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/redeem.json")
+            .create();
+        let mock_redeem_locked = mock("POST", "/sapi/v1/simple-earn/locked/redeem")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^positionId=12345&recvWindow=1234&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/redeem.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let flexible = savings
+            .subscribe_flexible(
+                FlexibleSubscribeRequest::new("USDT001", 250)
+                    .set_auto_subscribe(false)
+                    .set_source_account(EarnAccount::Fund),
+            )
+            .unwrap();
+        let locked = savings
+            .subscribe_locked(
+                LockedSubscribeRequest::new("Axs*90", 3).set_redeem_to(LockedRedeemTo::Flexible),
+            )
+            .unwrap();
+        let redemption = savings
+            .redeem_flexible(FlexibleRedeemRequest::all("USDT001"))
+            .unwrap();
+        savings.redeem_locked(locked.position_id.unwrap()).unwrap();
+
+        mock_subscribe_flexible.assert();
+        mock_subscribe_locked.assert();
+        mock_redeem_flexible.assert();
+        mock_redeem_locked.assert();
+
+        assert_eq!(flexible.purchase_id, 40607);
+        assert!(flexible.position_id.is_none());
+        assert_eq!(locked.position_id, Some(12345));
+        assert!(redemption.success);
+
+        // Either an amount or the whole position, to the spot or funding wallet
+        let neither = FlexibleRedeemRequest {
+            redeem_all: false,
+            ..FlexibleRedeemRequest::all("USDT001")
+        };
+        assert!(neither.validate().is_err());
+        let to_all = FlexibleRedeemRequest::new("USDT001", 1).set_dest_account(EarnAccount::All);
+        assert!(to_all.validate().is_err());
+    }
+
+    #[test]
+    fn earn_positions() {
+        let mock_flexible = mock("GET", "/sapi/v1/simple-earn/flexible/position")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^asset=USDT&recvWindow=1234&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/flexible_position.json")
+            .create();
+        let mock_locked = mock("GET", "/sapi/v1/simple-earn/locked/position")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^projectId=Axs\\*90&recvWindow=1234&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/locked_position.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let flexible = savings
+            .flexible_positions(FlexiblePositionRequest::new().set_asset("USDT"))
+            .unwrap();
+        let locked = savings
+            .locked_positions(LockedPositionRequest::new().set_project_id("Axs*90"))
+            .unwrap();
+
+        mock_flexible.assert();
+        mock_locked.assert();
+
+        let position = &flexible.rows[0];
+        assert_eq!(position.total_amount, "75.46".parse::<Number>().unwrap());
+        assert!(position.auto_subscribe);
+
+        // Numbers and dates are sent as numbers or strings
+        let position = &locked.rows[0];
+        assert_eq!(position.position_id, 123123);
+        assert_eq!(position.purchase_time, 1646182276000);
+        assert_eq!(position.duration, 60);
+        assert_eq!(position.apy, "0.2032".parse::<Number>().unwrap());
+        assert_eq!(position.redeem_to, LockedRedeemTo::Flexible);
+    }
+
+    #[test]
+    fn earn_history() {
+        let mock_flexible_rewards =
+            mock("GET", "/sapi/v1/simple-earn/flexible/history/rewardsRecord")
+                .with_header("content-type", "application/json;charset=UTF-8")
+                .match_query(Matcher::Regex(
+                    "^endTime=1577300000000&recvWindow=1234&startTime=1577200000000&timestamp=\\d+&type=REALTIME".into(),
+                ))
+                .with_body_from_file("tests/mocks/savings/flexible_rewards.json")
+                .create();
+        let mock_locked_redemptions = mock(
+            "GET",
+            "/sapi/v1/simple-earn/locked/history/redemptionRecord",
+        )
+        .with_header("content-type", "application/json;charset=UTF-8")
+        .match_query(Matcher::Regex(
+            "^asset=BNB&current=1&recvWindow=1234&size=100&timestamp=\\d+".into(),
+        ))
+        .with_body_from_file("tests/mocks/savings/locked_redemptions.json")
+        .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let request = EarnHistoryRequest::new()
+            .set_start_time(1577200000000)
+            .set_end_time(1577300000000);
+        let rewards = savings
+            .flexible_rewards_history(EarnRewardType::Realtime, request)
+            .unwrap();
+        let redemptions = savings
+            .locked_redemption_history(EarnHistoryRequest::new().set_asset("BNB").set_page(1, 100))
+            .unwrap();
+
+        mock_flexible_rewards.assert();
+        mock_locked_redemptions.assert();
+
+        assert_eq!(rewards.total, 2);
+        assert_eq!(
+            rewards.rows[1].rewards,
+            "0.00687654".parse::<Number>().unwrap()
+        );
+        assert_eq!(rewards.rows[1].project_id, "USDT001");
+
+        let redemption = &redemptions.rows[0];
+        assert_eq!(redemption.position_id, 123123);
+        assert_eq!(redemption.lock_period, 30);
+        assert_eq!(redemption.deliver_date, 1575018510000);
+        assert_eq!(redemption.status, "PAID");
+    }
+
+    #[test]
+    fn earn_history_iter_windows() {
+        const DAY: u64 = 24 * 60 * 60 * 1000;
+        let end_time = 1577300000000;
+        let start_time = end_time - 100 * DAY;
+
+        let mock_recent_window =
+            mock("GET", "/sapi/v1/simple-earn/flexible/history/rewardsRecord")
+                .with_header("content-type", "application/json;charset=UTF-8")
+                .match_query(Matcher::Regex(format!(
+                    "^current=1&endTime={}&recvWindow=1234&size=2&startTime={}&timestamp=\\d+&type=REALTIME",
+                    end_time,
+                    end_time - 90 * DAY + 1
+                )))
+                .with_body_from_file("tests/mocks/savings/flexible_rewards.json")
+                .create();
+        let mock_oldest_window = mock("GET", "/sapi/v1/simple-earn/flexible/history/rewardsRecord")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(format!(
+                "^current=1&endTime={}&recvWindow=1234&size=2&startTime={}&",
+                end_time - 90 * DAY,
+                start_time
+            )))
+            .with_body("{\"total\":0}")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let request = EarnHistoryRequest::new()
+            .set_start_time(start_time)
+            .set_end_time(end_time)
+            .set_page(1, 2);
+        let rewards: Vec<FlexibleRewardRecord> = savings
+            .flexible_rewards_history_iter(EarnRewardType::Realtime, request)
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        mock_recent_window.assert();
+        mock_oldest_window.assert();
+
+        assert_eq!(rewards.len(), 2);
+        assert_eq!(rewards[0].asset, "BUSD");
+    }
+}
@@ -0,0 +1,91 @@
+use binance::signer::*;
+#[cfg(feature = "ed25519")]
+use binance::{account::*, api::*, config::*};
+#[cfg(feature = "ed25519")]
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "ed25519")]
+    use mockito::{mock, Matcher};
+
+    // Example from the Binance "SIGNED Endpoint Examples" section
+    const PAYLOAD: &str = "symbol=LTCBTC&side=BUY&type=LIMIT&timeInForce=GTC&quantity=1&price=0.1&recvWindow=5000&timestamp=1499827319559";
+
+    #[test]
+    fn hmac_signature() {
+        let signer =
+            HmacSigner::new("NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j");
+
+        assert_eq!(
+            signer.sign(PAYLOAD).unwrap(),
+            "c8db56825ae71d6d79447849e617115f4a920fa2acdcab2b053c4b2838bd6b71"
+        );
+    }
+
+    #[cfg(feature = "ed25519")]
+    #[test]
+    fn ed25519_rfc8032_vector() {
+        let seed = hex::decode("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60")
+            .unwrap();
+        let signature = hex::decode("e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b").unwrap();
+        let mut secret_key = [0u8; 32];
+        secret_key.copy_from_slice(&seed);
+        let signer = Ed25519Signer::from_bytes(&secret_key);
+
+        assert_eq!(
+            STANDARD.decode(signer.sign("").unwrap()).unwrap(),
+            signature
+        );
+    }
+
+    #[cfg(feature = "ed25519")]
+    #[test]
+    fn ed25519_pem_signature() {
+        let pem = std::fs::read_to_string("tests/mocks/keys/ed25519_private.pem").unwrap();
+        let signer = Ed25519Signer::from_pkcs8_pem(&pem).unwrap();
+
+        // openssl pkeyutl -sign -inkey ed25519_private.pem -rawin
+        assert_eq!(
+            signer.sign(PAYLOAD).unwrap(),
+            "ejT46IySkSJ9pahzzh/PZA4ocwg8BG0Q+1GiuKzqdvTqqD+1nyi00CG8p4L+5jELlGHR0FJ6VspfUj9Tu03KDA=="
+        );
+        assert!(Ed25519Signer::from_pkcs8_pem("not a key").is_err());
+    }
+
+    #[cfg(feature = "rsa")]
+    #[test]
+    fn rsa_pem_signature() {
+        let pem = std::fs::read_to_string("tests/mocks/keys/rsa_private.pem").unwrap();
+        let signer = RsaSigner::from_pkcs8_pem(&pem).unwrap();
+
+        // openssl dgst -sha256 -sign rsa_private.pem
+        assert_eq!(
+            signer.sign(PAYLOAD).unwrap(),
+            "nRMPwhiKKF4cu+bXqF1gqQDZD6ozY3vCNUSuQW6dkrGWptzG+VOf8zL2mwkdpn/W259ocupMues5FVizIbVmt4ftilwraj+YhXMjVeOHyJn7M47sEd4z1dkho1aMZMpXWSQSSEXPngAv4I9N49YWDpLbB/beHVyFJAZg8K4Wo7z9TsBK4emc9xuxd6im/urfEC6ZSvQFe+xpMotz1sFSNhiwHdrEVdwXjPAgXBET27FZGFuHwlxliIV9cjPFwis7w9Zi5EU8TESPY4VgYERVSrql1YQ6T3cZv57EPrUpRp5IY39wxrvVr/sC3dtQCeHOH/UWtdBVILgEICDNCcBkZA=="
+        );
+    }
+
+    #[cfg(feature = "ed25519")]
+    #[test]
+    fn ed25519_signed_request() {
+        let mock_get_account = mock("GET", "/api/v3/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=[0-9A-Za-z%]{86,}%3D%3D$".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/get_account.json")
+            .create();
+
+        let pem = std::fs::read_to_string("tests/mocks/keys/ed25519_private.pem").unwrap();
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234)
+            .set_signer(Ed25519Signer::from_pkcs8_pem(&pem).unwrap());
+        let account: Account = Binance::new_with_config(None, None, &config);
+
+        account.get_account().unwrap();
+        mock_get_account.assert();
+    }
+}
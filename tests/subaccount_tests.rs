@@ -0,0 +1,212 @@
+use binance::api::*;
+use binance::config::*;
+use binance::model::*;
+use binance::subaccount::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+
+    #[test]
+    fn list() {
+        let mock_list = mock("GET", "/sapi/v1/sub-account/list")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^limit=200&page=1&recvWindow=1234&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/subaccount/list.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let sub_account: SubAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let list = sub_account
+            .list(SubAccountListRequest::new().set_page(1, 200))
+            .unwrap();
+
+        mock_list.assert();
+
+        assert_eq!(list.len(), 2);
+        assert_eq!(list[0].email, "strategy-1@example.com");
+        assert!(!list[0].is_freeze);
+        assert!(list[1].is_freeze);
+        assert_eq!(list[1].is_managed_sub_account, Some(false));
+    }
+
+    #[test]
+    fn balances() {
+        let mock_assets = mock("GET", "/sapi/v3/sub-account/assets")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^email=strategy-1%40example.com&recvWindow=1234&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/subaccount/assets.json")
+            .create();
+        let mock_futures_account = mock("GET", "/sapi/v2/sub-account/futures/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^email=strategy-1%40example.com&futuresType=1&recvWindow=1234".into(),
+            ))
+            .with_body_from_file("tests/mocks/subaccount/futures_account.json")
+            .create();
+        let mock_margin_account = mock("GET", "/sapi/v1/sub-account/margin/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^email=strategy-1%40example.com&recvWindow=1234".into(),
+            ))
+            .with_body_from_file("tests/mocks/subaccount/margin_account.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let sub_account: SubAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let balances = sub_account.spot_balances("strategy-1@example.com").unwrap();
+        let futures = sub_account
+            .futures_account("strategy-1@example.com", SubAccountFuturesType::UsdM)
+            .unwrap();
+        let margin = sub_account
+            .margin_account("strategy-1@example.com")
+            .unwrap();
+
+        mock_assets.assert();
+        mock_futures_account.assert();
+        mock_margin_account.assert();
+
+        // Amounts are sent as numbers or strings
+        assert_eq!(balances[0].free, "10000".parse::<Number>().unwrap());
+        assert_eq!(balances[1].free, "10003.5".parse::<Number>().unwrap());
+        assert_eq!(balances[1].locked, "0.5".parse::<Number>().unwrap());
+
+        assert!(futures.coin_m.is_none());
+        let account = futures.account().unwrap();
+        assert_eq!(account.fee_tier, 2);
+        assert_eq!(
+            account.total_wallet_balance,
+            Some("0.88308".parse::<Number>().unwrap())
+        );
+        assert_eq!(account.assets[0].asset, "USDT");
+
+        assert_eq!(
+            margin.margin_level,
+            "11.64405625".parse::<Number>().unwrap()
+        );
+        assert_eq!(margin.user_assets[0].asset, "BTC");
+    }
+
+    #[test]
+    fn transfer() {
+        let mock_transfer = mock("POST", "/sapi/v1/sub-account/universalTransfer")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^amount=1000&asset=USDT&clientTranId=rebalance-7&fromAccountType=SPOT&recvWindow=1234&timestamp=\\d+&toAccountType=USDT_FUTURE&toEmail=strategy-1%40example.com".into(),
+            ))
+            .with_body_from_file("tests/mocks/subaccount/transfer.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let sub_account: SubAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let request = SubAccountTransferRequest::new(
+            SubAccountType::Spot,
+            SubAccountType::UsdtFuture,
+            "USDT",
+            1000,
+        )
+        .set_to_email("strategy-1@example.com")
+        .set_client_tran_id("rebalance-7");
+        let response = sub_account.transfer(request.clone()).unwrap();
+
+        mock_transfer.assert();
+
+        assert_eq!(response.tran_id, 11945860693);
+        assert_eq!(response.client_tran_id.as_deref(), Some("rebalance-7"));
+
+        // Isolated margin accounts need their symbol
+        let isolated = SubAccountTransferRequest {
+            to_account_type: SubAccountType::IsolatedMargin,
+            ..request.clone()
+        };
+        assert!(isolated.validate().is_err());
+        assert!(isolated.set_symbol("BTCUSDT").validate().is_ok());
+        // Transfers within the master account are not sub-account transfers
+        let master_only = SubAccountTransferRequest {
+            to_email: None,
+            ..request
+        };
+        assert!(master_only.validate().is_err());
+    }
+
+    #[test]
+    fn transfer_history() {
+        let mock_history = mock("GET", "/sapi/v1/sub-account/universalTransfer")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^recvWindow=1234&startTime=1640000000000&timestamp=\\d+&toEmail=strategy-1%40example.com".into(),
+            ))
+            .with_body_from_file("tests/mocks/subaccount/transfer_history.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let sub_account: SubAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let request = SubAccountTransferHistoryRequest::new()
+            .set_to_email("strategy-1@example.com")
+            .set_start_time(1640000000000);
+        let history = sub_account.transfer_history(request.clone()).unwrap();
+
+        mock_history.assert();
+
+        assert_eq!(history.total_count, 1);
+        let transfer = &history.result[0];
+        assert_eq!(transfer.from_email, "");
+        assert_eq!(transfer.from_account_type, SubAccountType::Spot);
+        assert_eq!(transfer.to_account_type, SubAccountType::UsdtFuture);
+        assert_eq!(transfer.amount, "1000".parse::<Number>().unwrap());
+
+        let both = request.set_from_email("strategy-2@example.com");
+        assert!(both.validate().is_err());
+    }
+
+    #[test]
+    fn enable_futures_and_margin() {
+        let mock_enable_futures = mock("POST", "/sapi/v1/sub-account/futures/enable")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^email=strategy-1%40example.com&recvWindow=1234".into(),
+            ))
+            .with_body_from_file("tests/mocks/subaccount/enable_futures.json")
+            .create();
+        let mock_enable_margin = mock("POST", "/sapi/v1/sub-account/margin/enable")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^email=strategy-1%40example.com&recvWindow=1234".into(),
+            ))
+            .with_body_from_file("tests/mocks/subaccount/enable_margin.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234);
+        let sub_account: SubAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let futures = sub_account
+            .enable_futures("strategy-1@example.com")
+            .unwrap();
+        let margin = sub_account.enable_margin("strategy-1@example.com").unwrap();
+
+        mock_enable_futures.assert();
+        mock_enable_margin.assert();
+
+        assert!(futures.is_futures_enabled);
+        assert!(margin.is_margin_enabled);
+    }
+}
@@ -0,0 +1,88 @@
+use binance::api::*;
+use binance::config::*;
+use binance::account::*;
+use binance::errors::ErrorKind;
+use binance::timesync::*;
+use std::time::Duration;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+
+    #[test]
+    fn signed_request_uses_server_time() {
+        // server_time.json is in 2017, far behind the local clock
+        let mock_server_time = mock("GET", "/api/v3/time")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/general/server_time.json")
+            .expect(1)
+            .create();
+        let mock_get_account = mock("GET", "/api/v3/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=14998273\\d{5}&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/get_account.json")
+            .expect(2)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_recv_window(1234)
+            .set_time_sync(TimeSync::new(Duration::from_secs(600)));
+        let account: Account = Binance::new_with_config(None, None, &config);
+
+        account.get_account().unwrap();
+        account.get_account().unwrap();
+        mock_server_time.assert();
+        mock_get_account.assert();
+
+        let time_sync = config.time_sync.unwrap();
+        assert!(time_sync.offset() < 0);
+        assert_eq!(time_sync.drift(), 0);
+        assert!(time_sync.round_trip().is_some());
+        assert!(time_sync.last_sync().is_some());
+    }
+
+    #[test]
+    fn resync_after_timestamp_rejected() {
+        let mock_server_time = mock("GET", "/api/v3/time")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/general/server_time.json")
+            .expect(2)
+            .create();
+        let mock_open_orders = mock("GET", "/api/v3/openOrders")
+            .with_status(400)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Any)
+            .with_body(
+                r#"{"code":-1021,"msg":"Timestamp for this request is outside of the recvWindow."}"#,
+            )
+            .expect(2)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(mockito::server_url())
+            .set_time_sync(TimeSync::default());
+        let account: Account = Binance::new_with_config(None, None, &config);
+
+        let err = account.get_open_orders("LTCBTC").unwrap_err();
+        match err.kind() {
+            ErrorKind::BinanceError(response) => assert_eq!(response.code, -1021),
+            _ => panic!("Unexpected error: {:?}", err),
+        }
+        assert!(account.get_open_orders("LTCBTC").is_err());
+
+        mock_server_time.assert();
+        mock_open_orders.assert();
+    }
+
+    #[test]
+    fn sync_time_requires_time_sync() {
+        let account: Account = Binance::new(None, None);
+
+        assert!(account.client.time_sync().is_none());
+        assert!(account.client.sync_time().is_err());
+    }
+}
@@ -0,0 +1,162 @@
+use binance::api::*;
+use binance::config::*;
+use binance::model::Number;
+use binance::account::*;
+use binance::general::*;
+use binance::errors::*;
+use binance::rest::*;
+use binance::signer::*;
+use binance::transport::*;
+use reqwest::{Method, StatusCode};
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+// Answers with canned responses and keeps every request it was asked to send
+#[derive(Debug, Clone, Default)]
+struct FakeTransport {
+    requests: Arc<Mutex<Vec<HttpRequest>>>,
+    responses: Arc<Mutex<VecDeque<(u16, String)>>>,
+}
+
+impl FakeTransport {
+    fn respond(self, status: u16, body: &str) -> Self {
+        self.responses
+            .lock()
+            .unwrap()
+            .push_back((status, body.to_string()));
+        self
+    }
+
+    fn requests(&self) -> Vec<HttpRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl Transport for FakeTransport {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        self.requests.lock().unwrap().push(request);
+        let (status, body) = match self.responses.lock().unwrap().pop_front() {
+            Some(response) => response,
+            None => return Err("No response left".into()),
+        };
+        Ok(HttpResponse {
+            status: StatusCode::from_u16(status).unwrap(),
+            headers: Default::default(),
+            body,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn public_request_through_transport() {
+        let transport = FakeTransport::default().respond(200, "{}");
+        let config = Config::default().set_transport(transport.clone());
+        let general: General = Binance::new_with_config(None, None, &config);
+
+        assert_eq!(general.ping().unwrap(), "pong");
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, Method::GET);
+        assert_eq!(requests[0].url, "https://api.binance.com/api/v3/ping");
+        assert!(requests[0].headers.is_empty());
+        assert!(requests[0].body.is_none());
+    }
+
+    #[test]
+    fn signed_request_through_transport() {
+        let body = std::fs::read_to_string("tests/mocks/account/limit_buy.json").unwrap();
+        let transport = FakeTransport::default().respond(200, &body);
+        let config = Config::default()
+            .set_rest_api_endpoint("http://fake")
+            .set_recv_window(1234)
+            .set_transport(transport.clone());
+        let account: Account =
+            Binance::new_with_config(Some("api-key".into()), Some("secret".into()), &config);
+
+        let transaction = account
+            .limit_buy("LTCBTC", 1, "0.1".parse::<Number>().unwrap())
+            .unwrap();
+        assert_eq!(transaction.symbol, "LTCBTC");
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, Method::POST);
+        assert!(requests[0].url.starts_with("http://fake/api/v3/order?"));
+        assert!(requests[0].url.contains("recvWindow=1234"));
+        assert!(requests[0].url.contains("timestamp="));
+        assert!(requests[0].url.contains("&signature="));
+        assert_eq!(requests[0].headers["x-mbx-apikey"], "api-key");
+    }
+
+    #[test]
+    fn error_response_from_transport() {
+        let transport =
+            FakeTransport::default().respond(400, r#"{"code":-1121,"msg":"Invalid symbol."}"#);
+        let config = Config::default().set_transport(transport.clone());
+        let account: Account = Binance::new_with_config(None, None, &config);
+
+        let err = account.get_open_orders("NOPE").unwrap_err();
+        assert_eq!(err.error_code(), Some(BinanceErrorCode::BadSymbol));
+        assert_eq!(err.category(), ErrorCategory::InvalidRequest);
+
+        // Transport errors are returned as they are
+        let err = account.get_open_orders("NOPE").unwrap_err();
+        assert_eq!(err.to_string(), "No response left");
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[test]
+    fn signed_post_in_form_body() {
+        let transport = FakeTransport::default().respond(200, "{}");
+        let config = Config::default()
+            .set_rest_api_endpoint("http://fake")
+            .set_recv_window(1234)
+            .set_signed_body(true)
+            .set_transport(transport.clone());
+        let rest: Rest =
+            Binance::new_with_config(Some("api-key".into()), Some("secret".into()), &config);
+
+        let mut parameters = BTreeMap::new();
+        parameters.insert("newClientOrderId".into(), "my order/1".into());
+        parameters.insert("symbol".into(), "LTCBTC".into());
+        rest.post_signed::<serde_json::Value>("/api/v3/order", parameters)
+            .unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].url, "http://fake/api/v3/order");
+        assert_eq!(
+            requests[0].headers["content-type"],
+            "application/x-www-form-urlencoded"
+        );
+
+        // The signature covers the encoded parameters exactly as sent
+        let body = requests[0].body.as_ref().unwrap();
+        assert!(body.starts_with("newClientOrderId=my%20order%2F1&recvWindow=1234&symbol=LTCBTC"));
+        let index = body.find("&signature=").unwrap();
+        let signature = HmacSigner::new("secret").sign(&body[..index]).unwrap();
+        assert_eq!(&body[index + "&signature=".len()..], signature);
+    }
+
+    #[test]
+    fn signed_get_stays_in_query() {
+        let transport = FakeTransport::default().respond(200, "[]");
+        let config = Config::default()
+            .set_rest_api_endpoint("http://fake")
+            .set_signed_body(true)
+            .set_transport(transport.clone());
+        let account: Account = Binance::new_with_config(None, None, &config);
+
+        account.get_open_orders("LTCBTC").unwrap();
+
+        let requests = transport.requests();
+        assert!(requests[0]
+            .url
+            .starts_with("http://fake/api/v3/openOrders?recvWindow=5000&symbol=LTCBTC&timestamp="));
+        assert!(requests[0].body.is_none());
+    }
+}
@@ -22,6 +22,18 @@ mod tests {
         assert_eq!(result, format!("recvWindow={}", 1234));
     }
 
+    #[test]
+    fn build_request_encodes_values() {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("newClientOrderId".into(), "my order/1+2".into());
+        parameters.insert("symbols".into(), r#"["BTCUSDT","BNBBTC"]"#.into());
+        let result = build_request(parameters);
+        assert_eq!(
+            result,
+            "newClientOrderId=my%20order%2F1%2B2&symbols=%5B%22BTCUSDT%22%2C%22BNBBTC%22%5D"
+        );
+    }
+
     #[test]
     fn build_signed_request() {
         let now = SystemTime::now();